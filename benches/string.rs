@@ -0,0 +1,51 @@
+//! Benchmarks of the string predicates that the `memchr` feature accelerates.
+//!
+//! Compare `cargo +nightly bench --bench string` with and without `--features memchr`.
+#![feature(test)]
+
+extern crate test;
+
+use refined::{
+    prelude::*,
+    string::{Contains, IsAscii, NoWhitespace, Trimmed},
+};
+use test::{black_box, Bencher};
+
+type_string!(Needle, "needle");
+
+/// A field of an ingested record, which satisfies every predicate that is benchmarked.
+fn field() -> String {
+    let mut field = "abcdefghijklmnopqrstuvwxyz0123456789".repeat(64);
+    field.push_str("needle");
+    field
+}
+
+#[bench]
+fn bench_no_whitespace(b: &mut Bencher) {
+    let field = field();
+    b.iter(|| <NoWhitespace as Predicate<String>>::test(black_box(&field)));
+}
+
+#[bench]
+fn bench_no_whitespace_unicode(b: &mut Bencher) {
+    let field = field().replace('a', "á");
+    b.iter(|| <NoWhitespace as Predicate<String>>::test(black_box(&field)));
+}
+
+#[bench]
+fn bench_contains(b: &mut Bencher) {
+    let field = field();
+    b.iter(|| <Contains<Needle> as Predicate<String>>::test(black_box(&field)));
+}
+
+#[bench]
+fn bench_trimmed(b: &mut Bencher) {
+    let field = field();
+    b.iter(|| <Trimmed as Predicate<String>>::test(black_box(&field)));
+}
+
+#[bench]
+fn bench_is_ascii(b: &mut Bencher) {
+    let field = field();
+    b.iter(|| <IsAscii as Predicate<String>>::test(black_box(&field)));
+}
@@ -55,11 +55,14 @@
           crane'.buildPackage {
             inherit src;
             cargoTestExtraArgs = "--no-default-features --all-targets --features ${feature}";
+            # The pyo3 build script locates a Python interpreter to link against.
+            nativeBuildInputs = [ pkgs.python3 ];
           };
 
         refined = crane'.buildPackage {
           inherit src;
           cargoTestExtraArgs = "--all-features";
+          nativeBuildInputs = [ pkgs.python3 ];
         };
 
         refined-no-std = crane'.buildPackage {
@@ -67,10 +70,85 @@
           cargoTestExtraArgs = "--no-default-features --all-targets";
         };
 
+        # Tests against database frameworks, whose dependencies are kept out of refined's own.
+        refined-integration-tests = crane'.buildPackage {
+          inherit src;
+          pname = "refined-integration-tests";
+          cargoExtraArgs = "--package refined-integration-tests";
+          buildInputs = [ pkgs.sqlite ];
+        };
+
+        # `cargo kani` provides the `kani` crate, so outside of it the proof harnesses and function
+        # contracts are compiled against a stub of the items that they use.
+        kaniStub =
+          let
+            macros = pkgs.writeText "kani_macros.rs" ''
+              extern crate proc_macro;
+              use proc_macro::TokenStream;
+
+              #[proc_macro_attribute]
+              pub fn proof(_: TokenStream, item: TokenStream) -> TokenStream {
+                  let mut out: TokenStream = "#[allow(dead_code)]".parse().unwrap();
+                  out.extend(item);
+                  out
+              }
+
+              #[proc_macro_attribute]
+              pub fn requires(_: TokenStream, item: TokenStream) -> TokenStream {
+                  item
+              }
+
+              #[proc_macro_attribute]
+              pub fn ensures(_: TokenStream, item: TokenStream) -> TokenStream {
+                  item
+              }
+            '';
+            lib = pkgs.writeText "kani.rs" ''
+              pub use kani_macros::{ensures, proof, requires};
+
+              pub trait Arbitrary {
+                  fn any() -> Self;
+              }
+
+              macro_rules! arbitrary {
+                  ($($t:ty),*) => {
+                      $(impl Arbitrary for $t {
+                          fn any() -> Self {
+                              unimplemented!()
+                          }
+                      })*
+                  };
+              }
+
+              arbitrary!(bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+              pub fn any<T: Arbitrary>() -> T {
+                  T::any()
+              }
+
+              pub fn assume(_: bool) {}
+            '';
+          in
+          pkgs.runCommand "kani-stub" { nativeBuildInputs = [ fenix'.complete.toolchain ]; } ''
+            mkdir $out
+            rustc --edition 2021 --crate-type proc-macro --crate-name kani_macros ${macros} \
+              -o $out/libkani_macros.so
+            rustc --edition 2021 --crate-type rlib --crate-name kani \
+              --extern kani_macros=$out/libkani_macros.so ${lib} -o $out/libkani.rlib
+          '';
+
+        refined-check-kani = crane'.buildPackage {
+          inherit src;
+          cargoExtraArgs = "--no-default-features --features kani,arithmetic,implication";
+          RUSTFLAGS = "--cfg kani --extern kani=${kaniStub}/libkani.rlib -L ${kaniStub}";
+          doCheck = false;
+        };
+
         refined-doc = crane'.cargoDoc {
           inherit src;
           cargoArtifacts = refined;
           cargoDocExtraArgs = "--all-features";
+          nativeBuildInputs = [ pkgs.python3 ];
         };
 
         refined-example-quickstart = buildExample {
@@ -115,18 +193,48 @@
           inherit
             refined
             refined-no-std
+            refined-check-kani
             refined-doc
+            refined-integration-tests
             refined-example-axum
             refined-example-quickstart
             refined-example-optimized
             ;
           refined-test-serde = testFeature "serde";
+          refined-test-serde-alloc = testFeature "serde,alloc";
           refined-test-alloc = testFeature "alloc";
           refined-test-std = testFeature "std";
           refined-test-optimized = testFeature "optimized";
           refined-test-implication = testFeature "implication";
           refined-test-arithmetic = testFeature "arithmetic";
           refined-test-regex = testFeature "regex";
+          refined-test-regex-cache = testFeature "regex-cache";
+          refined-test-memchr = testFeature "memchr,alloc";
+          refined-test-schemars = testFeature "schemars";
+          refined-test-utoipa = testFeature "utoipa";
+          refined-test-validator = testFeature "validator";
+          refined-test-axum = testFeature "axum";
+          refined-test-actix = testFeature "actix";
+          refined-test-rocket = testFeature "rocket";
+          refined-test-clap = testFeature "clap";
+          refined-test-sqlx = testFeature "sqlx";
+          refined-test-diesel = testFeature "diesel";
+          refined-test-postgres-types = testFeature "postgres-types";
+          refined-test-sea-orm = testFeature "sea-orm";
+          refined-test-redis = testFeature "redis";
+          refined-test-bson = testFeature "bson";
+          refined-test-async-graphql = testFeature "async-graphql";
+          refined-test-proto = testFeature "proto";
+          refined-test-wasm = testFeature "wasm";
+          refined-test-pyo3 = testFeature "pyo3";
+          refined-test-proptest = testFeature "proptest";
+          refined-test-quickcheck = testFeature "quickcheck";
+          refined-test-rand = testFeature "rand";
+          refined-test-fake = testFeature "fake";
+          refined-test-kani = testFeature "kani";
+          refined-test-miette = testFeature "miette";
+          refined-test-tracing = testFeature "tracing";
+          refined-test-macros = testFeature "macros";
           refined-test-full = testFeature "full";
         };
 
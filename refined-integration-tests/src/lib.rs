@@ -0,0 +1,4 @@
+//! Integration tests of [refined](https://docs.rs/refined) with the frameworks whose test
+//! dependencies are too heavy for `refined` itself, such as database drivers.
+//!
+//! The tests live in `tests/`; this crate is not published.
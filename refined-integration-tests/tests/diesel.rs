@@ -0,0 +1,26 @@
+//! Round trips of refinements through diesel, backed by an in-memory SQLite database.
+use diesel::{dsl::sql, prelude::*, select, sql_types::BigInt, SqliteConnection};
+use refined::{boundable::signed::LessThan, Refinement, RefinementOps};
+
+type Size = Refinement<i64, LessThan<100>>;
+
+#[test]
+fn test_round_trip() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    let size = select(Size::refine(5).unwrap().into_sql::<BigInt>() + 1)
+        .get_result::<Size>(&mut conn)
+        .unwrap();
+    assert_eq!(*size, 6);
+
+    let err = select(sql::<BigInt>("150"))
+        .get_result::<Size>(&mut conn)
+        .unwrap_err();
+    let diesel::result::Error::DeserializationError(err) = err else {
+        panic!("expected a deserialization error");
+    };
+    let source = std::error::Error::source(&*err).unwrap();
+    assert_eq!(
+        source.to_string(),
+        "refinement violated: must be less than 100"
+    );
+}
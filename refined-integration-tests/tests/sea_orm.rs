@@ -0,0 +1,61 @@
+//! Refined columns of SeaORM entities, backed by a mock database.
+use std::collections::BTreeMap;
+
+use refined::{boundable::signed::LessThan, Refinement, RefinementError, RefinementOps};
+use sea_orm::{
+    entity::prelude::*,
+    sea_query::{ColumnType, ValueType},
+    ActiveValue, DatabaseBackend, IntoActiveValue, MockDatabase,
+};
+
+type Size = Refinement<i32, LessThan<100>>;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "item")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub size: Size,
+    pub limit: Option<Size>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn row(size: i32) -> BTreeMap<&'static str, Value> {
+    BTreeMap::from([
+        ("id", 1.into()),
+        ("size", size.into()),
+        ("limit", Option::<i32>::None.into()),
+    ])
+}
+
+#[test]
+fn test_value_type() {
+    assert_eq!(*<Size as ValueType>::try_from(5.into()).unwrap(), 5);
+    assert!(<Size as ValueType>::try_from(150.into()).is_err());
+    assert_eq!(Value::from(Size::refine(5).unwrap()), Value::Int(Some(5)));
+    assert_eq!(Size::column_type(), ColumnType::Integer);
+    assert_eq!(
+        Size::refine(5).unwrap().into_active_value(),
+        ActiveValue::Set(Size::refine(5).unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_entity() {
+    let db = MockDatabase::new(DatabaseBackend::Sqlite)
+        .append_query_results([vec![row(5)], vec![row(150)]])
+        .into_connection();
+
+    let model = Entity::find().one(&db).await.unwrap().unwrap();
+    assert_eq!(*model.size, 5);
+    assert_eq!(model.limit, None);
+
+    let DbErr::TryIntoErr { source, .. } = Entity::find().one(&db).await.unwrap_err() else {
+        panic!("expected a conversion error");
+    };
+    assert!(source.downcast_ref::<RefinementError>().is_some());
+}
@@ -0,0 +1,27 @@
+//! Round trips of refinements through sqlx, backed by an in-memory SQLite database.
+use refined::{boundable::signed::LessThan, Refinement, RefinementError, RefinementOps};
+use sqlx::{Connection, Row, SqliteConnection};
+
+type Size = Refinement<i64, LessThan<100>>;
+
+#[tokio::test]
+async fn test_round_trip() {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    let row = sqlx::query("SELECT ? + 1")
+        .bind(Size::refine(5).unwrap())
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(*row.get::<Size, _>(0), 6);
+
+    let err = sqlx::query("SELECT 150")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap()
+        .try_get::<Size, _>(0)
+        .unwrap_err();
+    let sqlx::Error::ColumnDecode { source, .. } = err else {
+        panic!("expected a decode error");
+    };
+    assert!(source.downcast_ref::<RefinementError>().is_some());
+}
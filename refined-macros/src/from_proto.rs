@@ -0,0 +1,169 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, spanned::Spanned, Attribute, Data, DeriveInput, Fields, Ident, LitStr, Path,
+};
+
+/// The options of a single field.
+struct Field<'a> {
+    ident: &'a Ident,
+    ty: &'a syn::Type,
+    proto: Ident,
+    required: bool,
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "FromProto can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "FromProto can only be derived for structs",
+            ))
+        }
+    };
+    let message = message(&input)?;
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named fields have identifiers");
+            let (proto, required) = field_options(&field.attrs, ident)?;
+            Ok(Field {
+                ident,
+                ty: &field.ty,
+                proto,
+                required,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let value = format_ident!("__refined_message");
+    let errors = format_ident!("__refined_errors");
+    let idents: Vec<_> = fields.iter().map(|field| field.ident).collect();
+    let checks = fields.iter().map(|field| {
+        let Field {
+            ident,
+            ty,
+            proto,
+            required,
+        } = field;
+        let name = proto.unraw().to_string();
+        let result = if *required {
+            quote! {
+                ::refined::proto::required(#value.#proto)
+                    .and_then(<#ty as ::refined::proto::FromProto<_>>::from_proto)
+            }
+        } else {
+            quote!(<#ty as ::refined::proto::FromProto<_>>::from_proto(#value.#proto))
+        };
+        quote!(let #ident = #errors.nest(#name, #result);)
+    });
+    let conversions = fields.iter().map(|field| {
+        let Field {
+            ident,
+            ty,
+            proto,
+            required,
+        } = field;
+        let converted = quote!(<#ty as ::refined::proto::IntoProto<_>>::into_proto(#value.#ident));
+        if *required {
+            quote!(#proto: ::core::option::Option::Some(#converted))
+        } else {
+            quote!(#proto: #converted)
+        }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<#message> for #ident #ty_generics #where_clause {
+            type Error = ::refined::error::RefinementErrors;
+
+            fn try_from(#value: #message) -> ::core::result::Result<Self, Self::Error> {
+                let mut #errors = ::refined::error::RefinementErrors::new();
+                #(#checks)*
+                match (#(#idents,)*) {
+                    (#(::core::option::Option::Some(#idents),)*) => ::core::result::Result::Ok(Self { #(#idents),* }),
+                    _ => ::core::result::Result::Err(#errors),
+                }
+            }
+        }
+
+        impl #impl_generics ::refined::proto::FromProto<#message> for #ident #ty_generics #where_clause {
+            fn from_proto(#value: #message) -> ::core::result::Result<Self, ::refined::error::RefinementErrors> {
+                ::core::convert::TryFrom::try_from(#value)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for #message #where_clause {
+            #[allow(clippy::needless_update)]
+            fn from(#value: #ident #ty_generics) -> Self {
+                Self {
+                    #(#conversions,)*
+                    ..::core::default::Default::default()
+                }
+            }
+        }
+
+        impl #impl_generics ::refined::proto::IntoProto<#message> for #ident #ty_generics #where_clause {
+            fn into_proto(self) -> #message {
+                ::core::convert::From::from(self)
+            }
+        }
+    })
+}
+
+/// The message type named by the `#[proto(message = ...)]` attribute of the struct.
+fn message(input: &DeriveInput) -> syn::Result<Path> {
+    let mut message = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("proto"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("message") {
+                message = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `message = ...`"))
+            }
+        })?;
+    }
+    message.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "FromProto requires the message type, e.g. `#[proto(message = ItemMessage)]`",
+        )
+    })
+}
+
+/// The name of the message field of a struct field and whether it is `#[proto(required)]`.
+fn field_options(attrs: &[Attribute], ident: &Ident) -> syn::Result<(Ident, bool)> {
+    let mut proto = ident.clone();
+    let mut required = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("proto")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("required") {
+                required = true;
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                let name: LitStr = meta.value()?.parse()?;
+                proto = name.parse_with(Ident::parse_any)?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `required` or `rename = \"...\"`"))
+            }
+        })?;
+    }
+    Ok((proto, required))
+}
@@ -0,0 +1,154 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{spanned::Spanned, Data, DeriveInput, Expr, Fields, GenericParam, LitStr, Token};
+
+/// A single `#[invariant(...)]` of a struct.
+struct Invariant {
+    expr: Expr,
+    message: String,
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "Invariants can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Invariants can only be derived for structs",
+            ))
+        }
+    };
+
+    let invariants = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("invariant"))
+        .map(|attr| attr.parse_args_with(parse_invariant))
+        .collect::<syn::Result<Vec<_>>>()?;
+    if invariants.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "expected at least one `#[invariant(...)]` attribute",
+        ));
+    }
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let predicate = format_ident!("{}Invariants", ident);
+    let alias = format_ident!("Valid{}", ident);
+    let idents: Vec<_> = fields
+        .iter()
+        .map(|field| field.ident.as_ref().expect("named fields have identifiers"))
+        .collect();
+    let exprs: Vec<_> = invariants.iter().map(|invariant| &invariant.expr).collect();
+    let messages: Vec<_> = invariants
+        .iter()
+        .map(|invariant| &invariant.message)
+        .collect();
+    let error = messages
+        .iter()
+        .map(|message| message.as_str())
+        .collect::<Vec<_>>()
+        .join(" and ");
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let params: Vec<_> = input
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                let ty = &param.ty;
+                quote!(const #ident: #ty)
+            }
+        })
+        .collect();
+    let params = (!params.is_empty()).then(|| quote!(<#(#params),*>));
+    let predicate_doc = format!(
+        "The invariants of [`{}`], which must all hold for it to be refined.",
+        ident
+    );
+    let alias_doc = format!("A [`{}`] whose invariants hold.", ident);
+    // Fields are bound by reference so that each invariant can refer to them by name.
+    let bindings = quote! {
+        #[allow(unused_variables)]
+        let #ident { #(#idents),* } = value;
+    };
+
+    Ok(quote! {
+        #[doc = #predicate_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #vis struct #predicate;
+
+        #[doc = #alias_doc]
+        #vis type #alias #params = ::refined::Refinement<#ident #ty_generics, #predicate>;
+
+        impl #impl_generics ::refined::Predicate<#ident #ty_generics> for #predicate #where_clause {
+            fn test(value: &#ident #ty_generics) -> bool {
+                #bindings
+                #((#exprs))&&*
+            }
+
+            fn error() -> ::refined::ErrorMessage {
+                ::refined::ErrorMessage::from(#error)
+            }
+
+            fn kind() -> ::refined::error::ErrorKind {
+                ::refined::error::ErrorKind::And
+            }
+
+            fn violation(value: &#ident #ty_generics) -> ::refined::RefinementError {
+                #bindings
+                let causes = [#(
+                    (!(#exprs)).then(|| ::refined::RefinementError::new(
+                        ::refined::error::ErrorKind::Custom,
+                        ::refined::ErrorMessage::from(#messages),
+                    ))
+                ),*];
+                ::refined::RefinementError::deferred(
+                    ::refined::error::ErrorKind::And,
+                    <Self as ::refined::Predicate<#ident #ty_generics>>::error,
+                )
+                .with_causes(::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter(causes).flatten()))
+            }
+
+            fn explain(value: &#ident #ty_generics) -> ::refined::explain::Explanation {
+                #bindings
+                let children = [#(
+                    ::refined::explain::Explanation::new(
+                        ::refined::error::ErrorKind::Custom,
+                        ::refined::ErrorMessage::from(#messages),
+                        #exprs,
+                    )
+                ),*];
+                ::refined::explain::Explanation::of::<#ident #ty_generics, Self>(value)
+                    .with_children(::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter(children)))
+            }
+        }
+    })
+}
+
+/// Parses the contents of `#[invariant(expr)]` or `#[invariant(expr, "message")]`.
+fn parse_invariant(input: syn::parse::ParseStream) -> syn::Result<Invariant> {
+    let expr: Expr = input.parse()?;
+    let message = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+        input.parse::<LitStr>()?.value()
+    } else {
+        format!("must satisfy `{}`", expr.to_token_stream())
+    };
+    if !input.is_empty() {
+        return Err(input.error("expected `#[invariant(expression, \"message\")]`"));
+    }
+    Ok(Invariant { expr, message })
+}
@@ -0,0 +1,261 @@
+//! Procedural macros for [refined](https://docs.rs/refined).
+//!
+//! This crate should not be used directly; enable the `macros` feature of `refined` instead, which
+//! re-exports everything defined here.
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemFn, ItemStruct};
+
+mod from_proto;
+mod invariants;
+mod named_fields;
+mod refined;
+mod refined_args;
+
+/// Wraps each field of a struct in a [Named](https://docs.rs/refined/latest/refined/struct.Named.html)
+/// refinement, named after the field.
+///
+/// This removes the need to declare a `type_string!` for every field whose errors should carry
+/// its name. Each field must be a refinement (a type implementing `RefinementOps`); other fields
+/// can be excluded with `#[named(skip)]`. The name of a field can be changed with
+/// `#[named(rename = "...")]`.
+///
+/// Passing `serde` (i.e. `#[named_fields(serde)]`) wraps fields in `NamedSerde` instead, so that
+/// the struct can derive `Serialize` and `Deserialize`.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::{ClosedInterval, LessThanEqual}, named_fields};
+///
+/// #[named_fields]
+/// #[derive(Debug)]
+/// struct Frobnicator {
+///     name: Refinement<String, ClosedInterval<1, 10>>,
+///     #[named(rename = "frobnicator size")]
+///     size: Refinement<u8, LessThanEqual<100>>,
+///     #[named(skip)]
+///     enabled: bool,
+/// }
+///
+/// fn frobnicator(name: String, size: u8) -> Result<Frobnicator, RefinementError> {
+///     Ok(Frobnicator {
+///         name: RefinementOps::refine(name)?,
+///         size: RefinementOps::refine(size)?,
+///         enabled: true,
+///     })
+/// }
+///
+/// assert!(frobnicator("Good name".to_string(), 99).is_ok());
+/// assert_eq!(
+///     frobnicator("Bad name, too long".to_string(), 99).unwrap_err().to_string(),
+///     "refinement violated: name must be less than or equal to 10"
+/// );
+/// assert_eq!(
+///     frobnicator("Good name".to_string(), 123).unwrap_err().to_string(),
+///     "refinement violated: frobnicator size must be less than or equal to 100"
+/// );
+/// ```
+///
+/// With `serde`:
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThanEqual, named_fields};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[named_fields(serde)]
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Frobnicator {
+///     size: Refinement<u8, LessThanEqual<100>>,
+/// }
+///
+/// let err = serde_json::from_str::<Frobnicator>(r#"{"size": 123}"#).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "refinement violated: size must be less than or equal to 100 at line 1 column 13"
+/// );
+/// ```
+#[proc_macro_attribute]
+pub fn named_fields(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemStruct);
+    named_fields::expand(attr.into(), item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates a `new` constructor for a struct of refined fields that refines every field,
+/// reporting all failures rather than only the first.
+///
+/// The constructor accepts the raw (unrefined) value of each field. On failure, every error is
+/// returned in a [RefinementErrors](https://docs.rs/refined/latest/refined/error/struct.RefinementErrors.html),
+/// each named after the field that it belongs to (unless the field is already
+/// [Named](https://docs.rs/refined/latest/refined/struct.Named.html)). Fields that are not refinements
+/// must be marked `#[refined(skip)]`; they are passed to the constructor as-is.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::{ClosedInterval, LessThanEqual}, Refined};
+///
+/// #[derive(Debug, Refined)]
+/// struct Frobnicator {
+///     name: Refinement<String, ClosedInterval<1, 10>>,
+///     size: Refinement<u8, LessThanEqual<100>>,
+///     #[refined(skip)]
+///     enabled: bool,
+/// }
+///
+/// assert!(Frobnicator::new("Good name".to_string(), 99, true).is_ok());
+///
+/// let errors = Frobnicator::new("Bad name, too long".to_string(), 123, true).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(
+///     errors.to_string(),
+///     "refinement violated: name must be less than or equal to 10; \
+///      refinement violated: size must be less than or equal to 100"
+/// );
+/// ```
+#[proc_macro_derive(Refined, attributes(refined))]
+pub fn derive_refined(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    refined::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates a predicate for the invariants of a struct that relate several of its fields, such as
+/// `start <= end`.
+///
+/// Each invariant is declared with `#[invariant(expression)]` or
+/// `#[invariant(expression, "message")]` on the struct. Within the expression, every field of the
+/// struct is bound by reference to its name. This derives a predicate named `<Struct>Invariants`
+/// that holds when every invariant holds, along with an alias `Valid<Struct>` for the
+/// `Refinement` of the struct by that predicate. When refinement fails, the error reports every
+/// invariant that does not hold; invariants without a message are described by their expression.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, Invariants};
+///
+/// #[derive(Debug, Invariants)]
+/// #[invariant(start <= end)]
+/// #[invariant(password == confirmation, "passwords must match")]
+/// struct Signup {
+///     start: u32,
+///     end: u32,
+///     password: String,
+///     confirmation: String,
+/// }
+///
+/// let signup = |start, end, password: &str, confirmation: &str| {
+///     ValidSignup::refine(Signup {
+///         start,
+///         end,
+///         password: password.to_string(),
+///         confirmation: confirmation.to_string(),
+///     })
+/// };
+///
+/// assert!(signup(1, 2, "hunter2", "hunter2").is_ok());
+/// assert_eq!(
+///     signup(3, 2, "hunter2", "hunter3").unwrap_err().to_string(),
+///     "refinement violated: must satisfy `start <= end` and passwords must match"
+/// );
+/// assert_eq!(
+///     signup(1, 2, "hunter2", "").unwrap_err().to_string(),
+///     "refinement violated: passwords must match"
+/// );
+/// ```
+#[proc_macro_derive(Invariants, attributes(invariant))]
+pub fn derive_invariants(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    invariants::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates the conversions between a struct of refined fields and the protobuf message that it is
+/// decoded from.
+///
+/// The message type is named with `#[proto(message = ...)]`. This derives `TryFrom<Message>`, which
+/// refines every field of the message and reports all failures in a
+/// [RefinementErrors](https://docs.rs/refined/latest/refined/error/struct.RefinementErrors.html),
+/// each named after the path of its field (e.g. `item.size` or `extras[1].name`), along with
+/// `From<Struct>` for the message. Fields are converted with the `FromProto` and `IntoProto` traits
+/// of the `proto` feature of `refined`, so they may be refinements, plain values, other structs that
+/// derive `FromProto`, or [Option]s and [Vec]s of these. The message must implement [Default], as
+/// prost messages do, so that fields that the struct omits are defaulted.
+///
+/// A field that reads a differently named message field is marked `#[proto(rename = "...")]`.
+/// Message fields are always optional in protobuf; `#[proto(required)]` converts a field of
+/// `Option<Message>` into a struct that must be present.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan, string::Trimmed, FromProto};
+///
+/// #[derive(Clone, PartialEq, Default)]
+/// struct ItemMessage {
+///     name: String,
+///     size: u32,
+/// }
+///
+/// #[derive(Debug, FromProto)]
+/// #[proto(message = ItemMessage)]
+/// struct Item {
+///     #[proto(rename = "name")]
+///     label: Refinement<String, Trimmed>,
+///     size: Refinement<u32, LessThan<100>>,
+/// }
+///
+/// let message = ItemMessage { name: " widget".to_string(), size: 150 };
+/// assert_eq!(
+///     Item::try_from(message).unwrap_err().to_string(),
+///     "refinement violated: name must not start or end with whitespace; \
+///      refinement violated: size must be less than 100"
+/// );
+/// ```
+#[proc_macro_derive(FromProto, attributes(proto))]
+pub fn derive_from_proto(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    from_proto::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Refines the arguments of a function that are annotated with `#[refine(Predicate)]`.
+///
+/// Annotated arguments keep their raw types in the signature of the function, so that callers do
+/// not need to refine values themselves. Within the body of the function, each annotated argument
+/// is shadowed by its `Refinement`. If any argument fails refinement, the function returns early
+/// with the error (named after the argument), converted with [From] into the error type of the
+/// function.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::{LessThan, NonZero}, refined_args};
+///
+/// #[refined_args]
+/// fn resize(
+///     #[refine(LessThan<4096>)] width: u32,
+///     #[refine(NonZero)] height: u32,
+/// ) -> Result<u32, RefinementError> {
+///     let width: Refinement<u32, LessThan<4096>> = width;
+///     Ok(*width * *height)
+/// }
+///
+/// assert_eq!(resize(100, 2).unwrap(), 200);
+/// assert_eq!(
+///     resize(5000, 2).unwrap_err().to_string(),
+///     "refinement violated: width must be less than 4096"
+/// );
+/// ```
+#[proc_macro_attribute]
+pub fn refined_args(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemFn);
+    refined_args::expand(attr.into(), item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
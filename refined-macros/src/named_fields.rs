@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse::Parser, punctuated::Punctuated, spanned::Spanned, Attribute, Expr,
+    ExprLit, Fields, Ident, ItemStruct, Lit, Meta, Token,
+};
+
+/// How a single field should be named.
+enum FieldName {
+    Skip,
+    Name(String),
+}
+
+pub fn expand(attr: TokenStream, mut item: ItemStruct) -> syn::Result<TokenStream> {
+    let wrapper = if attr.is_empty() {
+        quote!(::refined::Named)
+    } else {
+        let ident: Ident = syn::parse2(attr)?;
+        if ident != "serde" {
+            return Err(syn::Error::new(ident.span(), "expected `serde`"));
+        }
+        quote!(::refined::NamedSerde)
+    };
+
+    let fields = match &mut item.fields {
+        Fields::Named(fields) => &mut fields.named,
+        fields => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "named_fields can only be applied to structs with named fields",
+            ))
+        }
+    };
+
+    let vis = &item.vis;
+    let module = format_ident!("__refined_names_{}", item.ident);
+    let mut names = Vec::new();
+    for field in fields.iter_mut() {
+        let ident = field.ident.clone().expect("named fields have identifiers");
+        let name = match field_name(&mut field.attrs)? {
+            Some(FieldName::Skip) => continue,
+            Some(FieldName::Name(name)) => name,
+            None => ident.unraw().to_string(),
+        };
+        let ty = &field.ty;
+        field.ty = syn::parse_quote!(#wrapper<#module::#ident, #ty>);
+        names.push(quote! {
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+            pub struct #ident;
+
+            impl ::refined::TypeString for #ident {
+                const VALUE: &'static str = #name;
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types, non_snake_case)]
+        #vis mod #module {
+            #(#names)*
+        }
+
+        #item
+    })
+}
+
+/// Extracts (and removes) the `#[named(...)]` attribute of a field.
+fn field_name(attrs: &mut Vec<Attribute>) -> syn::Result<Option<FieldName>> {
+    let mut result = None;
+    let mut error = None;
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("named") {
+            return true;
+        }
+        match parse_named(attr) {
+            Ok(name) => result = Some(name),
+            Err(err) => error = Some(err),
+        }
+        false
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(result),
+    }
+}
+
+const USAGE: &str = "expected `#[named(skip)]` or `#[named(rename = \"...\")]`";
+
+fn parse_named(attr: &Attribute) -> syn::Result<FieldName> {
+    let Meta::List(list) = &attr.meta else {
+        return Err(syn::Error::new(attr.span(), USAGE));
+    };
+    let metas = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(list.tokens.clone())?;
+    match metas.first() {
+        Some(Meta::Path(path)) if metas.len() == 1 && path.is_ident("skip") => Ok(FieldName::Skip),
+        Some(Meta::NameValue(nv)) if metas.len() == 1 && nv.path.is_ident("rename") => {
+            match &nv.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(name),
+                    ..
+                }) => Ok(FieldName::Name(name.value())),
+                value => Err(syn::Error::new(value.span(), "expected a string literal")),
+            }
+        }
+        _ => Err(syn::Error::new(list.span(), USAGE)),
+    }
+}
@@ -0,0 +1,88 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, spanned::Spanned, Attribute, Data, DeriveInput, Fields};
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "Refined can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Refined can only be derived for structs",
+            ))
+        }
+    };
+
+    let errors = format_ident!("__refined_errors");
+    let mut params = Vec::new();
+    let mut checks = Vec::new();
+    let mut refined = Vec::new();
+    let mut idents = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let ty = &field.ty;
+        idents.push(ident);
+        if is_skipped(&field.attrs)? {
+            params.push(quote!(#ident: #ty));
+            continue;
+        }
+        let name = ident.unraw().to_string();
+        params.push(quote!(#ident: <#ty as ::refined::RefinementOps>::T));
+        checks.push(quote! {
+            let #ident = #errors.check(#name, <#ty as ::refined::RefinementOps>::refine(#ident));
+        });
+        refined.push(ident);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let doc = format!(
+        "Creates a new `{}`, refining each of its fields.\n\nIf any field cannot be refined, every refinement error is returned.",
+        ident
+    );
+    let body = if refined.is_empty() {
+        quote!(::core::result::Result::Ok(Self { #(#idents),* }))
+    } else {
+        quote! {
+            let mut #errors = ::refined::error::RefinementErrors::new();
+            #(#checks)*
+            match (#(#refined,)*) {
+                (#(::core::option::Option::Some(#refined),)*) => ::core::result::Result::Ok(Self { #(#idents),* }),
+                _ => ::core::result::Result::Err(#errors),
+            }
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #doc]
+            #[allow(clippy::too_many_arguments)]
+            pub fn new(#(#params),*) -> ::core::result::Result<Self, ::refined::error::RefinementErrors> {
+                #body
+            }
+        }
+    })
+}
+
+/// Whether a field is marked `#[refined(skip)]`.
+fn is_skipped(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("refined")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skipped = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skipped)
+}
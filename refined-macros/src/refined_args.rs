@@ -0,0 +1,52 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, spanned::Spanned, FnArg, ItemFn, Pat, Type};
+
+pub fn expand(attr: TokenStream, mut item: ItemFn) -> syn::Result<TokenStream> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new(
+            attr.span(),
+            "refined_args does not accept any arguments",
+        ));
+    }
+
+    let mut checks = Vec::new();
+    for arg in item.sig.inputs.iter_mut() {
+        let FnArg::Typed(arg) = arg else {
+            continue;
+        };
+        let Some(index) = arg
+            .attrs
+            .iter()
+            .position(|attr| attr.path().is_ident("refine"))
+        else {
+            continue;
+        };
+        let predicate: Type = arg.attrs.remove(index).parse_args()?;
+        let Pat::Ident(binding) = &mut *arg.pat else {
+            return Err(syn::Error::new(
+                arg.pat.span(),
+                "refined arguments must be bound to an identifier",
+            ));
+        };
+        // The raw argument is never mutated; only the refined binding may be.
+        let pat = binding.clone();
+        binding.mutability = None;
+        let ident = &pat.ident;
+        let name = ident.unraw().to_string();
+        let ty = &arg.ty;
+        checks.push(quote! {
+            let #pat = match ::refined::DynNamed::<::refined::Refinement<#ty, #predicate>>::refine(#name, #ident) {
+                ::core::result::Result::Ok(value) => value.into_inner(),
+                ::core::result::Result::Err(err) => return ::core::result::Result::Err(::core::convert::From::from(err)),
+            };
+        });
+    }
+
+    let block = &item.block;
+    item.block = syn::parse_quote!({
+        #(#checks)*
+        #block
+    });
+    Ok(quote!(#item))
+}
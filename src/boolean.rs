@@ -18,10 +18,12 @@
 //! ```
 
 #[cfg(feature = "alloc")]
-use alloc::format;
+use alloc::{format, vec, vec::Vec};
 use core::marker::PhantomData;
 
-use crate::{ErrorMessage, Predicate};
+#[cfg(feature = "alloc")]
+use crate::explain::Explanation;
+use crate::{error::ErrorKind, ErrorMessage, Predicate, RefinementError};
 
 /// Always `true`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -42,6 +44,10 @@ impl<T> Predicate<T> for True {
         "true predicate"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::True
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -66,20 +72,48 @@ impl<T> Predicate<T> for False {
         "false predicate"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::False
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
 }
 
+/// A predicate that every value satisfies; an alias of [True].
+///
+/// With the `implication` feature, every predicate implies `Always`, which makes it a useful
+/// default for generic code.
+pub type Always = True;
+
+/// A predicate that no value satisfies; an alias of [False].
+///
+/// With the `implication` feature, `Never` implies every predicate.
+pub type Never = False;
+
 /// Logical conjunction of two [predicates](Predicate).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct And<A, B>(pub(crate) PhantomData<A>, pub(crate) PhantomData<B>);
 
 impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for And<A, B> {
+    const BOUNDS: Option<(i128, i128)> = match (A::BOUNDS, B::BOUNDS) {
+        (Some((a_min, a_max)), Some((b_min, b_max))) => Some((
+            if a_min > b_min { a_min } else { b_min },
+            if a_max < b_max { a_max } else { b_max },
+        )),
+        (Some(bounds), None) | (None, Some(bounds)) => Some(bounds),
+        (None, None) => None,
+    };
+
     fn test(t: &T) -> bool {
         A::test(t) && B::test(t)
     }
 
+    fn normalize(value: T) -> T {
+        B::normalize(A::normalize(value))
+    }
+
     #[cfg(feature = "alloc")]
     fn error() -> ErrorMessage {
         format!("{} and {}", A::error(), B::error())
@@ -90,20 +124,56 @@ impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for And<A, B> {
         "conjunction"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::And
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).with_children(vec![A::explain(value), B::explain(value)])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !A::test(value) {
+            causes.push(A::violation(value));
+        }
+        if !B::test(value) {
+            causes.push(B::violation(value));
+        }
+        RefinementError::deferred(Self::kind(), Self::error).with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(value: &T) -> RefinementError {
+        if !A::test(value) {
+            A::violation(value)
+        } else {
+            B::violation(value)
+        }
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
 }
 
 /// Logical disjunction of two [predicates](Predicate).
+///
+/// As with [And], values are [normalized](Predicate::normalize) by `A` and then by `B`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Or<A, B>(PhantomData<A>, PhantomData<B>);
+pub struct Or<A, B>(pub(crate) PhantomData<A>, pub(crate) PhantomData<B>);
 
 impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Or<A, B> {
     fn test(t: &T) -> bool {
         A::test(t) || B::test(t)
     }
 
+    fn normalize(value: T) -> T {
+        B::normalize(A::normalize(value))
+    }
+
     #[cfg(feature = "alloc")]
     fn error() -> ErrorMessage {
         format!("{} or {}", A::error(), B::error())
@@ -114,12 +184,29 @@ impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Or<A, B> {
         "disjunction"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Or
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).with_children(vec![A::explain(value), B::explain(value)])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(value: &T) -> RefinementError {
+        RefinementError::deferred(Self::kind(), Self::error)
+            .with_causes(vec![A::violation(value), B::violation(value)])
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
 }
 
 /// Logical exclusive disjunction of two [predicates](Predicate).
+///
+/// As with [And], values are [normalized](Predicate::normalize) by `A` and then by `B`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Xor<A, B>(PhantomData<A>, PhantomData<B>);
 
@@ -128,6 +215,10 @@ impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Xor<A, B> {
         A::test(t) ^ B::test(t)
     }
 
+    fn normalize(value: T) -> T {
+        B::normalize(A::normalize(value))
+    }
+
     #[cfg(feature = "alloc")]
     fn error() -> ErrorMessage {
         format!("{} xor {}", A::error(), B::error())
@@ -138,6 +229,15 @@ impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Xor<A, B> {
         "exclusive disjunction"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Xor
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).with_children(vec![A::explain(value), B::explain(value)])
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -152,6 +252,10 @@ impl<T, P: Predicate<T>> Predicate<T> for Not<P> {
         !P::test(t)
     }
 
+    fn normalize(value: T) -> T {
+        P::normalize(value)
+    }
+
     #[cfg(feature = "alloc")]
     fn error() -> ErrorMessage {
         format!("not {}", P::error())
@@ -162,6 +266,15 @@ impl<T, P: Predicate<T>> Predicate<T> for Not<P> {
         "negation"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Not
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).with_children(vec![P::explain(value)])
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -205,6 +318,29 @@ mod tests {
         assert!(TestFalseFalse::refine(123).is_err());
     }
 
+    #[test]
+    fn test_and_bounds() {
+        use crate::boundable::{signed, unsigned};
+
+        assert_eq!(
+            <unsigned::ClosedInterval<5, 10> as Predicate<u8>>::BOUNDS,
+            Some((5, 10))
+        );
+        assert_eq!(
+            <And<signed::OpenInterval<-5, 5>, signed::LessThan<3>> as Predicate<i8>>::BOUNDS,
+            Some((-4, 2))
+        );
+        assert_eq!(
+            <And<unsigned::GreaterThan<3>, True> as Predicate<u8>>::BOUNDS,
+            Some((4, usize::MAX as i128))
+        );
+        assert_eq!(<And<True, False> as Predicate<u8>>::BOUNDS, None);
+        assert_eq!(
+            <unsigned::OpenInterval<5, 6> as Predicate<u8>>::BOUNDS,
+            Some((6, 5))
+        );
+    }
+
     #[test]
     fn test_or() {
         type TestTrueFalse = Refinement<u8, Or<True, False>>;
@@ -0,0 +1,520 @@
+//! Statically knowable bounds of boundable predicates.
+
+use crate::{boolean::And, boundable::*, Predicate, SignedBoundable, UnsignedBoundable};
+use signed::{
+    GreaterThan as SGreaterThan, GreaterThanEqual as SGreaterThanEqual, LessThan as SLessThan,
+    LessThanEqual as SLessThanEqual,
+};
+use unsigned::{
+    GreaterThan as UGreaterThan, GreaterThanEqual as UGreaterThanEqual, LessThan as ULessThan,
+    LessThanEqual as ULessThanEqual,
+};
+
+/// A type that has a statically knowable unsigned maximum value.
+pub trait UnsignedMax<T: UnsignedBoundable>: Predicate<T> {
+    /// The maximum value.
+    const UMAX: usize;
+}
+
+impl<T: UnsignedBoundable, const MAX: usize> UnsignedMax<T> for unsigned::LessThan<MAX> {
+    const UMAX: usize = MAX - 1;
+}
+
+impl<T: UnsignedBoundable, const MAX: usize> UnsignedMax<T> for unsigned::LessThanEqual<MAX> {
+    const UMAX: usize = MAX;
+}
+
+impl<T: UnsignedBoundable, const VAL: usize> UnsignedMax<T> for unsigned::Equals<VAL> {
+    const UMAX: usize = VAL;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
+    for unsigned::ClosedInterval<MIN, MAX>
+{
+    const UMAX: usize = MAX;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
+    for unsigned::OpenClosedInterval<MIN, MAX>
+{
+    const UMAX: usize = MAX;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
+    for unsigned::OpenInterval<MIN, MAX>
+{
+    const UMAX: usize = MAX - 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
+    for unsigned::ClosedOpenInterval<MIN, MAX>
+{
+    const UMAX: usize = MAX - 1;
+}
+
+/// A type that has a statically knowable unsigned minimum value.
+pub trait UnsignedMin<T: UnsignedBoundable>: Predicate<T> {
+    /// The minimum value.
+    const UMIN: usize;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize> UnsignedMin<T> for unsigned::GreaterThan<MIN> {
+    const UMIN: usize = MIN + 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize> UnsignedMin<T> for unsigned::GreaterThanEqual<MIN> {
+    const UMIN: usize = MIN;
+}
+
+impl<T: UnsignedBoundable, const VAL: usize> UnsignedMin<T> for unsigned::Equals<VAL> {
+    const UMIN: usize = VAL;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
+    for unsigned::ClosedInterval<MIN, MAX>
+{
+    const UMIN: usize = MIN;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
+    for unsigned::OpenClosedInterval<MIN, MAX>
+{
+    const UMIN: usize = MIN + 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
+    for unsigned::OpenInterval<MIN, MAX>
+{
+    const UMIN: usize = MIN + 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
+    for unsigned::ClosedOpenInterval<MIN, MAX>
+{
+    const UMIN: usize = MIN;
+}
+
+/// A type that has a statically knowable unsigned minimum value and maximum value.
+pub trait UnsignedMinMax<T: UnsignedBoundable>:
+    Predicate<T> + UnsignedMin<T> + UnsignedMax<T>
+{
+}
+
+impl<T: UnsignedBoundable, const VAL: usize> UnsignedMinMax<T> for unsigned::Equals<VAL> {}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
+    for unsigned::ClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
+    for unsigned::OpenClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
+    for unsigned::OpenInterval<MIN, MAX>
+{
+}
+
+impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
+    for unsigned::ClosedOpenInterval<MIN, MAX>
+{
+}
+
+/// A type that has a statically knowable signed maximum value.
+pub trait SignedMax<T: SignedBoundable>: Predicate<T> {
+    /// The maximum value.
+    const UMAX: isize;
+}
+
+impl<T: SignedBoundable, const MAX: isize> SignedMax<T> for signed::LessThan<MAX> {
+    const UMAX: isize = MAX - 1;
+}
+
+impl<T: SignedBoundable, const MAX: isize> SignedMax<T> for signed::LessThanEqual<MAX> {
+    const UMAX: isize = MAX;
+}
+
+impl<T: SignedBoundable, const VAL: isize> SignedMax<T> for signed::Equals<VAL> {
+    const UMAX: isize = VAL;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
+    for signed::ClosedInterval<MIN, MAX>
+{
+    const UMAX: isize = MAX;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
+    for signed::OpenClosedInterval<MIN, MAX>
+{
+    const UMAX: isize = MAX;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
+    for signed::OpenInterval<MIN, MAX>
+{
+    const UMAX: isize = MAX - 1;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
+    for signed::ClosedOpenInterval<MIN, MAX>
+{
+    const UMAX: isize = MAX - 1;
+}
+
+/// A type that has a statically knowable signed minimum value.
+pub trait SignedMin<T: SignedBoundable>: Predicate<T> {
+    /// The minimum value.
+    const UMIN: isize;
+}
+
+impl<T: SignedBoundable, const MIN: isize> SignedMin<T> for signed::GreaterThan<MIN> {
+    const UMIN: isize = MIN + 1;
+}
+
+impl<T: SignedBoundable, const MIN: isize> SignedMin<T> for signed::GreaterThanEqual<MIN> {
+    const UMIN: isize = MIN;
+}
+
+impl<T: SignedBoundable, const VAL: isize> SignedMin<T> for signed::Equals<VAL> {
+    const UMIN: isize = VAL;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
+    for signed::ClosedInterval<MIN, MAX>
+{
+    const UMIN: isize = MIN;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
+    for signed::OpenClosedInterval<MIN, MAX>
+{
+    const UMIN: isize = MIN + 1;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
+    for signed::OpenInterval<MIN, MAX>
+{
+    const UMIN: isize = MIN + 1;
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
+    for signed::ClosedOpenInterval<MIN, MAX>
+{
+    const UMIN: isize = MIN;
+}
+
+/// A type that has a statically knowable signed minimum value and maximum value.
+pub trait SignedMinMax<T: SignedBoundable>: Predicate<T> + SignedMin<T> + SignedMax<T> {}
+
+impl<T: SignedBoundable, const VAL: isize> SignedMinMax<T> for signed::Equals<VAL> {}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
+    for signed::ClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
+    for signed::OpenClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
+    for signed::OpenInterval<MIN, MAX>
+{
+}
+
+impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
+    for signed::ClosedOpenInterval<MIN, MAX>
+{
+}
+
+/// A predicate that holds for exactly the unsigned values within a statically knowable range.
+///
+/// Predicates with only one bound extend to the limit of `usize` on the other side.
+pub trait UnsignedRange<T: UnsignedBoundable>: Predicate<T> {
+    /// The minimum value.
+    const MIN: usize;
+    /// The maximum value.
+    const MAX: usize;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize> UnsignedRange<T> for UGreaterThan<MIN> {
+    const MIN: usize = MIN + 1;
+    const MAX: usize = usize::MAX;
+}
+
+impl<T: UnsignedBoundable, const MIN: usize> UnsignedRange<T> for UGreaterThanEqual<MIN> {
+    const MIN: usize = MIN;
+    const MAX: usize = usize::MAX;
+}
+
+impl<T: UnsignedBoundable, const MAX: usize> UnsignedRange<T> for ULessThan<MAX> {
+    const MIN: usize = 0;
+    const MAX: usize = MAX - 1;
+}
+
+impl<T: UnsignedBoundable, const MAX: usize> UnsignedRange<T> for ULessThanEqual<MAX> {
+    const MIN: usize = 0;
+    const MAX: usize = MAX;
+}
+
+impl<T: UnsignedBoundable, const VAL: usize> UnsignedRange<T> for unsigned::Equals<VAL> {
+    const MIN: usize = VAL;
+    const MAX: usize = VAL;
+}
+
+impl<T: UnsignedBoundable, A: UnsignedRange<T>, B: UnsignedRange<T>> UnsignedRange<T>
+    for And<A, B>
+{
+    const MIN: usize = if A::MIN > B::MIN { A::MIN } else { B::MIN };
+    const MAX: usize = if A::MAX < B::MAX { A::MAX } else { B::MAX };
+}
+
+/// A predicate that holds for exactly the signed values within a statically knowable range.
+///
+/// Predicates with only one bound extend to the limit of `isize` on the other side.
+pub trait SignedRange<T: SignedBoundable>: Predicate<T> {
+    /// The minimum value.
+    const MIN: isize;
+    /// The maximum value.
+    const MAX: isize;
+}
+
+impl<T: SignedBoundable, const MIN: isize> SignedRange<T> for SGreaterThan<MIN> {
+    const MIN: isize = MIN + 1;
+    const MAX: isize = isize::MAX;
+}
+
+impl<T: SignedBoundable, const MIN: isize> SignedRange<T> for SGreaterThanEqual<MIN> {
+    const MIN: isize = MIN;
+    const MAX: isize = isize::MAX;
+}
+
+impl<T: SignedBoundable, const MAX: isize> SignedRange<T> for SLessThan<MAX> {
+    const MIN: isize = isize::MIN;
+    const MAX: isize = MAX - 1;
+}
+
+impl<T: SignedBoundable, const MAX: isize> SignedRange<T> for SLessThanEqual<MAX> {
+    const MIN: isize = isize::MIN;
+    const MAX: isize = MAX;
+}
+
+impl<T: SignedBoundable, const VAL: isize> SignedRange<T> for signed::Equals<VAL> {
+    const MIN: isize = VAL;
+    const MAX: isize = VAL;
+}
+
+impl<T: SignedBoundable, A: SignedRange<T>, B: SignedRange<T>> SignedRange<T> for And<A, B> {
+    const MIN: isize = if A::MIN > B::MIN { A::MIN } else { B::MIN };
+    const MAX: isize = if A::MAX < B::MAX { A::MAX } else { B::MAX };
+}
+
+/// Predicates with both a lower and an upper bound, into which values can be clamped.
+pub trait Clamp<T>: Predicate<T> {
+    /// Clamps `value` into the bounds of the predicate.
+    ///
+    /// If a bound cannot be represented by `T`, the value is returned unchanged.
+    fn clamp(value: T) -> T;
+}
+
+macro_rules! unsigned_clamp {
+    ($($p:ty),*) => {
+        $(
+            impl<T, const MIN: usize, const MAX: usize> Clamp<T> for $p
+            where
+                T: UnsignedBoundable + TryFrom<usize>,
+            {
+                fn clamp(value: T) -> T {
+                    let bound = value.bounding_value();
+                    let clamped = if bound < <Self as UnsignedMin<T>>::UMIN {
+                        T::try_from(<Self as UnsignedMin<T>>::UMIN)
+                    } else if bound > <Self as UnsignedMax<T>>::UMAX {
+                        T::try_from(<Self as UnsignedMax<T>>::UMAX)
+                    } else {
+                        return value;
+                    };
+                    clamped.unwrap_or(value)
+                }
+            }
+        )*
+    };
+}
+
+unsigned_clamp!(
+    unsigned::OpenInterval<MIN, MAX>,
+    unsigned::OpenClosedInterval<MIN, MAX>,
+    unsigned::ClosedOpenInterval<MIN, MAX>,
+    unsigned::ClosedInterval<MIN, MAX>
+);
+
+macro_rules! signed_clamp {
+    ($($p:ty),*) => {
+        $(
+            impl<T, const MIN: isize, const MAX: isize> Clamp<T> for $p
+            where
+                T: SignedBoundable + TryFrom<isize>,
+            {
+                fn clamp(value: T) -> T {
+                    let bound = value.bounding_value();
+                    let clamped = if bound < <Self as SignedMin<T>>::UMIN {
+                        T::try_from(<Self as SignedMin<T>>::UMIN)
+                    } else if bound > <Self as SignedMax<T>>::UMAX {
+                        T::try_from(<Self as SignedMax<T>>::UMAX)
+                    } else {
+                        return value;
+                    };
+                    clamped.unwrap_or(value)
+                }
+            }
+        )*
+    };
+}
+
+signed_clamp!(
+    signed::OpenInterval<MIN, MAX>,
+    signed::OpenClosedInterval<MIN, MAX>,
+    signed::ClosedOpenInterval<MIN, MAX>,
+    signed::ClosedInterval<MIN, MAX>
+);
+
+/// Predicates with a single bound or an interval, onto which out-of-range values can be saturated.
+///
+/// Intervals saturate values as they [clamp](Clamp) them.
+pub trait Saturate<T>: Predicate<T> {
+    /// Saturates `value` to the nearest value satisfying the predicate.
+    ///
+    /// If the bound cannot be represented by `T`, the value is returned unchanged.
+    fn saturate(value: T) -> T;
+}
+
+macro_rules! saturate {
+    ($boundable:ident, $bound:ty, $($p:ident => $trait:ident::$c:ident $cmp:tt),*) => {
+        $(
+            impl<T, const B: $bound> Saturate<T> for $p<B>
+            where
+                T: $boundable + TryFrom<$bound>,
+            {
+                fn saturate(value: T) -> T {
+                    let limit = <Self as $trait<T>>::$c;
+                    if value.bounding_value() $cmp limit {
+                        T::try_from(limit).unwrap_or(value)
+                    } else {
+                        value
+                    }
+                }
+            }
+        )*
+    };
+}
+
+saturate!(
+    UnsignedBoundable,
+    usize,
+    ULessThan => UnsignedMax::UMAX >,
+    ULessThanEqual => UnsignedMax::UMAX >,
+    UGreaterThan => UnsignedMin::UMIN <,
+    UGreaterThanEqual => UnsignedMin::UMIN <
+);
+
+saturate!(
+    SignedBoundable,
+    isize,
+    SLessThan => SignedMax::UMAX >,
+    SLessThanEqual => SignedMax::UMAX >,
+    SGreaterThan => SignedMin::UMIN <,
+    SGreaterThanEqual => SignedMin::UMIN <
+);
+
+macro_rules! interval_saturate {
+    ($bound:ty, $($p:ty),*) => {
+        $(
+            impl<T, const MIN: $bound, const MAX: $bound> Saturate<T> for $p
+            where
+                Self: Clamp<T>,
+            {
+                fn saturate(value: T) -> T {
+                    <Self as Clamp<T>>::clamp(value)
+                }
+            }
+        )*
+    };
+}
+
+interval_saturate!(
+    usize,
+    unsigned::OpenInterval<MIN, MAX>,
+    unsigned::OpenClosedInterval<MIN, MAX>,
+    unsigned::ClosedOpenInterval<MIN, MAX>,
+    unsigned::ClosedInterval<MIN, MAX>
+);
+
+interval_saturate!(
+    isize,
+    signed::OpenInterval<MIN, MAX>,
+    signed::OpenClosedInterval<MIN, MAX>,
+    signed::ClosedOpenInterval<MIN, MAX>,
+    signed::ClosedInterval<MIN, MAX>
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::*, *};
+
+    #[test]
+    fn test_unsigned_clamp() {
+        type Knob = Refinement<u8, unsigned::ClosedInterval<10, 20>>;
+        assert_eq!(*Knob::refine_clamped(5).unwrap(), 10);
+        assert_eq!(*Knob::refine_clamped(15).unwrap(), 15);
+        assert_eq!(*Knob::refine_clamped(25).unwrap(), 20);
+
+        type Open = Refinement<u8, unsigned::OpenInterval<10, 20>>;
+        assert_eq!(*Open::refine_clamped(0).unwrap(), 11);
+        assert_eq!(*Open::refine_clamped(255).unwrap(), 19);
+    }
+
+    #[test]
+    fn test_signed_clamp() {
+        type Knob = Refinement<i16, signed::ClosedOpenInterval<-10, 10>>;
+        assert_eq!(*Knob::refine_clamped(-100).unwrap(), -10);
+        assert_eq!(*Knob::refine_clamped(100).unwrap(), 9);
+    }
+
+    #[test]
+    fn test_clamp_unrepresentable() {
+        type Knob = Refinement<u8, unsigned::ClosedInterval<300, 400>>;
+        assert!(Knob::refine_clamped(5).is_err());
+    }
+
+    #[test]
+    fn test_unsigned_saturate() {
+        type Max = Refinement<u8, unsigned::LessThan<100>>;
+        assert_eq!(*Max::refine_saturating(150).unwrap(), 99);
+        assert_eq!(*Max::refine_saturating(50).unwrap(), 50);
+
+        type Min = Refinement<u8, unsigned::GreaterThanEqual<10>>;
+        assert_eq!(*Min::refine_saturating(5).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_signed_saturate() {
+        type Max = Refinement<i8, signed::LessThanEqual<-5>>;
+        assert_eq!(*Max::refine_saturating(0).unwrap(), -5);
+
+        type Min = Refinement<i8, signed::GreaterThan<-5>>;
+        assert_eq!(*Min::refine_saturating(-100).unwrap(), -4);
+    }
+
+    #[test]
+    fn test_interval_saturate() {
+        type Knob = Refinement<u8, unsigned::OpenInterval<10, 20>>;
+        assert_eq!(*Knob::refine_saturating(5).unwrap(), 11);
+        assert_eq!(*Knob::refine_saturating(25).unwrap(), 19);
+
+        type Offset = Refinement<i8, signed::ClosedInterval<-5, 5>>;
+        assert_eq!(*Offset::refine_saturating(-100).unwrap(), -5);
+    }
+}
@@ -8,5 +8,37 @@
 //! don't support any form of polymorphism), signed and unsigned bounding must be
 //! implemented independently. The signed numerics are implemented using signed bounds,
 //! while all other types are implemented using unsigned bounds.
+mod bounds;
 pub mod signed;
 pub mod unsigned;
+
+pub use bounds::*;
+
+use crate::boolean::{And, False, Not, Or, True, Xor};
+
+/// Marker for predicates whose result depends only on the bounding value of the tested type.
+///
+/// Because every numeric type reduces to the same bounding value after a lossless widening
+/// (e.g. `u8` to `u16`), refinements using these predicates can be widened without re-checking.
+pub trait BoundingPredicate {}
+
+impl<const MIN: usize> BoundingPredicate for unsigned::GreaterThan<MIN> {}
+impl<const MIN: usize> BoundingPredicate for unsigned::GreaterThanEqual<MIN> {}
+impl<const MAX: usize> BoundingPredicate for unsigned::LessThan<MAX> {}
+impl<const MAX: usize> BoundingPredicate for unsigned::LessThanEqual<MAX> {}
+impl<const DIV: usize, const MOD: usize> BoundingPredicate for unsigned::Modulo<DIV, MOD> {}
+impl<const VAL: usize> BoundingPredicate for unsigned::Equals<VAL> {}
+
+impl<const MIN: isize> BoundingPredicate for signed::GreaterThan<MIN> {}
+impl<const MIN: isize> BoundingPredicate for signed::GreaterThanEqual<MIN> {}
+impl<const MAX: isize> BoundingPredicate for signed::LessThan<MAX> {}
+impl<const MAX: isize> BoundingPredicate for signed::LessThanEqual<MAX> {}
+impl<const DIV: isize, const MOD: isize> BoundingPredicate for signed::Modulo<DIV, MOD> {}
+impl<const VAL: isize> BoundingPredicate for signed::Equals<VAL> {}
+
+impl BoundingPredicate for True {}
+impl BoundingPredicate for False {}
+impl<A: BoundingPredicate, B: BoundingPredicate> BoundingPredicate for And<A, B> {}
+impl<A: BoundingPredicate, B: BoundingPredicate> BoundingPredicate for Or<A, B> {}
+impl<A: BoundingPredicate, B: BoundingPredicate> BoundingPredicate for Xor<A, B> {}
+impl<P: BoundingPredicate> BoundingPredicate for Not<P> {}
@@ -13,9 +13,15 @@
 //! let not_ok = RangedI64::refine(11);
 //! assert!(not_ok.is_err());
 //! ```
-use crate::{boolean::*, ErrorMessage, Predicate};
 #[cfg(feature = "alloc")]
-use alloc::format;
+use crate::explain::Explanation;
+use crate::{
+    boolean::*,
+    error::{Bound, ErrorKind},
+    ErrorMessage, Predicate, RefinementError, StatefulPredicate,
+};
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
 
 /// Types that can be reduced to a signed size so that they can be bounded.
 pub trait SignedBoundable {
@@ -115,12 +121,59 @@ impl SignedBoundable for core::num::NonZeroI64 {
     }
 }
 
+/// A [SignedBoundable] type with a statically knowable minimum value.
+pub trait SignedBoundableMin: SignedBoundable {
+    /// The minimum value of the type.
+    const TYPE_MIN: isize;
+}
+
+macro_rules! signed_boundable_min {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SignedBoundableMin for $t {
+                const TYPE_MIN: isize = <$t>::MIN as isize;
+            }
+
+            impl SignedBoundableMin for core::num::Saturating<$t> {
+                const TYPE_MIN: isize = <$t>::MIN as isize;
+            }
+        )*
+    };
+}
+
+signed_boundable_min!(i8, i16, i32, isize);
+
+#[cfg(target_pointer_width = "64")]
+signed_boundable_min!(i64);
+
+macro_rules! non_zero_signed_boundable_min {
+    ($($t:ty => $n:ty),* $(,)?) => {
+        $(
+            impl SignedBoundableMin for $n {
+                const TYPE_MIN: isize = <$t>::MIN as isize;
+            }
+        )*
+    };
+}
+
+non_zero_signed_boundable_min!(
+    i8 => core::num::NonZeroI8,
+    i16 => core::num::NonZeroI16,
+    i32 => core::num::NonZeroI32,
+    isize => core::num::NonZeroIsize,
+);
+
+#[cfg(target_pointer_width = "64")]
+non_zero_signed_boundable_min!(i64 => core::num::NonZeroI64);
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct GreaterThan<const MIN: isize>;
 
 pub type GT<const MIN: isize> = GreaterThan<MIN>;
 
 impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThan<MIN> {
+    const BOUNDS: Option<(i128, i128)> = Some((MIN as i128 + 1, isize::MAX as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() > MIN
     }
@@ -135,6 +188,15 @@ impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThan<MIN> {
         "greater than"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::GreaterThan(Bound::Signed(MIN))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Signed(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -146,6 +208,8 @@ pub struct GreaterThanEqual<const MIN: isize>;
 pub type GTE<const MIN: isize> = GreaterThanEqual<MIN>;
 
 impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThanEqual<MIN> {
+    const BOUNDS: Option<(i128, i128)> = Some((MIN as i128, isize::MAX as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() >= MIN
     }
@@ -160,6 +224,15 @@ impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThanEqual<MIN
         "greater than equal"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::GreaterThanEqual(Bound::Signed(MIN))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Signed(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -171,6 +244,8 @@ pub struct LessThan<const MAX: isize>;
 pub type LT<const MAX: isize> = LessThan<MAX>;
 
 impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThan<MAX> {
+    const BOUNDS: Option<(i128, i128)> = Some((isize::MIN as i128, MAX as i128 - 1));
+
     fn test(value: &T) -> bool {
         value.bounding_value() < MAX
     }
@@ -185,6 +260,15 @@ impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThan<MAX> {
         "less than"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::LessThan(Bound::Signed(MAX))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Signed(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -196,6 +280,8 @@ pub struct LessThanEqual<const MAX: isize>;
 pub type LTE<const MAX: isize> = LessThanEqual<MAX>;
 
 impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThanEqual<MAX> {
+    const BOUNDS: Option<(i128, i128)> = Some((isize::MIN as i128, MAX as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() <= MAX
     }
@@ -210,6 +296,15 @@ impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThanEqual<MAX> {
         "less than equal"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::LessThanEqual(Bound::Signed(MAX))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Signed(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -241,6 +336,18 @@ impl<T: SignedBoundable, const DIV: isize, const MOD: isize> Predicate<T> for Mo
         "modulo"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Modulo {
+            divisor: Bound::Signed(DIV),
+            remainder: Bound::Signed(MOD),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Signed(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -256,6 +363,8 @@ pub type Odd = Not<Even>;
 pub struct Equals<const VAL: isize>;
 
 impl<T: SignedBoundable, const VAL: isize> Predicate<T> for Equals<VAL> {
+    const BOUNDS: Option<(i128, i128)> = Some((VAL as i128, VAL as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() == VAL
     }
@@ -270,6 +379,15 @@ impl<T: SignedBoundable, const VAL: isize> Predicate<T> for Equals<VAL> {
         "equals"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Equals(Bound::Signed(VAL))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Signed(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -287,6 +405,544 @@ pub type Negative = LT<0>;
 
 pub type NonNegative = Not<Negative>;
 
+/// The error of the runtime-bounded predicates when refined without their bounds.
+const STATELESS: &str = "must be refined with a runtime bound";
+
+/// A stateful counterpart of [GreaterThan] whose bound is provided at runtime.
+///
+/// Bounds that are only known at runtime, such as those loaded from configuration, cannot be
+/// expressed with const generics. These predicates are instead constructed with their bounds and
+/// used with [StatefulRefinementOps](crate::StatefulRefinementOps).
+///
+/// # Stateless refinement
+///
+/// Refining statelessly (e.g. with [RefinementOps](crate::RefinementOps) or when deserializing)
+/// has no bound to test against, so it **always fails**.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynGreaterThan {
+    min: isize,
+}
+
+impl DynGreaterThan {
+    pub const fn new(min: isize) -> Self {
+        Self { min }
+    }
+
+    pub const fn min(&self) -> isize {
+        self.min
+    }
+
+    fn holds(&self, value: isize) -> bool {
+        value > self.min
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be greater than {}", self.min)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "greater than"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::GreaterThan(Bound::Signed(self.min))
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynGreaterThan {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynGreaterThan {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [GreaterThanEqual] whose bound is provided at runtime. See [DynGreaterThan].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynGreaterThanEqual {
+    min: isize,
+}
+
+impl DynGreaterThanEqual {
+    pub const fn new(min: isize) -> Self {
+        Self { min }
+    }
+
+    pub const fn min(&self) -> isize {
+        self.min
+    }
+
+    fn holds(&self, value: isize) -> bool {
+        value >= self.min
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be greater than or equal to {}", self.min)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "greater than equal"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::GreaterThanEqual(Bound::Signed(self.min))
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynGreaterThanEqual {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynGreaterThanEqual {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [LessThan] whose bound is provided at runtime. See [DynGreaterThan].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynLessThan {
+    max: isize,
+}
+
+impl DynLessThan {
+    pub const fn new(max: isize) -> Self {
+        Self { max }
+    }
+
+    pub const fn max(&self) -> isize {
+        self.max
+    }
+
+    fn holds(&self, value: isize) -> bool {
+        value < self.max
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be less than {}", self.max)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "less than"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::LessThan(Bound::Signed(self.max))
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynLessThan {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynLessThan {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [LessThanEqual] whose bound is provided at runtime. See [DynGreaterThan].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynLessThanEqual {
+    max: isize,
+}
+
+impl DynLessThanEqual {
+    pub const fn new(max: isize) -> Self {
+        Self { max }
+    }
+
+    pub const fn max(&self) -> isize {
+        self.max
+    }
+
+    fn holds(&self, value: isize) -> bool {
+        value <= self.max
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be less than or equal to {}", self.max)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "less than equal"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::LessThanEqual(Bound::Signed(self.max))
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynLessThanEqual {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynLessThanEqual {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [OpenInterval] whose bounds are provided at runtime.
+///
+/// As with [OpenInterval], violations report whichever of the bounds failed. See [DynGreaterThan] for
+/// more information about runtime bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynOpenInterval {
+    min: DynGreaterThan,
+    max: DynLessThan,
+}
+
+impl DynOpenInterval {
+    pub const fn new(min: isize, max: isize) -> Self {
+        Self {
+            min: DynGreaterThan::new(min),
+            max: DynLessThan::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> isize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> isize {
+        self.max.max
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynOpenInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynOpenInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
+/// A stateful counterpart of [OpenClosedInterval] whose bounds are provided at runtime. See
+/// [DynOpenInterval].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynOpenClosedInterval {
+    min: DynGreaterThan,
+    max: DynLessThanEqual,
+}
+
+impl DynOpenClosedInterval {
+    pub const fn new(min: isize, max: isize) -> Self {
+        Self {
+            min: DynGreaterThan::new(min),
+            max: DynLessThanEqual::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> isize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> isize {
+        self.max.max
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynOpenClosedInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynOpenClosedInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
+/// A stateful counterpart of [ClosedOpenInterval] whose bounds are provided at runtime. See
+/// [DynOpenInterval].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynClosedOpenInterval {
+    min: DynGreaterThanEqual,
+    max: DynLessThan,
+}
+
+impl DynClosedOpenInterval {
+    pub const fn new(min: isize, max: isize) -> Self {
+        Self {
+            min: DynGreaterThanEqual::new(min),
+            max: DynLessThan::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> isize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> isize {
+        self.max.max
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynClosedOpenInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynClosedOpenInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
+/// A stateful counterpart of [ClosedInterval] whose bounds are provided at runtime. See
+/// [DynOpenInterval].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynClosedInterval {
+    min: DynGreaterThanEqual,
+    max: DynLessThanEqual,
+}
+
+impl DynClosedInterval {
+    pub const fn new(min: isize, max: isize) -> Self {
+        Self {
+            min: DynGreaterThanEqual::new(min),
+            max: DynLessThanEqual::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> isize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> isize {
+        self.max.max
+    }
+}
+
+impl<T: SignedBoundable> Predicate<T> for DynClosedInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: SignedBoundable> StatefulPredicate<T> for DynClosedInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -457,4 +1113,24 @@ mod tests {
         assert!(Test::refine(0).is_err());
         assert!(Test::refine(-2).is_err());
     }
+
+    #[test]
+    fn test_dyn_greater_than_equal() {
+        type Test = Refinement<i32, DynGreaterThanEqual>;
+        let predicate = DynGreaterThanEqual::new(-5);
+        assert!(Test::refine_with_state(&predicate, -5).is_ok());
+        let err = Test::refine_with_state(&predicate, -6).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::GreaterThanEqual(Bound::Signed(-5)));
+    }
+
+    #[test]
+    fn test_dyn_open_interval() {
+        type Test = Refinement<i8, DynOpenInterval>;
+        let predicate = DynOpenInterval::new(-10, 10);
+        assert!(Test::refine_with_state(&predicate, -9).is_ok());
+        assert!(Test::refine_with_state(&predicate, 9).is_ok());
+        assert!(Test::refine_with_state(&predicate, -10).is_err());
+        assert!(Test::refine_with_state(&predicate, 10).is_err());
+        assert!(Test::refine(0).is_err());
+    }
 }
@@ -14,7 +14,13 @@
 //! assert!(not_ok_string.is_err());
 //! ```
 
-use crate::{boolean::*, ErrorMessage, Predicate};
+#[cfg(feature = "alloc")]
+use crate::explain::Explanation;
+use crate::{
+    boolean::*,
+    error::{Bound, ErrorKind},
+    ErrorMessage, Predicate, RefinementError, StatefulPredicate,
+};
 #[cfg(feature = "alloc")]
 use alloc::{
     collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
@@ -151,9 +157,33 @@ macro_rules! unsigned_boundable_via_len {
     };
 }
 
+/// Creates [UnsignedBoundable] implementations for an owning pointer to a string or a slice, so
+/// that shared values such as `Arc<str>` are bounded by their length.
+#[cfg(feature = "alloc")]
+macro_rules! unsigned_boundable_via_deref {
+    ($($t:ident),* $(,)?) => {
+        $(
+            impl UnsignedBoundable for $t<str> {
+                fn bounding_value(&self) -> usize {
+                    self.len()
+                }
+            }
+
+            impl<T> UnsignedBoundable for $t<[T]> {
+                fn bounding_value(&self) -> usize {
+                    self.len()
+                }
+            }
+        )*
+    };
+}
+
 #[cfg(feature = "alloc")]
 mod needs_alloc {
     use super::*;
+    #[cfg(target_has_atomic = "ptr")]
+    use alloc::sync::Arc;
+    use alloc::{boxed::Box, rc::Rc};
 
     unsigned_boundable_via_len!(String);
     unsigned_boundable_via_len!(BinaryHeap<T>);
@@ -162,6 +192,10 @@ mod needs_alloc {
     unsigned_boundable_via_len!(LinkedList<T>);
     unsigned_boundable_via_len!(Vec<T>);
     unsigned_boundable_via_len!(VecDeque<T>);
+
+    unsigned_boundable_via_deref!(Box, Rc);
+    #[cfg(target_has_atomic = "ptr")]
+    unsigned_boundable_via_deref!(Arc);
 }
 
 #[cfg(feature = "std")]
@@ -177,12 +211,20 @@ impl<T> UnsignedBoundable for [T] {
         self.len()
     }
 }
+
+impl UnsignedBoundable for &str {
+    fn bounding_value(&self) -> usize {
+        self.len()
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct GreaterThan<const MIN: usize>;
 
 pub type GT<const MIN: usize> = GreaterThan<MIN>;
 
 impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThan<MIN> {
+    const BOUNDS: Option<(i128, i128)> = Some((MIN as i128 + 1, usize::MAX as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() > MIN
     }
@@ -197,6 +239,15 @@ impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThan<MIN> {
         "greater than"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::GreaterThan(Bound::Unsigned(MIN))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Unsigned(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -208,6 +259,8 @@ pub struct GreaterThanEqual<const MIN: usize>;
 pub type GTE<const MIN: usize> = GreaterThanEqual<MIN>;
 
 impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThanEqual<MIN> {
+    const BOUNDS: Option<(i128, i128)> = Some((MIN as i128, usize::MAX as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() >= MIN
     }
@@ -222,6 +275,15 @@ impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThanEqual<M
         "greater than equal"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::GreaterThanEqual(Bound::Unsigned(MIN))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Unsigned(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -233,6 +295,8 @@ pub struct LessThan<const MAX: usize>;
 pub type LT<const MAX: usize> = LessThan<MAX>;
 
 impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThan<MAX> {
+    const BOUNDS: Option<(i128, i128)> = Some((0, MAX as i128 - 1));
+
     fn test(value: &T) -> bool {
         value.bounding_value() < MAX
     }
@@ -247,6 +311,15 @@ impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThan<MAX> {
         "less than"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::LessThan(Bound::Unsigned(MAX))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Unsigned(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -258,6 +331,8 @@ pub struct LessThanEqual<const MAX: usize>;
 pub type LTE<const MAX: usize> = LessThanEqual<MAX>;
 
 impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThanEqual<MAX> {
+    const BOUNDS: Option<(i128, i128)> = Some((0, MAX as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() <= MAX
     }
@@ -272,6 +347,15 @@ impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThanEqual<MAX>
         "less than equal"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::LessThanEqual(Bound::Unsigned(MAX))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Unsigned(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -303,6 +387,18 @@ impl<T: UnsignedBoundable, const DIV: usize, const MOD: usize> Predicate<T> for
         "modulo"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Modulo {
+            divisor: Bound::Unsigned(DIV),
+            remainder: Bound::Unsigned(MOD),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Unsigned(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -318,6 +414,8 @@ pub type Odd = Not<Even>;
 pub struct Equals<const VAL: usize>;
 
 impl<T: UnsignedBoundable, const VAL: usize> Predicate<T> for Equals<VAL> {
+    const BOUNDS: Option<(i128, i128)> = Some((VAL as i128, VAL as i128));
+
     fn test(value: &T) -> bool {
         value.bounding_value() == VAL
     }
@@ -332,6 +430,15 @@ impl<T: UnsignedBoundable, const VAL: usize> Predicate<T> for Equals<VAL> {
         "equals"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Equals(Bound::Unsigned(VAL))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Explanation {
+        Explanation::of::<T, Self>(value).measuring(Bound::Unsigned(value.bounding_value()))
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -341,6 +448,544 @@ pub type Zero = Equals<0>;
 
 pub type NonZero = Not<Zero>;
 
+/// The error of the runtime-bounded predicates when refined without their bounds.
+const STATELESS: &str = "must be refined with a runtime bound";
+
+/// A stateful counterpart of [GreaterThan] whose bound is provided at runtime.
+///
+/// Bounds that are only known at runtime, such as those loaded from configuration, cannot be
+/// expressed with const generics. These predicates are instead constructed with their bounds and
+/// used with [StatefulRefinementOps](crate::StatefulRefinementOps).
+///
+/// # Stateless refinement
+///
+/// Refining statelessly (e.g. with [RefinementOps](crate::RefinementOps) or when deserializing)
+/// has no bound to test against, so it **always fails**.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynGreaterThan {
+    min: usize,
+}
+
+impl DynGreaterThan {
+    pub const fn new(min: usize) -> Self {
+        Self { min }
+    }
+
+    pub const fn min(&self) -> usize {
+        self.min
+    }
+
+    fn holds(&self, value: usize) -> bool {
+        value > self.min
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be greater than {}", self.min)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "greater than"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::GreaterThan(Bound::Unsigned(self.min))
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynGreaterThan {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynGreaterThan {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [GreaterThanEqual] whose bound is provided at runtime. See [DynGreaterThan].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynGreaterThanEqual {
+    min: usize,
+}
+
+impl DynGreaterThanEqual {
+    pub const fn new(min: usize) -> Self {
+        Self { min }
+    }
+
+    pub const fn min(&self) -> usize {
+        self.min
+    }
+
+    fn holds(&self, value: usize) -> bool {
+        value >= self.min
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be greater than or equal to {}", self.min)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "greater than equal"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::GreaterThanEqual(Bound::Unsigned(self.min))
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynGreaterThanEqual {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynGreaterThanEqual {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [LessThan] whose bound is provided at runtime. See [DynGreaterThan].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynLessThan {
+    max: usize,
+}
+
+impl DynLessThan {
+    pub const fn new(max: usize) -> Self {
+        Self { max }
+    }
+
+    pub const fn max(&self) -> usize {
+        self.max
+    }
+
+    fn holds(&self, value: usize) -> bool {
+        value < self.max
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be less than {}", self.max)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "less than"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::LessThan(Bound::Unsigned(self.max))
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynLessThan {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynLessThan {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [LessThanEqual] whose bound is provided at runtime. See [DynGreaterThan].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynLessThanEqual {
+    max: usize,
+}
+
+impl DynLessThanEqual {
+    pub const fn new(max: usize) -> Self {
+        Self { max }
+    }
+
+    pub const fn max(&self) -> usize {
+        self.max
+    }
+
+    fn holds(&self, value: usize) -> bool {
+        value <= self.max
+    }
+
+    #[cfg(feature = "alloc")]
+    fn message(&self) -> ErrorMessage {
+        format!("must be less than or equal to {}", self.max)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn message(&self) -> ErrorMessage {
+        "less than equal"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::LessThanEqual(Bound::Unsigned(self.max))
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynLessThanEqual {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynLessThanEqual {
+    fn test(&self, value: &T) -> bool {
+        self.holds(value.bounding_value())
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.message()
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(self.kind(), self.message())
+    }
+}
+
+/// A stateful counterpart of [OpenInterval] whose bounds are provided at runtime.
+///
+/// As with [OpenInterval], violations report whichever of the bounds failed. See [DynGreaterThan] for
+/// more information about runtime bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynOpenInterval {
+    min: DynGreaterThan,
+    max: DynLessThan,
+}
+
+impl DynOpenInterval {
+    pub const fn new(min: usize, max: usize) -> Self {
+        Self {
+            min: DynGreaterThan::new(min),
+            max: DynLessThan::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> usize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> usize {
+        self.max.max
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynOpenInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynOpenInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
+/// A stateful counterpart of [OpenClosedInterval] whose bounds are provided at runtime. See
+/// [DynOpenInterval].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynOpenClosedInterval {
+    min: DynGreaterThan,
+    max: DynLessThanEqual,
+}
+
+impl DynOpenClosedInterval {
+    pub const fn new(min: usize, max: usize) -> Self {
+        Self {
+            min: DynGreaterThan::new(min),
+            max: DynLessThanEqual::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> usize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> usize {
+        self.max.max
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynOpenClosedInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynOpenClosedInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
+/// A stateful counterpart of [ClosedOpenInterval] whose bounds are provided at runtime. See
+/// [DynOpenInterval].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynClosedOpenInterval {
+    min: DynGreaterThanEqual,
+    max: DynLessThan,
+}
+
+impl DynClosedOpenInterval {
+    pub const fn new(min: usize, max: usize) -> Self {
+        Self {
+            min: DynGreaterThanEqual::new(min),
+            max: DynLessThan::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> usize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> usize {
+        self.max.max
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynClosedOpenInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynClosedOpenInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
+/// A stateful counterpart of [ClosedInterval] whose bounds are provided at runtime. See
+/// [DynOpenInterval].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DynClosedInterval {
+    min: DynGreaterThanEqual,
+    max: DynLessThanEqual,
+}
+
+impl DynClosedInterval {
+    pub const fn new(min: usize, max: usize) -> Self {
+        Self {
+            min: DynGreaterThanEqual::new(min),
+            max: DynLessThanEqual::new(max),
+        }
+    }
+
+    pub const fn min(&self) -> usize {
+        self.min.min
+    }
+
+    pub const fn max(&self) -> usize {
+        self.max.max
+    }
+}
+
+impl<T: UnsignedBoundable> Predicate<T> for DynClosedInterval {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+}
+
+impl<T: UnsignedBoundable> StatefulPredicate<T> for DynClosedInterval {
+    fn test(&self, value: &T) -> bool {
+        StatefulPredicate::test(&self.min, value) && StatefulPredicate::test(&self.max, value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.min.message(), self.max.message())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error(&self) -> ErrorMessage {
+        "conjunction"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !StatefulPredicate::test(&self.min, value) {
+            causes.push(StatefulPredicate::violation(&self.min, value));
+        }
+        if !StatefulPredicate::test(&self.max, value) {
+            causes.push(StatefulPredicate::violation(&self.max, value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::<T>::error(self))
+            .with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !StatefulPredicate::test(&self.min, value) {
+            StatefulPredicate::violation(&self.min, value)
+        } else {
+            StatefulPredicate::violation(&self.max, value)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -472,4 +1117,30 @@ mod tests {
         assert!(Test::refine(4).is_err());
         assert!(Test::refine(0).is_err());
     }
+
+    #[test]
+    fn test_dyn_less_than() {
+        type Test = Refinement<u8, DynLessThan>;
+        let predicate = DynLessThan::new(10);
+        assert!(Test::refine_with_state(&predicate, 9).is_ok());
+        let err = Test::refine_with_state(&predicate, 10).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LessThan(Bound::Unsigned(10)));
+        assert!(Test::refine(0).is_err());
+    }
+
+    #[test]
+    fn test_dyn_closed_interval() {
+        type Test = Refinement<u16, DynClosedInterval>;
+        let predicate = DynClosedInterval::new(5, 10);
+        assert!(Test::refine_with_state(&predicate, 5).is_ok());
+        assert!(Test::refine_with_state(&predicate, 10).is_ok());
+        assert!(Test::refine_with_state(&predicate, 4).is_err());
+        let err = Test::refine_with_state(&predicate, 11).unwrap_err();
+        #[cfg(feature = "alloc")]
+        assert_eq!(err.kind(), ErrorKind::And);
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(err.kind(), ErrorKind::LessThanEqual(Bound::Unsigned(10)));
+        assert!(Test::refine(0).is_err());
+        assert!(Test::refine(7).is_err());
+    }
 }
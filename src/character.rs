@@ -10,7 +10,7 @@
 //! assert!(Test::refine('0').is_ok());
 //! assert!(Test::refine('a').is_err());
 //! ```
-use crate::{ErrorMessage, Predicate};
+use crate::{error::ErrorKind, ErrorMessage, Predicate};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct IsControl;
@@ -30,6 +30,10 @@ impl Predicate<char> for IsControl {
         "must be a control character"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Control
+    }
+
     unsafe fn optimize(value: &char) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -53,6 +57,10 @@ impl Predicate<char> for IsDigit {
         "must be a digit"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Digit
+    }
+
     unsafe fn optimize(value: &char) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -76,6 +84,10 @@ impl Predicate<char> for IsLowercase {
         "must be a lowercase character"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Lowercase
+    }
+
     unsafe fn optimize(value: &char) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -99,6 +111,10 @@ impl Predicate<char> for IsUppercase {
         "must be an uppercase character"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Uppercase
+    }
+
     unsafe fn optimize(value: &char) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -122,6 +138,10 @@ impl Predicate<char> for IsNumeric {
         "must be a numeric character"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Numeric
+    }
+
     unsafe fn optimize(value: &char) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -145,6 +165,10 @@ impl Predicate<char> for IsWhitespace {
         "must be a whitespace character"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Whitespace
+    }
+
     unsafe fn optimize(value: &char) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -168,6 +192,10 @@ impl Predicate<char> for IsHexDigit {
         "must be a valid hex character"
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::HexDigit
+    }
+
     unsafe fn optimize(value: &char) {
         core::hint::assert_unchecked(Self::test(value));
     }
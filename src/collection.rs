@@ -0,0 +1,397 @@
+//! Element-wise refinement of collections.
+//!
+//! The predicates in this module apply another predicate to every element of a collection. When
+//! refinement fails, each violation records the [path](crate::error::RefinementError::path) of
+//! the offending element, so that nested collections produce a full location rather than just a
+//! leaf message.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::LessThan, collection::All};
+//!
+//! type Scores = Refinement<Vec<Vec<u8>>, All<All<LessThan<100>>>>;
+//!
+//! assert!(Scores::refine(vec![vec![1, 2], vec![99]]).is_ok());
+//!
+//! let err = Scores::refine(vec![vec![1, 2], vec![3, 123]]).unwrap_err();
+//! assert_eq!(
+//!     err.to_string(),
+//!     "refinement violated: element [1][1]: must be less than 100"
+//! );
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    format,
+    vec::Vec,
+};
+use core::{fmt::Debug, marker::PhantomData};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::{error::ErrorKind, ErrorMessage, Predicate, RefinementError};
+#[cfg(feature = "alloc")]
+use crate::{error::PathSegment, explain::Explanation};
+
+/// Collections whose elements can be refined individually.
+pub trait Elements {
+    type Item;
+
+    /// Iterates over the elements of the collection, in order.
+    fn elements(&self) -> impl Iterator<Item = &Self::Item>;
+}
+
+/// Maps whose keys and values can be refined individually.
+pub trait Entries {
+    type Key;
+    type Value;
+
+    /// Iterates over the entries of the map, in order.
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
+}
+
+impl<T> Elements for [T] {
+    type Item = T;
+
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter()
+    }
+}
+
+impl<T, const N: usize> Elements for [T; N] {
+    type Item = T;
+
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter()
+    }
+}
+
+impl<T> Elements for &[T] {
+    type Item = T;
+
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter()
+    }
+}
+
+/// Implements [Elements] for a collection type by delegating to its `iter` method.
+#[cfg(feature = "alloc")]
+macro_rules! elements_via_iter {
+    ($($t:ident<$($ts:ident),+>),* $(,)?) => {
+        $(
+            impl<$($ts),+> Elements for $t<$($ts),+> {
+                type Item = T;
+
+                fn elements(&self) -> impl Iterator<Item = &T> {
+                    self.iter()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+elements_via_iter!(
+    Vec<T>,
+    VecDeque<T>,
+    LinkedList<T>,
+    BinaryHeap<T>,
+    BTreeSet<T>
+);
+
+#[cfg(feature = "std")]
+elements_via_iter!(HashSet<T, S>);
+
+/// Implements [Elements] for an owning pointer to a slice.
+#[cfg(feature = "alloc")]
+macro_rules! elements_via_slice {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl<T> Elements for $t {
+                type Item = T;
+
+                fn elements(&self) -> impl Iterator<Item = &T> {
+                    self.iter()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+elements_via_slice!(alloc::boxed::Box<[T]>, alloc::rc::Rc<[T]>);
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+elements_via_slice!(alloc::sync::Arc<[T]>);
+
+#[cfg(feature = "alloc")]
+impl<K, V> Entries for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Entries for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter()
+    }
+}
+
+/// Every element of a collection satisfies the predicate `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct All<P>(PhantomData<P>);
+
+impl<C: Elements, P: Predicate<C::Item>> Predicate<C> for All<P> {
+    fn test(value: &C) -> bool {
+        value.elements().all(P::test)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("every element {}", P::error())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "every element"
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::All
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &C) -> Explanation {
+        let children = value
+            .elements()
+            .enumerate()
+            .map(|(index, element)| P::explain(element).at(PathSegment::Index(index)))
+            .collect();
+        Explanation::of::<C, Self>(value).with_children(children)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(value: &C) -> RefinementError {
+        let causes = value
+            .elements()
+            .enumerate()
+            .filter(|(_, element)| !P::test(element))
+            .map(|(index, element)| P::violation(element).at(PathSegment::Index(index)))
+            .collect();
+        RefinementError::deferred(ErrorKind::All, <Self as Predicate<C>>::error).with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(value: &C) -> RefinementError {
+        match value.elements().find(|element| !P::test(element)) {
+            Some(element) => P::violation(element),
+            None => RefinementError::deferred(ErrorKind::All, <Self as Predicate<C>>::error),
+        }
+    }
+
+    unsafe fn optimize(value: &C) {
+        core::hint::assert_unchecked(Self::test(value));
+    }
+}
+
+/// Every key of a map satisfies the predicate `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct AllKeys<P>(PhantomData<P>);
+
+impl<M: Entries, P: Predicate<M::Key>> Predicate<M> for AllKeys<P>
+where
+    M::Key: Debug,
+{
+    fn test(value: &M) -> bool {
+        value.entries().all(|(key, _)| P::test(key))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("every key {}", P::error())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "every key"
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::AllKeys
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &M) -> Explanation {
+        let children = value
+            .entries()
+            .map(|(key, _)| P::explain(key).at(PathSegment::Key(format!("{:?}", key))))
+            .collect();
+        Explanation::of::<M, Self>(value).with_children(children)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(value: &M) -> RefinementError {
+        let causes = value
+            .entries()
+            .filter(|(key, _)| !P::test(key))
+            .map(|(key, _)| P::violation(key).at(PathSegment::Key(format!("{:?}", key))))
+            .collect();
+        RefinementError::deferred(ErrorKind::AllKeys, <Self as Predicate<M>>::error)
+            .with_causes(causes)
+    }
+
+    unsafe fn optimize(value: &M) {
+        core::hint::assert_unchecked(Self::test(value));
+    }
+}
+
+/// Every value of a map satisfies the predicate `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct AllValues<P>(PhantomData<P>);
+
+impl<M: Entries, P: Predicate<M::Value>> Predicate<M> for AllValues<P>
+where
+    M::Key: Debug,
+{
+    fn test(value: &M) -> bool {
+        value.entries().all(|(_, value)| P::test(value))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("every value {}", P::error())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "every value"
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::AllValues
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &M) -> Explanation {
+        let children = value
+            .entries()
+            .map(|(key, value)| P::explain(value).at(PathSegment::Key(format!("{:?}", key))))
+            .collect();
+        Explanation::of::<M, Self>(value).with_children(children)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(value: &M) -> RefinementError {
+        let causes = value
+            .entries()
+            .filter(|(_, value)| !P::test(value))
+            .map(|(key, value)| P::violation(value).at(PathSegment::Key(format!("{:?}", key))))
+            .collect();
+        RefinementError::deferred(ErrorKind::AllValues, <Self as Predicate<M>>::error)
+            .with_causes(causes)
+    }
+
+    unsafe fn optimize(value: &M) {
+        core::hint::assert_unchecked(Self::test(value));
+    }
+}
+
+/// The elements of a collection are in ascending order, allowing for repeated elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sorted;
+
+impl<C: Elements> Predicate<C> for Sorted
+where
+    C::Item: PartialOrd,
+{
+    fn test(value: &C) -> bool {
+        value.elements().is_sorted()
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from("must be sorted")
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::Sorted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::*, collection::*, *};
+    #[cfg(feature = "alloc")]
+    use alloc::{
+        collections::BTreeMap,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn test_all_array() {
+        type Test = Refinement<[u8; 3], All<LessThan<10>>>;
+        assert!(Test::refine([1, 2, 3]).is_ok());
+        assert!(Test::refine([1, 20, 3]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_all_reports_every_index() {
+        type Test = Refinement<Vec<u8>, All<LessThan<10>>>;
+        let err = Test::refine(vec![1, 20, 3, 40]).unwrap_err();
+        let paths: Vec<_> = err.violations().iter().map(|v| v.path().to_vec()).collect();
+        assert_eq!(
+            paths,
+            vec![vec![PathSegment::Index(1)], vec![PathSegment::Index(3)]]
+        );
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: element [1]: must be less than 10 and element [3]: must be less than 10"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_all_values_reports_key() {
+        type Test = Refinement<BTreeMap<String, u8>, AllValues<LessThan<10>>>;
+        let mut map = BTreeMap::new();
+        map.insert("ok".to_string(), 1);
+        map.insert("bad".to_string(), 11);
+        let err = Test::refine(map).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: element [\"bad\"]: must be less than 10"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_all_keys() {
+        type Test = Refinement<BTreeMap<String, u8>, AllKeys<LessThan<3>>>;
+        let mut map = BTreeMap::new();
+        map.insert("ok".to_string(), 1);
+        assert!(Test::refine(map.clone()).is_ok());
+        map.insert("long".to_string(), 1);
+        assert!(Test::refine(map).is_err());
+    }
+
+    #[test]
+    fn test_sorted() {
+        type Test = Refinement<[u8; 4], Sorted>;
+        assert!(Test::refine([1, 2, 2, 3]).is_ok());
+        assert!(Refinement::<[u8; 0], Sorted>::refine([]).is_ok());
+        let err = Test::refine([1, 3, 2, 4]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Sorted);
+        assert_eq!(err.message(), "must be sorted");
+    }
+}
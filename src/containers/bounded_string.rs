@@ -0,0 +1,177 @@
+use alloc::string::String;
+use core::{fmt::Display, ops::Deref};
+
+use crate::{boundable::unsigned::ClosedInterval, Refinement, RefinementError, RefinementOps};
+
+/// A string whose length in bytes is always within `MIN..=MAX`.
+///
+/// Operations that change the length fail with a [RefinementError] when the new length would be
+/// out of bounds, in which case the string is left unchanged.
+///
+/// ```
+/// use refined::containers::BoundedString;
+///
+/// let mut username = BoundedString::<1, 8>::try_from("sam").unwrap();
+/// username.push_str("wise").unwrap();
+/// assert!(username.push_str("guy").is_err());
+/// username.push('1').unwrap();
+/// assert_eq!(username.as_str(), "samwise1");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedString<const MIN: usize, const MAX: usize>(
+    Refinement<String, ClosedInterval<MIN, MAX>>,
+);
+
+impl<const MIN: usize, const MAX: usize> BoundedString<MIN, MAX> {
+    /// Creates a bounded string, failing if the length of `value` is out of bounds.
+    pub fn new(value: String) -> Result<Self, RefinementError> {
+        Refinement::refine(value).map(Self)
+    }
+
+    /// Appends a character, failing if the string would become too long.
+    pub fn push(&mut self, ch: char) -> Result<(), RefinementError> {
+        self.0.try_mutate(
+            |value| value.push(ch),
+            |value, ()| {
+                value.pop();
+            },
+        )
+    }
+
+    /// Appends a string slice, failing if the string would become too long.
+    pub fn push_str(&mut self, s: &str) -> Result<(), RefinementError> {
+        self.0
+            .try_mutate(
+                |value| {
+                    let len = value.len();
+                    value.push_str(s);
+                    len
+                },
+                |value, len| value.truncate(len),
+            )
+            .map(drop)
+    }
+
+    /// Removes the last character, failing if the string would become too short. Returns `None` if
+    /// the string is empty and may be.
+    pub fn pop(&mut self) -> Result<Option<char>, RefinementError> {
+        self.0
+            .try_mutate(String::pop, |value, popped| value.extend(popped))
+    }
+
+    /// Shortens the string to `len` bytes, failing if `len` is less than the minimum length. Has no
+    /// effect if `len` is greater than the current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` does not lie on a [char] boundary.
+    pub fn truncate(&mut self, len: usize) -> Result<(), RefinementError> {
+        self.0
+            .try_mutate(
+                |value| value.split_off(len.min(value.len())),
+                |value, removed| value.push_str(&removed),
+            )
+            .map(drop)
+    }
+
+    /// Returns the string as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the refinement of the underlying string.
+    pub fn into_refinement(self) -> Refinement<String, ClosedInterval<MIN, MAX>> {
+        self.0
+    }
+
+    /// Returns the underlying string.
+    pub fn into_inner(self) -> String {
+        self.0.take()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Deref for BoundedString<MIN, MAX> {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> AsRef<str> for BoundedString<MIN, MAX> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Display for BoundedString<MIN, MAX> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> From<Refinement<String, ClosedInterval<MIN, MAX>>>
+    for BoundedString<MIN, MAX>
+{
+    fn from(refinement: Refinement<String, ClosedInterval<MIN, MAX>>) -> Self {
+        Self(refinement)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> TryFrom<String> for BoundedString<MIN, MAX> {
+    type Error = RefinementError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> TryFrom<&str> for BoundedString<MIN, MAX> {
+    type Error = RefinementError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(String::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BoundedString::<1, 3>::try_from("").is_err());
+        assert!(BoundedString::<1, 3>::try_from("abcd").is_err());
+        assert_eq!(
+            BoundedString::<1, 3>::new("abc".to_string())
+                .unwrap()
+                .as_str(),
+            "abc"
+        );
+    }
+
+    #[test]
+    fn test_push() {
+        let mut value = BoundedString::<0, 4>::try_from("ab").unwrap();
+        value.push('c').unwrap();
+        assert!(value.push('é').is_err());
+        assert!(value.push_str("de").is_err());
+        assert_eq!(value.as_str(), "abc");
+        value.push_str("d").unwrap();
+        assert_eq!(value.to_string(), "abcd");
+    }
+
+    #[test]
+    fn test_pop_truncate() {
+        let mut value = BoundedString::<2, 8>::try_from("héllo").unwrap();
+        assert_eq!(value.pop().unwrap(), Some('o'));
+        assert!(value.truncate(1).is_err());
+        assert_eq!(value.as_str(), "héll");
+        value.truncate(3).unwrap();
+        assert_eq!(value.as_str(), "hé");
+        assert!(value.pop().is_err());
+        assert_eq!(value.into_inner(), "hé");
+    }
+}
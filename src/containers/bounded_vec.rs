@@ -0,0 +1,158 @@
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::{boundable::unsigned::ClosedInterval, Refinement, RefinementError, RefinementOps};
+
+/// A vector whose length is always within `MIN..=MAX`.
+///
+/// Operations that change the length fail with a [RefinementError] when the new length would be
+/// out of bounds, in which case the vector is left unchanged.
+///
+/// ```
+/// use refined::containers::BoundedVec;
+///
+/// let mut queue = BoundedVec::<u8, 1, 2>::new(vec![1]).unwrap();
+/// assert!(queue.pop().is_err());
+/// queue.push(2).unwrap();
+/// assert!(queue.push(3).is_err());
+/// assert_eq!(queue.pop().unwrap(), Some(2));
+/// assert_eq!(*queue, vec![1]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedVec<T, const MIN: usize, const MAX: usize>(
+    Refinement<Vec<T>, ClosedInterval<MIN, MAX>>,
+);
+
+impl<T, const MIN: usize, const MAX: usize> BoundedVec<T, MIN, MAX> {
+    /// Creates a bounded vector, failing if the length of `values` is out of bounds.
+    pub fn new(values: Vec<T>) -> Result<Self, RefinementError> {
+        Refinement::refine(values).map(Self)
+    }
+
+    /// Appends an element, failing if the vector is already at its maximum length. The element is
+    /// dropped on failure.
+    pub fn push(&mut self, value: T) -> Result<(), RefinementError> {
+        self.0.try_mutate(
+            |values| values.push(value),
+            |values, ()| {
+                values.pop();
+            },
+        )
+    }
+
+    /// Removes the last element, failing if the vector is already at its minimum length. Returns
+    /// `None` if the vector is empty and may be.
+    pub fn pop(&mut self) -> Result<Option<T>, RefinementError> {
+        self.0
+            .try_mutate(Vec::pop, |values, popped| values.extend(popped))
+    }
+
+    /// Shortens the vector to `len` elements, failing if `len` is less than the minimum length. Has
+    /// no effect if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) -> Result<(), RefinementError> {
+        self.0
+            .try_mutate(
+                |values| values.split_off(len.min(values.len())),
+                |values, mut removed| values.append(&mut removed),
+            )
+            .map(drop)
+    }
+
+    /// Returns a mutable slice of the elements, which can't change the length of the vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0 .0
+    }
+
+    /// Returns the refinement of the underlying vector.
+    pub fn into_refinement(self) -> Refinement<Vec<T>, ClosedInterval<MIN, MAX>> {
+        self.0
+    }
+
+    /// Returns the underlying vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0.take()
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Deref for BoundedVec<T, MIN, MAX> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> From<Refinement<Vec<T>, ClosedInterval<MIN, MAX>>>
+    for BoundedVec<T, MIN, MAX>
+{
+    fn from(refinement: Refinement<Vec<T>, ClosedInterval<MIN, MAX>>) -> Self {
+        Self(refinement)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BoundedVec<T, MIN, MAX> {
+    type Error = RefinementError;
+
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(values)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> IntoIterator for BoundedVec<T, MIN, MAX> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BoundedVec::<u8, 1, 3>::new(vec![]).is_err());
+        assert!(BoundedVec::<u8, 1, 3>::new(vec![1, 2, 3, 4]).is_err());
+        assert_eq!(*BoundedVec::<u8, 1, 3>::new(vec![1]).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut values = BoundedVec::<u8, 1, 2>::new(vec![1]).unwrap();
+        values.push(2).unwrap();
+        let err = values.push(3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: must be less than or equal to 2"
+        );
+        assert_eq!(*values, vec![1, 2]);
+
+        assert_eq!(values.pop().unwrap(), Some(2));
+        assert!(values.pop().is_err());
+        assert_eq!(*values, vec![1]);
+
+        let mut empty = BoundedVec::<u8, 0, 2>::new(vec![]).unwrap();
+        assert_eq!(empty.pop().unwrap(), None);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut values = BoundedVec::<u8, 2, 5>::new(vec![1, 2, 3, 4]).unwrap();
+        assert!(values.truncate(1).is_err());
+        assert_eq!(*values, vec![1, 2, 3, 4]);
+        values.truncate(10).unwrap();
+        values.truncate(2).unwrap();
+        assert_eq!(*values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_mutate_elements() {
+        let mut values = BoundedVec::<u8, 1, 5>::new(vec![3, 1, 2]).unwrap();
+        values.as_mut_slice().sort();
+        assert_eq!(values.into_inner(), vec![1, 2, 3]);
+    }
+}
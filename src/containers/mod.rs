@@ -0,0 +1,29 @@
+//! Containers that maintain a refinement through mutation.
+//!
+//! A [Refinement](crate::Refinement) of a collection is checked when it is created, but must be
+//! taken apart and refined again to be modified. The containers in this module instead expose
+//! fallible mutations that re-check the predicate, leaving the container unchanged when an
+//! operation would violate it.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::containers::BoundedVec;
+//!
+//! let mut team = BoundedVec::<&str, 1, 3>::new(vec!["alice"]).unwrap();
+//! team.push("bob").unwrap();
+//! team.push("carol").unwrap();
+//! assert!(team.push("dave").is_err());
+//! assert_eq!(team.len(), 3);
+//! ```
+mod bounded_string;
+mod bounded_vec;
+mod non_empty_vec;
+mod refined_map;
+mod sorted_vec;
+
+pub use bounded_string::BoundedString;
+pub use bounded_vec::BoundedVec;
+pub use non_empty_vec::NonEmptyVec;
+pub use refined_map::{Entry, RefinedMap};
+pub use sorted_vec::SortedVec;
@@ -0,0 +1,178 @@
+use alloc::{vec, vec::Vec};
+use core::{marker::PhantomData, ops::Deref};
+
+use crate::{boundable::unsigned::NonZero, Refinement, RefinementError, RefinementOps};
+
+/// A vector that always has at least one element, so that its first and last elements can be
+/// accessed without an `Option`.
+///
+/// ```
+/// use refined::containers::NonEmptyVec;
+///
+/// let mut readings = NonEmptyVec::new(vec![3, 1, 4]).unwrap();
+/// readings.push(1);
+/// assert_eq!(*readings.first(), 3);
+/// assert_eq!(*readings.last(), 1);
+///
+/// let (head, tail) = readings.split_first();
+/// assert_eq!((*head, tail), (3, &[1, 4, 1][..]));
+///
+/// let doubled = readings.map(|reading| reading * 2);
+/// assert_eq!(*doubled.last(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVec<T>(Refinement<Vec<T>, NonZero>);
+
+impl<T> NonEmptyVec<T> {
+    /// Creates a non-empty vector, failing if `values` is empty.
+    pub fn new(values: Vec<T>) -> Result<Self, RefinementError> {
+        Refinement::refine(values).map(Self)
+    }
+
+    /// Creates a non-empty vector containing only `value`.
+    pub fn singleton(value: T) -> Self {
+        Self(Refinement(vec![value], PhantomData))
+    }
+
+    /// Returns the first element.
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Returns the last element.
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Returns a mutable reference to the first element.
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.0 .0[0]
+    }
+
+    /// Returns a mutable reference to the last element.
+    pub fn last_mut(&mut self) -> &mut T {
+        let last = self.0.len() - 1;
+        &mut self.0 .0[last]
+    }
+
+    /// Returns the first element and the rest of the elements, which may be empty.
+    pub fn split_first(&self) -> (&T, &[T]) {
+        let (first, rest) = self.0.split_at(1);
+        (&first[0], rest)
+    }
+
+    /// Returns the last element and the rest of the elements, which may be empty.
+    pub fn split_last(&self) -> (&T, &[T]) {
+        let (rest, last) = self.0.split_at(self.0.len() - 1);
+        (&last[0], rest)
+    }
+
+    /// Appends an element, which can't violate non-emptiness.
+    pub fn push(&mut self, value: T) {
+        self.0 .0.push(value);
+    }
+
+    /// Removes and returns the last element, failing if it is the only element.
+    pub fn pop(&mut self) -> Result<T, RefinementError> {
+        self.0
+            .try_mutate(Vec::pop, |values, popped| values.extend(popped))
+            .map(|popped| popped.expect("non-empty vectors always have a last element"))
+    }
+
+    /// Maps each element with `f`, preserving non-emptiness.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> NonEmptyVec<U> {
+        NonEmptyVec(Refinement(
+            self.into_inner().into_iter().map(f).collect(),
+            PhantomData,
+        ))
+    }
+
+    /// Returns a mutable slice of the elements, which can't change the length of the vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0 .0
+    }
+
+    /// Returns the refinement of the underlying vector.
+    pub fn into_refinement(self) -> Refinement<Vec<T>, NonZero> {
+        self.0
+    }
+
+    /// Returns the underlying vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0.take()
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> From<Refinement<Vec<T>, NonZero>> for NonEmptyVec<T> {
+    fn from(refinement: Refinement<Vec<T>, NonZero>) -> Self {
+        Self(refinement)
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = RefinementError;
+
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(values)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(NonEmptyVec::<u8>::new(vec![]).is_err());
+        assert_eq!(*NonEmptyVec::singleton('a'), vec!['a']);
+    }
+
+    #[test]
+    fn test_accessors() {
+        let mut values = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+        assert_eq!((*values.first(), *values.last()), (1, 3));
+        assert_eq!(values.split_first(), (&1, &[2, 3][..]));
+        assert_eq!(values.split_last(), (&3, &[1, 2][..]));
+        *values.first_mut() = 0;
+        *values.last_mut() = 4;
+        assert_eq!(*values, vec![0, 2, 4]);
+
+        let single = NonEmptyVec::singleton(7);
+        assert_eq!(single.split_first(), (&7, &[][..]));
+        assert_eq!(single.split_last(), (&7, &[][..]));
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut values = NonEmptyVec::singleton(1);
+        values.push(2);
+        assert_eq!(values.pop().unwrap(), 2);
+        assert!(values.pop().is_err());
+        assert_eq!(*values, vec![1]);
+    }
+
+    #[test]
+    fn test_map() {
+        let values = NonEmptyVec::new(vec!["a", "bc"]).unwrap();
+        assert_eq!(*values.map(str::len), vec![1, 2]);
+    }
+}
@@ -0,0 +1,264 @@
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    format,
+};
+use core::{borrow::Borrow, fmt::Debug, marker::PhantomData, ops::Deref};
+
+use crate::{
+    boolean::And,
+    collection::{AllKeys, AllValues},
+    error::PathSegment,
+    Predicate, Refinement, RefinementError, RefinementOps,
+};
+
+/// A [BTreeMap] whose keys always satisfy `KP` and whose values always satisfy `VP`.
+///
+/// Entries are checked as they are inserted, so that the map can be read with the guarantee that
+/// every entry is refined. Violations by values are reported at the [path](RefinementError::path)
+/// of their key, which is rendered with its [Debug] implementation.
+///
+/// ```
+/// use refined::{
+///     boundable::unsigned::{ClosedInterval, LessThanEqual},
+///     containers::RefinedMap,
+/// };
+///
+/// type Ports = RefinedMap<String, u16, LessThanEqual<8>, ClosedInterval<1024, 49151>>;
+///
+/// let mut ports = Ports::default();
+/// ports.insert("http".to_string(), 8080).unwrap();
+/// assert!(ports.insert("ssh".to_string(), 22).is_err());
+/// assert!(ports.insert("prometheus".to_string(), 9090).is_err());
+/// let err = ports.entry("grpc".to_string()).unwrap().or_insert(50051).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "refinement violated: element [\"grpc\"]: must be less than or equal to 49151"
+/// );
+/// assert_eq!(ports.get("http"), Some(&8080));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RefinedMap<K, V, KP, VP>(BTreeMap<K, V>, PhantomData<(KP, VP)>);
+
+/// Checks `value` against `VP`, reporting a violation at the path of `key`.
+fn refine_value<K: Debug, V, VP: Predicate<V>>(key: &K, value: V) -> Result<V, RefinementError> {
+    Refinement::<V, VP>::refine(value)
+        .map(Refinement::take)
+        .map_err(|err| err.at(PathSegment::Key(format!("{:?}", key))))
+}
+
+impl<K: Ord + Debug, V, KP: Predicate<K>, VP: Predicate<V>> RefinedMap<K, V, KP, VP> {
+    /// Creates a refined map, failing if any of the entries of `map` are not refined.
+    pub fn new(map: BTreeMap<K, V>) -> Result<Self, RefinementError> {
+        Refinement::<_, And<AllKeys<KP>, AllValues<VP>>>::refine(map).map(Self::from)
+    }
+
+    /// Inserts an entry, failing if the key or value is not refined. Returns the previous value of
+    /// the key, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, RefinementError> {
+        let key = Refinement::<K, KP>::refine(key)?.take();
+        let value = refine_value::<K, V, VP>(&key, value)?;
+        Ok(self.0.insert(key, value))
+    }
+
+    /// Returns the entry of `key` for in-place manipulation, failing if the key is not refined.
+    pub fn entry(&mut self, key: K) -> Result<Entry<'_, K, V, VP>, RefinementError> {
+        let key = Refinement::<K, KP>::refine(key)?.take();
+        Ok(Entry(self.0.entry(key), PhantomData))
+    }
+
+    /// Returns the refinement of the underlying map.
+    pub fn into_refinement(self) -> Refinement<BTreeMap<K, V>, And<AllKeys<KP>, AllValues<VP>>> {
+        Refinement(self.0, PhantomData)
+    }
+}
+
+impl<K: Ord, V, KP, VP> RefinedMap<K, V, KP, VP> {
+    /// Inserts an entry that is already refined, which can't fail.
+    pub fn insert_refined(&mut self, key: Refinement<K, KP>, value: Refinement<V, VP>) -> Option<V>
+    where
+        KP: Predicate<K>,
+        VP: Predicate<V>,
+    {
+        self.0.insert(key.take(), value.take())
+    }
+
+    /// Removes the entry of `key`, returning its value if it was present.
+    pub fn remove<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.remove(key)
+    }
+
+    /// Retains only the entries for which `f` returns `true`.
+    ///
+    /// Values are only borrowed immutably, so that they remain refined.
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &V) -> bool) {
+        self.0.retain(|key, value| f(key, value));
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> BTreeMap<K, V> {
+        self.0
+    }
+}
+
+impl<K, V, KP, VP> Default for RefinedMap<K, V, KP, VP> {
+    fn default() -> Self {
+        Self(BTreeMap::new(), PhantomData)
+    }
+}
+
+impl<K, V, KP, VP> Deref for RefinedMap<K, V, KP, VP> {
+    type Target = BTreeMap<K, V>;
+
+    fn deref(&self) -> &BTreeMap<K, V> {
+        &self.0
+    }
+}
+
+impl<K: Ord + Debug, V, KP: Predicate<K>, VP: Predicate<V>>
+    From<Refinement<BTreeMap<K, V>, And<AllKeys<KP>, AllValues<VP>>>> for RefinedMap<K, V, KP, VP>
+{
+    fn from(refinement: Refinement<BTreeMap<K, V>, And<AllKeys<KP>, AllValues<VP>>>) -> Self {
+        Self(refinement.take(), PhantomData)
+    }
+}
+
+impl<K: Ord + Debug, V, KP: Predicate<K>, VP: Predicate<V>> TryFrom<BTreeMap<K, V>>
+    for RefinedMap<K, V, KP, VP>
+{
+    type Error = RefinementError;
+
+    fn try_from(map: BTreeMap<K, V>) -> Result<Self, Self::Error> {
+        Self::new(map)
+    }
+}
+
+impl<K, V, KP, VP> IntoIterator for RefinedMap<K, V, KP, VP> {
+    type Item = (K, V);
+    type IntoIter = btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// The entry of a refined key in a [RefinedMap], whose value is checked against `VP` when it is
+/// inserted.
+pub struct Entry<'a, K, V, VP>(btree_map::Entry<'a, K, V>, PhantomData<VP>);
+
+impl<K: Ord, V, VP> Entry<'_, K, V, VP> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        self.0.key()
+    }
+
+    /// Returns the value of the entry, if it is occupied.
+    pub fn get(&self) -> Option<&V> {
+        match &self.0 {
+            btree_map::Entry::Occupied(entry) => Some(entry.get()),
+            btree_map::Entry::Vacant(_) => None,
+        }
+    }
+}
+
+impl<'a, K: Ord + Debug, V, VP: Predicate<V>> Entry<'a, K, V, VP> {
+    /// Returns the value of the entry, inserting `value` if it is vacant. Fails if `value` is
+    /// inserted and is not refined.
+    pub fn or_insert(self, value: V) -> Result<&'a V, RefinementError> {
+        self.or_insert_with(|| value)
+    }
+
+    /// Returns the value of the entry, inserting the result of `f` if it is vacant. Fails if the
+    /// result is not refined.
+    pub fn or_insert_with(self, f: impl FnOnce() -> V) -> Result<&'a V, RefinementError> {
+        match self.0 {
+            btree_map::Entry::Occupied(entry) => Ok(entry.into_mut()),
+            btree_map::Entry::Vacant(entry) => {
+                let value = refine_value::<K, V, VP>(entry.key(), f())?;
+                Ok(entry.insert(value))
+            }
+        }
+    }
+
+    /// Sets the value of the entry, failing if `value` is not refined. Returns the previous value,
+    /// if any.
+    pub fn insert(self, value: V) -> Result<Option<V>, RefinementError> {
+        let value = refine_value::<K, V, VP>(self.0.key(), value)?;
+        Ok(match self.0 {
+            btree_map::Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use super::*;
+    use crate::boundable::unsigned::{GreaterThan, LessThan};
+
+    type Registry = RefinedMap<String, u8, GreaterThan<0>, LessThan<10>>;
+
+    #[test]
+    fn test_new() {
+        let mut map = BTreeMap::new();
+        map.insert("a".to_string(), 1);
+        assert!(Registry::new(map.clone()).is_ok());
+        map.insert(String::new(), 1);
+        assert!(Registry::new(map).is_err());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut registry = Registry::default();
+        assert_eq!(registry.insert("a".to_string(), 1).unwrap(), None);
+        assert_eq!(registry.insert("a".to_string(), 2).unwrap(), Some(1));
+        assert!(registry.insert(String::new(), 1).is_err());
+        let err = registry.insert("b".to_string(), 10).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: element [\"b\"]: must be less than 10"
+        );
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.remove("a"), Some(2));
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut registry = Registry::default();
+        assert!(registry.entry(String::new()).is_err());
+        assert!(registry
+            .entry("a".to_string())
+            .unwrap()
+            .or_insert(10)
+            .is_err());
+        assert_eq!(
+            registry
+                .entry("a".to_string())
+                .unwrap()
+                .or_insert(3)
+                .unwrap(),
+            &3
+        );
+        assert_eq!(
+            registry
+                .entry("a".to_string())
+                .unwrap()
+                .or_insert(10)
+                .unwrap(),
+            &3
+        );
+        let entry = registry.entry("a".to_string()).unwrap();
+        assert_eq!((entry.key().as_str(), entry.get()), ("a", Some(&3)));
+        assert_eq!(entry.insert(4).unwrap(), Some(3));
+        assert!(registry.entry("a".to_string()).unwrap().insert(11).is_err());
+        assert_eq!(registry.into_inner().get("a"), Some(&4));
+    }
+}
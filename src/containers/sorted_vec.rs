@@ -0,0 +1,176 @@
+use alloc::vec::Vec;
+use core::{marker::PhantomData, ops::Deref};
+
+use crate::{collection::Sorted, Refinement, RefinementError, RefinementOps};
+
+/// A vector whose elements are always in ascending order.
+///
+/// Elements are inserted at their sorted position, so the vector can be searched without
+/// re-checking that it is sorted.
+///
+/// ```
+/// use refined::containers::SortedVec;
+///
+/// let mut deadlines = SortedVec::from_unsorted(vec![30, 10, 20]);
+/// assert_eq!(deadlines.insert(15), 1);
+/// assert_eq!(*deadlines, vec![10, 15, 20, 30]);
+/// assert_eq!(deadlines.binary_search(&20), Ok(2));
+/// assert!(SortedVec::new(vec![2, 1]).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVec<T: Ord>(Refinement<Vec<T>, Sorted>);
+
+impl<T: Ord> SortedVec<T> {
+    /// Creates a sorted vector, failing if `values` is not sorted.
+    pub fn new(values: Vec<T>) -> Result<Self, RefinementError> {
+        Refinement::refine(values).map(Self)
+    }
+
+    /// Creates a sorted vector by sorting `values`.
+    pub fn from_unsorted(mut values: Vec<T>) -> Self {
+        values.sort();
+        Self(Refinement(values, PhantomData))
+    }
+
+    /// Inserts an element at its sorted position, after any equal elements, and returns the
+    /// position.
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.0.partition_point(|element| element <= &value);
+        self.0 .0.insert(index, value);
+        index
+    }
+
+    /// Binary searches for an element, as [slice::binary_search].
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize> {
+        self.0.binary_search(value)
+    }
+
+    /// Returns whether the vector contains an element equal to `value`, in logarithmic time.
+    pub fn contains(&self, value: &T) -> bool {
+        self.binary_search(value).is_ok()
+    }
+
+    /// Removes and returns the element at `index`, which preserves the order of the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.0 .0.remove(index)
+    }
+
+    /// Removes and returns the last, and greatest, element.
+    pub fn pop(&mut self) -> Option<T> {
+        self.0 .0.pop()
+    }
+
+    /// Retains only the elements for which `f` returns `true`, which preserves their order.
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.0 .0.retain(f);
+    }
+
+    /// Returns the refinement of the underlying vector.
+    pub fn into_refinement(self) -> Refinement<Vec<T>, Sorted> {
+        self.0
+    }
+
+    /// Returns the underlying vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0.take()
+    }
+}
+
+impl<T: Ord> Default for SortedVec<T> {
+    fn default() -> Self {
+        Self(Refinement(Vec::new(), PhantomData))
+    }
+}
+
+impl<T: Ord> Deref for SortedVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T: Ord> From<Refinement<Vec<T>, Sorted>> for SortedVec<T> {
+    fn from(refinement: Refinement<Vec<T>, Sorted>) -> Self {
+        Self(refinement)
+    }
+}
+
+impl<T: Ord> TryFrom<Vec<T>> for SortedVec<T> {
+    type Error = RefinementError;
+
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(values)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0 .0.extend(iter);
+        self.0 .0.sort();
+    }
+}
+
+impl<T: Ord> IntoIterator for SortedVec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.take().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SortedVec::new(vec![1, 1, 2]).is_ok());
+        assert!(SortedVec::new(vec![2, 1]).is_err());
+        assert_eq!(*SortedVec::from_unsorted(vec![3, 1, 2]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut values = SortedVec::default();
+        for value in [5, 1, 3, 3, 9] {
+            values.insert(value);
+        }
+        assert_eq!(*values, vec![1, 3, 3, 5, 9]);
+        assert_eq!(values.insert(3), 3);
+        assert_eq!(values.insert(0), 0);
+        assert_eq!(values.insert(10), 7);
+    }
+
+    #[test]
+    fn test_search() {
+        let values: SortedVec<_> = [8, 2, 6, 4].into_iter().collect();
+        assert_eq!(values.binary_search(&6), Ok(2));
+        assert_eq!(values.binary_search(&5), Err(2));
+        assert!(values.contains(&8));
+        assert!(!values.contains(&1));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut values = SortedVec::from_unsorted(vec![4, 1, 3, 2]);
+        assert_eq!(values.remove(1), 2);
+        assert_eq!(values.pop(), Some(4));
+        values.extend([0, 5]);
+        values.retain(|value| *value != 3);
+        assert_eq!(values.into_inner(), vec![0, 1, 5]);
+    }
+}
@@ -0,0 +1,1285 @@
+//! Deserialization that tracks the location of refined values.
+//!
+//! [from_deserializer] separates refinement failures from documents that can't be deserialized at
+//! all, and collects every refinement that a document violates rather than only the first.
+//! Refinement failures are returned in a [RefinementErrors], each with the
+//! [path](RefinementError::path) of the value that violated it.
+//!
+//! [from_deserializer_named] names the refinement error of a document after the location of the
+//! value that violated it, without requiring a [NamedSerde](crate::NamedSerde) for every field.
+//!
+//! Only [Refinement](crate::Refinement) records its failures; other refinement types (such as
+//! [NamedSerde](crate::NamedSerde)) are reported as deserialization errors.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::{ClosedInterval, LessThan}, de};
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Form {
+//!     name: Refinement<String, ClosedInterval<1, 10>>,
+//!     sizes: Vec<Refinement<u8, LessThan<100>>>,
+//! }
+//!
+//! let mut json = serde_json::Deserializer::from_str(r#"{"name": "", "sizes": [1, 200, 250]}"#);
+//! let Err(de::Error::Refinement(errors)) = de::from_deserializer::<_, Form>(&mut json) else {
+//!     panic!("expected refinement errors");
+//! };
+//! assert_eq!(errors.len(), 3);
+//! assert_eq!(
+//!     errors.errors()[2].to_string(),
+//!     "refinement violated: element [sizes][2]: must be less than 100"
+//! );
+//! ```
+//!
+//! # Collecting errors
+//!
+//! A [Refinement](crate::Refinement) is never constructed from a value that violates its
+//! predicate. Instead, [from_deserializer] reads the document into memory and deserializes it
+//! again each time a refinement fails, leaving out every value that has failed so far, until the
+//! document can be deserialized without them. This requires a self-describing format, such as
+//! JSON.
+//!
+//! Leaving a value out can invalidate the value that contains it, such as a struct that requires
+//! it as a field: once its other values have been checked, the container is left out in turn.
+//! Errors that only arise because a value was left out, such as the missing field, are not
+//! reported. Neither are the failures of alternatives that serde attempts and abandons, such as
+//! the variants of an untagged enum, unless every alternative fails.
+use std::{
+    boxed::Box,
+    cell::RefCell,
+    fmt,
+    marker::PhantomData,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::de::{
+    self, Deserialize, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, Unexpected,
+    VariantAccess, Visitor,
+};
+use thiserror::Error;
+
+use crate::error::{PathSegment, RefinementError, RefinementErrors};
+
+/// The error of [from_deserializer].
+#[derive(Debug, Error)]
+pub enum Error<E> {
+    /// The document could not be deserialized, regardless of refinement.
+    #[error("{0}")]
+    Deserialize(E),
+    /// The document was deserialized, but at least one refinement was violated.
+    #[error("{0}")]
+    Refinement(RefinementErrors),
+}
+
+/// Deserializes a `T`, collecting every refinement error along with its path.
+///
+/// See the [module documentation](self) for more information.
+pub fn from_deserializer<'de, D, T>(deserializer: D) -> Result<T, Error<D::Error>>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    collecting(deserializer, false)
+}
+
+/// Deserializes a `T` from a format that represents every value as a string, such as a query
+/// string, collecting every refinement error along with its path.
+#[cfg(any(feature = "actix", feature = "axum"))]
+pub(crate) fn from_strings<'de, D, T>(deserializer: D) -> Result<T, Error<D::Error>>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    collecting(deserializer, true)
+}
+
+/// Deserializes a `T` until every refinement error has been collected, leaving out the values
+/// that failed; strings are parsed into the primitives that are requested if `parse` is set.
+fn collecting<'de, D, T>(deserializer: D, parse: bool) -> Result<T, Error<D::Error>>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let human_readable = deserializer.is_human_readable();
+    let content = Content::deserialize(deserializer).map_err(Error::Deserialize)?;
+    let mut errors = RefinementErrors::new();
+    let mut skip = Vec::new();
+    loop {
+        let replay = Replay {
+            content: &content,
+            human_readable,
+            parse,
+            error: PhantomData,
+        };
+        let state = State {
+            collect: true,
+            skip,
+            ..State::default()
+        };
+        let (result, state) = tracked(replay, state);
+        skip = state.skip;
+        let err = match result {
+            Ok(value) if errors.is_empty() => return Ok(value),
+            Ok(_) => return Err(Error::Refinement(errors)),
+            Err(err) => err,
+        };
+        // The document failed either on a refinement, or on a container that was invalidated by
+        // leaving out the values that failed beforehand; anything else is malformed.
+        let failed = state.failed.unwrap_or_default();
+        match state
+            .errors
+            .into_iter()
+            .rev()
+            .find(|(path, _)| *path == failed)
+        {
+            Some((_, err)) => errors.push(err),
+            None if skip.iter().any(|path| path.starts_with(&failed)) => {}
+            None => return Err(Error::Deserialize(err)),
+        }
+        if failed.is_empty() || skip.contains(&failed) {
+            return Err(Error::Refinement(errors));
+        }
+        skip.push(failed);
+    }
+}
+
+/// Deserializes a `T`, naming the first refinement error after the location of the value that
+/// violated it.
+///
+/// This removes the need for a [NamedSerde](crate::NamedSerde) (and its `type_string!`) for every
+/// refined field of a struct. The name is built from the enclosing field names and indices, such
+/// as `user.emails[1]`; errors that were already [named](RefinementError::name) are unchanged.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan, de};
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Frobnicator {
+///     size: Refinement<u8, LessThan<100>>,
+/// }
+///
+/// let mut json = serde_json::Deserializer::from_str(r#"{"size": 123}"#);
+/// let err = de::from_deserializer_named::<_, Frobnicator>(&mut json).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "refinement violated: size must be less than 100 at line 1 column 13"
+/// );
+/// ```
+pub fn from_deserializer_named<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    tracked(Tracked(deserializer), State::default()).0
+}
+
+/// Deserializes a `T` with the given tracking state, returning the final state.
+fn tracked<'de, D, T>(deserializer: D, state: State) -> (Result<T, D::Error>, State)
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let mut guard = Restore(Some(STATE.with(|current| current.replace(Some(state)))));
+    let result = T::deserialize(deserializer);
+    let state = guard
+        .restore()
+        .expect("tracking state is restored by its owner");
+    (result, state)
+}
+
+/// Restores the tracking state that was replaced by [tracked], even if deserialization panics.
+struct Restore(Option<Option<State>>);
+
+impl Restore {
+    /// Restores the previous state, returning the state that it replaces.
+    fn restore(&mut self) -> Option<State> {
+        let previous = self.0.take()?;
+        STATE.with(|state| state.replace(previous))
+    }
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// Whether refinement errors are collected.
+    collect: bool,
+    /// The path of the value being deserialized.
+    path: Vec<PathSegment>,
+    /// The paths of the values that are left out.
+    skip: Vec<Vec<PathSegment>>,
+    /// The path of the innermost value that failed to deserialize.
+    failed: Option<Vec<PathSegment>>,
+    /// The refinement errors collected, along with the path of the value that violated them.
+    errors: Vec<(Vec<PathSegment>, RefinementError)>,
+}
+
+std::thread_local! {
+    static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// Names a refinement error after the current path, if the location of values is being tracked
+/// and the error is not already named.
+pub(crate) fn named(err: RefinementError) -> RefinementError {
+    STATE.with(|state| match state.borrow().as_ref() {
+        Some(state) if !state.path.is_empty() && err.name().is_none() => {
+            let mut name = String::new();
+            for segment in &state.path {
+                match segment {
+                    PathSegment::Key(key) if name.is_empty() => name.push_str(key),
+                    PathSegment::Key(key) => {
+                        name.push('.');
+                        name.push_str(key);
+                    }
+                    PathSegment::Index(_) => name.push_str(&segment.to_string()),
+                }
+            }
+            err.named(&name)
+        }
+        _ => err,
+    })
+}
+
+/// Records a refinement error at the current path, if refinement errors are being collected.
+pub(crate) fn collect(mut err: RefinementError) {
+    STATE.with(|state| {
+        if let Some(state) = state.borrow_mut().as_mut().filter(|state| state.collect) {
+            for segment in state.path.iter().rev() {
+                err = err.at(segment.clone());
+            }
+            state.errors.push((state.path.clone(), err));
+        }
+    })
+}
+
+/// Runs `f` with `segment` appended to the current path, recording the path if `f` fails.
+fn at<T, E>(segment: PathSegment, f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    STATE.with(|state| {
+        if let Some(state) = state.borrow_mut().as_mut() {
+            state.path.push(segment);
+        }
+    });
+    let result = f();
+    STATE.with(|state| {
+        if let Some(state) = state.borrow_mut().as_mut() {
+            // A failure that was recovered from no longer counts.
+            if result.is_ok() {
+                state.failed = None;
+            } else if state.failed.is_none() {
+                state.failed = Some(state.path.clone());
+            }
+            state.path.pop();
+        }
+    });
+    result
+}
+
+/// Whether the value at `segment` of the current path is left out.
+fn skipped(segment: &PathSegment) -> bool {
+    STATE.with(|state| {
+        state.borrow().as_ref().is_some_and(|state| {
+            state.skip.iter().any(|path| {
+                path.len() == state.path.len() + 1
+                    && path.starts_with(&state.path)
+                    && path.last() == Some(segment)
+            })
+        })
+    })
+}
+
+/// Wraps a deserializer, or any of the serde types that it hands out, so that the location of
+/// every nested value is tracked.
+struct Tracked<X>(X);
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error> {
+                self.0.$method($($arg,)* Tracked(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Tracked<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(), deserialize_bool(), deserialize_i8(), deserialize_i16(),
+        deserialize_i32(), deserialize_i64(), deserialize_i128(), deserialize_u8(),
+        deserialize_u16(), deserialize_u32(), deserialize_u64(), deserialize_u128(),
+        deserialize_f32(), deserialize_f64(), deserialize_char(), deserialize_str(),
+        deserialize_string(), deserialize_bytes(), deserialize_byte_buf(), deserialize_option(),
+        deserialize_unit(), deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str), deserialize_seq(),
+        deserialize_tuple(len: usize), deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(), deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(), deserialize_ignored_any(),
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Tracked<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.0.deserialize(Tracked(deserializer))
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Tracked<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool), visit_i8(i8), visit_i16(i16), visit_i32(i32), visit_i64(i64),
+        visit_i128(i128), visit_u8(u8), visit_u16(u16), visit_u32(u32), visit_u64(u64),
+        visit_u128(u128), visit_f32(f32), visit_f64(f64), visit_char(char), visit_str(&str),
+        visit_borrowed_str(&'de str), visit_string(String), visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]), visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.0.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.visit_some(Tracked(deserializer))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.0.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.0.visit_newtype_struct(Tracked(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_seq(TrackedSeq { seq, index: 0 })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_map(TrackedMap { map, key: None })
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_enum(Tracked(data))
+    }
+}
+
+struct TrackedSeq<A> {
+    seq: A,
+    index: usize,
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for TrackedSeq<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, A::Error> {
+        let index = self.index;
+        self.index += 1;
+        at(PathSegment::Index(index), || {
+            self.seq.next_element_seed(Tracked(seed))
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.seq.size_hint()
+    }
+}
+
+struct TrackedMap<A> {
+    map: A,
+    key: Option<String>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for TrackedMap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        self.key = None;
+        self.map.next_key_seed(CaptureKey {
+            inner: seed,
+            key: &mut self.key,
+        })
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, A::Error> {
+        let key = self.key.take().unwrap_or_else(|| String::from("?"));
+        at(PathSegment::Key(key), || {
+            self.map.next_value_seed(Tracked(seed))
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map.size_hint()
+    }
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for Tracked<A> {
+    type Error = A::Error;
+    type Variant = TrackedVariant<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), A::Error> {
+        let mut key = None;
+        let (value, variant) = self.0.variant_seed(CaptureKey {
+            inner: seed,
+            key: &mut key,
+        })?;
+        let key = key.unwrap_or_else(|| String::from("?"));
+        Ok((value, TrackedVariant { variant, key }))
+    }
+}
+
+struct TrackedVariant<A> {
+    variant: A,
+    key: String,
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for TrackedVariant<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.variant.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, A::Error> {
+        let variant = self.variant;
+        at(PathSegment::Key(self.key), || {
+            variant.newtype_variant_seed(Tracked(seed))
+        })
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, A::Error> {
+        let variant = self.variant;
+        at(PathSegment::Key(self.key), || {
+            variant.tuple_variant(len, Tracked(visitor))
+        })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let variant = self.variant;
+        at(PathSegment::Key(self.key), || {
+            variant.struct_variant(fields, Tracked(visitor))
+        })
+    }
+}
+
+/// Records a rendering of a map key or enum variant as it is deserialized.
+struct CaptureKey<'a, X> {
+    inner: X,
+    key: &'a mut Option<String>,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for CaptureKey<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.inner.deserialize(CaptureKey {
+            inner: deserializer,
+            key: self.key,
+        })
+    }
+}
+
+macro_rules! capture_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error> {
+                self.inner.$method($($arg,)* CaptureKey { inner: visitor, key: self.key })
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for CaptureKey<'_, D> {
+    type Error = D::Error;
+
+    capture_deserialize! {
+        deserialize_any(), deserialize_bool(), deserialize_i8(), deserialize_i16(),
+        deserialize_i32(), deserialize_i64(), deserialize_i128(), deserialize_u8(),
+        deserialize_u16(), deserialize_u32(), deserialize_u64(), deserialize_u128(),
+        deserialize_f32(), deserialize_f64(), deserialize_char(), deserialize_str(),
+        deserialize_string(), deserialize_bytes(), deserialize_byte_buf(), deserialize_option(),
+        deserialize_unit(), deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str), deserialize_seq(),
+        deserialize_tuple(len: usize), deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(), deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(), deserialize_ignored_any(),
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! capture_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                *self.key = Some(v.to_string());
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CaptureKey<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    capture_visit! {
+        visit_bool(bool), visit_i8(i8), visit_i16(i16), visit_i32(i32), visit_i64(i64),
+        visit_i128(i128), visit_u8(u8), visit_u16(u16), visit_u32(u32), visit_u64(u64),
+        visit_u128(u128), visit_f32(f32), visit_f64(f64), visit_char(char), visit_str(&str),
+        visit_borrowed_str(&'de str),
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        *self.key = Some(v.clone());
+        self.inner.visit_string(v)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        *self.key = Some(String::from_utf8_lossy(v).into_owned());
+        self.inner.visit_bytes(v)
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        *self.key = Some(String::from_utf8_lossy(v).into_owned());
+        self.inner.visit_borrowed_bytes(v)
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        *self.key = Some(String::from_utf8_lossy(&v).into_owned());
+        self.inner.visit_byte_buf(v)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(data)
+    }
+}
+
+/// A document read into memory, so that it can be deserialized more than once.
+enum Content<'de> {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    F64(f64),
+    Char(char),
+    Str(&'de str),
+    String(String),
+    Bytes(&'de [u8]),
+    ByteBuf(Vec<u8>),
+    None,
+    Some(Box<Content<'de>>),
+    Unit,
+    Newtype(Box<Content<'de>>),
+    Seq(Vec<Content<'de>>),
+    Map(Vec<(Content<'de>, Content<'de>)>),
+}
+
+impl Content<'_> {
+    /// Renders a map key or enum variant as a path segment.
+    fn key(&self) -> String {
+        match self {
+            Content::Bool(v) => v.to_string(),
+            Content::U64(v) => v.to_string(),
+            Content::I64(v) => v.to_string(),
+            Content::U128(v) => v.to_string(),
+            Content::I128(v) => v.to_string(),
+            Content::F64(v) => v.to_string(),
+            Content::Char(v) => v.to_string(),
+            Content::Str(v) => v.to_string(),
+            Content::String(v) => v.clone(),
+            Content::Bytes(v) => String::from_utf8_lossy(v).into_owned(),
+            Content::ByteBuf(v) => String::from_utf8_lossy(v).into_owned(),
+            _ => String::from("?"),
+        }
+    }
+
+    fn string(&self) -> Option<&str> {
+        match self {
+            Content::Str(v) => Some(v),
+            Content::String(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Content::I128(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Content::U128(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(Content::Char(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Content::String(v.to_string()))
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Content::Str(v))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Content::String(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(v.to_vec()))
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(v))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        Ok(Content::Some(Box::new(Content::deserialize(deserializer)?)))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        Ok(Content::Newtype(Box::new(Content::deserialize(
+            deserializer,
+        )?)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Content::Seq(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Content::Map(entries))
+    }
+}
+
+/// Deserializes a [Content], tracking the location of each value and leaving out the values
+/// whose paths are [skipped].
+struct Replay<'a, 'de, E> {
+    content: &'a Content<'de>,
+    human_readable: bool,
+    parse: bool,
+    error: PhantomData<E>,
+}
+
+impl<'a, 'de, E> Replay<'a, 'de, E> {
+    fn with(&self, content: &'a Content<'de>) -> Self {
+        Replay {
+            content,
+            human_readable: self.human_readable,
+            parse: self.parse,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'de, E: de::Error> Replay<'a, 'de, E> {
+    fn visit_seq<V: Visitor<'de>>(
+        self,
+        items: &'a [Content<'de>],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        let mut seq = ReplaySeq {
+            replay: self,
+            items: items.iter().enumerate(),
+        };
+        let value = visitor.visit_seq(&mut seq)?;
+        if seq
+            .items
+            .any(|(index, _)| !skipped(&PathSegment::Index(index)))
+        {
+            return Err(E::invalid_length(
+                items.len(),
+                &"fewer elements in sequence",
+            ));
+        }
+        Ok(value)
+    }
+
+    fn visit_map<V: Visitor<'de>>(
+        self,
+        entries: &'a [(Content<'de>, Content<'de>)],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        let mut map = ReplayMap {
+            replay: self,
+            entries: entries.iter(),
+            value: None,
+        };
+        let value = visitor.visit_map(&mut map)?;
+        if map
+            .entries
+            .any(|(key, _)| !skipped(&PathSegment::Key(key.key())))
+        {
+            return Err(E::invalid_length(entries.len(), &"fewer elements in map"));
+        }
+        Ok(value)
+    }
+}
+
+macro_rules! parse_string {
+    ($($method:ident($ty:ty) => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+                match self.content.string() {
+                    Some(v) if self.parse => {
+                        let parsed = v
+                            .parse::<$ty>()
+                            .map_err(|_| E::invalid_value(Unexpected::Str(v), &visitor))?;
+                        visitor.$visit(parsed)
+                    }
+                    _ => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for Replay<'_, 'de, E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(*v),
+            Content::U64(v) => visitor.visit_u64(*v),
+            Content::I64(v) => visitor.visit_i64(*v),
+            Content::U128(v) => visitor.visit_u128(*v),
+            Content::I128(v) => visitor.visit_i128(*v),
+            Content::F64(v) => visitor.visit_f64(*v),
+            Content::Char(v) => visitor.visit_char(*v),
+            Content::Str(v) => visitor.visit_borrowed_str(v),
+            Content::String(v) => visitor.visit_str(v),
+            Content::Bytes(v) => visitor.visit_borrowed_bytes(v),
+            Content::ByteBuf(v) => visitor.visit_bytes(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(self.with(v)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(v) => visitor.visit_newtype_struct(self.with(v)),
+            Content::Seq(v) => self.visit_seq(v, visitor),
+            Content::Map(v) => self.visit_map(v, visitor),
+        }
+    }
+
+    parse_string! {
+        deserialize_bool(bool) => visit_bool, deserialize_i8(i8) => visit_i8,
+        deserialize_i16(i16) => visit_i16, deserialize_i32(i32) => visit_i32,
+        deserialize_i64(i64) => visit_i64, deserialize_i128(i128) => visit_i128,
+        deserialize_u8(u8) => visit_u8, deserialize_u16(u16) => visit_u16,
+        deserialize_u32(u32) => visit_u32, deserialize_u64(u64) => visit_u64,
+        deserialize_u128(u128) => visit_u128, deserialize_f32(f32) => visit_f32,
+        deserialize_f64(f64) => visit_f64, deserialize_char(char) => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(self.with(v)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::Newtype(v) => visitor.visit_newtype_struct(self.with(v)),
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::Str(_) | Content::String(_) => visitor.visit_enum(ReplayEnum {
+                replay: self.with(self.content),
+                value: None,
+            }),
+            Content::Map(entries) if entries.len() == 1 => visitor.visit_enum(ReplayEnum {
+                replay: self.with(&entries[0].0),
+                value: Some(&entries[0].1),
+            }),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+}
+
+struct ReplaySeq<'a, 'de, E> {
+    replay: Replay<'a, 'de, E>,
+    items: std::iter::Enumerate<std::slice::Iter<'a, Content<'de>>>,
+}
+
+impl<'de, E: de::Error> SeqAccess<'de> for ReplaySeq<'_, 'de, E> {
+    type Error = E;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, E> {
+        let Some((index, item)) = self
+            .items
+            .find(|(index, _)| !skipped(&PathSegment::Index(*index)))
+        else {
+            return Ok(None);
+        };
+        at(PathSegment::Index(index), || {
+            seed.deserialize(self.replay.with(item)).map(Some)
+        })
+    }
+}
+
+struct ReplayMap<'a, 'de, E> {
+    replay: Replay<'a, 'de, E>,
+    entries: std::slice::Iter<'a, (Content<'de>, Content<'de>)>,
+    value: Option<(String, &'a Content<'de>)>,
+}
+
+impl<'de, E: de::Error> MapAccess<'de> for ReplayMap<'_, 'de, E> {
+    type Error = E;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, E> {
+        for (key, value) in self.entries.by_ref() {
+            let rendered = key.key();
+            if !skipped(&PathSegment::Key(rendered.clone())) {
+                self.value = Some((rendered, value));
+                return seed.deserialize(self.replay.with(key)).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, E> {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| E::custom("value is missing"))?;
+        at(PathSegment::Key(key), || {
+            seed.deserialize(self.replay.with(value))
+        })
+    }
+}
+
+struct ReplayEnum<'a, 'de, E> {
+    replay: Replay<'a, 'de, E>,
+    value: Option<&'a Content<'de>>,
+}
+
+impl<'a, 'de, E: de::Error> EnumAccess<'de> for ReplayEnum<'a, 'de, E> {
+    type Error = E;
+    type Variant = ReplayVariant<'a, 'de, E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), E> {
+        let key = self.replay.content.key();
+        let variant = seed.deserialize(self.replay.with(self.replay.content))?;
+        Ok((
+            variant,
+            ReplayVariant {
+                replay: self.replay,
+                key,
+                value: self.value,
+            },
+        ))
+    }
+}
+
+struct ReplayVariant<'a, 'de, E> {
+    replay: Replay<'a, 'de, E>,
+    key: String,
+    value: Option<&'a Content<'de>>,
+}
+
+impl<'a, 'de, E: de::Error> ReplayVariant<'a, 'de, E> {
+    /// Deserializes the value of the variant, unless it was left out.
+    fn value<T>(
+        self,
+        expected: &str,
+        f: impl FnOnce(Replay<'a, 'de, E>) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let Some(value) = self.value else {
+            return Err(E::invalid_type(Unexpected::UnitVariant, &expected));
+        };
+        let segment = PathSegment::Key(self.key);
+        if skipped(&segment) {
+            return Err(E::custom("the value of the variant was left out"));
+        }
+        at(segment, || f(self.replay.with(value)))
+    }
+}
+
+impl<'de, E: de::Error> VariantAccess<'de> for ReplayVariant<'_, 'de, E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None | Some(Content::Unit) => Ok(()),
+            Some(_) => Err(E::invalid_type(Unexpected::NewtypeVariant, &"unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, E> {
+        self.value("newtype variant", |replay| seed.deserialize(replay))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, E> {
+        self.value("tuple variant", |replay| {
+            replay.deserialize_tuple(len, visitor)
+        })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        self.value("struct variant", |replay| {
+            replay.deserialize_struct("", fields, visitor)
+        })
+    }
+}
+
+impl<E> Error<E> {
+    /// The refinement errors, if the document was otherwise deserialized successfully.
+    pub fn refinement_errors(&self) -> Option<&RefinementErrors> {
+        match self {
+            Error::Refinement(errors) => Some(errors),
+            Error::Deserialize(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, error::ErrorKind, Refinement};
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    type Small = Refinement<u8, LessThan<10>>;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    enum Shape {
+        Square(Small),
+        Rect { width: Small, height: Small },
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Doc {
+        a: Small,
+        nested: BTreeMap<String, Vec<Small>>,
+        shape: Shape,
+        optional: Option<Small>,
+    }
+
+    fn paths(errors: &RefinementErrors) -> Vec<String> {
+        errors
+            .errors()
+            .iter()
+            .map(|err| err.path().iter().map(ToString::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_records_paths() {
+        for (json, path) in [
+            (r#"{"a": 10}"#, "[a]"),
+            (r#"{"a": 1, "nested": {"x": [1, 20]}}"#, "[nested][x][1]"),
+            (
+                r#"{"a": 1, "nested": {}, "shape": {"Rect": {"width": 1, "height": 30}}}"#,
+                "[shape][Rect][height]",
+            ),
+            (
+                r#"{"a": 1, "nested": {}, "shape": {"Square": 2}, "optional": 40}"#,
+                "[optional]",
+            ),
+        ] {
+            let mut json = serde_json::Deserializer::from_str(json);
+            let err = from_deserializer::<_, Doc>(&mut json).unwrap_err();
+            let errors = err.refinement_errors().unwrap();
+            assert_eq!(paths(errors), [path]);
+            assert_eq!(
+                errors.errors()[0].kind(),
+                ErrorKind::LessThan(crate::error::Bound::Unsigned(10))
+            );
+        }
+    }
+
+    #[test]
+    fn test_collects_every_error() {
+        let mut json = serde_json::Deserializer::from_str(
+            r#"{
+                "a": 10,
+                "nested": {"x": [1, 20, 30], "y": [40]},
+                "shape": {"Rect": {"width": 10, "height": 30}},
+                "optional": 40
+            }"#,
+        );
+        let err = from_deserializer::<_, Doc>(&mut json).unwrap_err();
+        let errors = err.refinement_errors().unwrap();
+        assert_eq!(
+            paths(errors),
+            [
+                "[a]",
+                "[nested][x][1]",
+                "[nested][x][2]",
+                "[nested][y][0]",
+                "[shape][Rect][width]",
+                "[shape][Rect][height]",
+                "[optional]",
+            ]
+        );
+        assert!(STATE.with(|state| state.borrow().is_none()));
+
+        let mut json = serde_json::Deserializer::from_str(
+            r#"{"a": 1, "nested": {"x": [20]}, "shape": {"Square": 2}, "malformed": [}"#,
+        );
+        assert!(matches!(
+            from_deserializer::<_, Doc>(&mut json),
+            Err(Error::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(any(feature = "actix", feature = "axum"))]
+    fn test_strings() {
+        #[derive(Debug, Deserialize)]
+        struct Query {
+            size: Small,
+            count: Option<Small>,
+        }
+
+        let deserializer = serde::de::value::MapDeserializer::<_, serde::de::value::Error>::new(
+            [("size", "20"), ("count", "30")].into_iter(),
+        );
+        let err = from_strings::<_, Query>(deserializer).unwrap_err();
+        assert_eq!(
+            paths(err.refinement_errors().unwrap()),
+            ["[size]", "[count]"]
+        );
+
+        let deserializer = serde::de::value::MapDeserializer::<_, serde::de::value::Error>::new(
+            [("size", "2"), ("count", "3")].into_iter(),
+        );
+        let query = from_strings::<_, Query>(deserializer).unwrap();
+        assert_eq!((*query.size, query.count.map(|count| *count)), (2, Some(3)));
+    }
+
+    #[test]
+    fn test_restores_state_after_panic() {
+        struct Panics;
+
+        impl<'de> Deserialize<'de> for Panics {
+            fn deserialize<D: Deserializer<'de>>(_: D) -> Result<Self, D::Error> {
+                panic!("deserialization panicked")
+            }
+        }
+
+        let panic = std::panic::catch_unwind(|| {
+            let mut json = serde_json::Deserializer::from_str("1");
+            from_deserializer::<_, Panics>(&mut json)
+        });
+        assert!(panic.is_err());
+        assert!(STATE.with(|state| state.borrow().is_none()));
+
+        let mut json = serde_json::Deserializer::from_str("20");
+        assert!(serde::Deserialize::deserialize(&mut json)
+            .map(|value: Small| *value)
+            .is_err());
+    }
+
+    #[test]
+    fn test_success() {
+        let mut json = serde_json::Deserializer::from_str(
+            r#"{"a": 1, "nested": {}, "shape": {"Square": 2}, "optional": null}"#,
+        );
+        let doc = from_deserializer::<_, Doc>(&mut json).unwrap();
+        assert_eq!(*doc.a, 1);
+        assert!(STATE.with(|state| state.borrow().is_none()));
+    }
+
+    #[test]
+    fn test_named() {
+        let mut json = serde_json::Deserializer::from_str(
+            r#"{"a": 1, "nested": {"x": [1, 20]}, "shape": {"Square": 2}, "optional": null}"#,
+        );
+        let err = from_deserializer_named::<_, Doc>(&mut json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: nested.x[1] must be less than 10 at line 1 column 32"
+        );
+
+        let mut json =
+            serde_json::Deserializer::from_str(r#"{"Rect": {"width": 10, "height": 1}}"#);
+        let err = from_deserializer_named::<_, Shape>(&mut json).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("refinement violated: Rect.width must be less than 10"));
+        assert!(STATE.with(|state| state.borrow().is_none()));
+    }
+
+    #[test]
+    fn test_deserialize_error() {
+        let mut json = serde_json::Deserializer::from_str(r#"{"a": 1, "nested": 1}"#);
+        let err = from_deserializer::<_, Doc>(&mut json).unwrap_err();
+        assert!(matches!(err, Error::Deserialize(_)));
+        assert!(STATE.with(|state| state.borrow().is_none()));
+    }
+}
@@ -0,0 +1,352 @@
+//! Dynamic refinement.
+//!
+//! The [predicates](crate::Predicate) used by [Refinement](crate::Refinement) are fixed at compile
+//! time. Some applications instead need to select or compose validation rules at runtime, for
+//! example from configuration or from plugins. [DynPredicate] is an object-safe counterpart of
+//! [Predicate], and [BoxedRefinement] certifies that a value satisfies a runtime predicate.
+//! Failures are reported with the same [RefinementError] used by static refinement.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use refined::{boundable::unsigned::{GreaterThan, LessThan}, dynamic::*};
+//!
+//! // Rules selected at runtime, e.g. from configuration.
+//! let strict = true;
+//! let mut rules: Vec<BoxedPredicate<u32>> = vec![Box::new(Static::<LessThan<100>>::new())];
+//! if strict {
+//!     rules.push(Box::new(Static::<GreaterThan<10>>::new()));
+//!     rules.push(Box::new(from_fn(|v: &u32| v % 2 == 0, "must be even")));
+//! }
+//! let predicate = Arc::new(DynAnd::new(rules));
+//!
+//! let value = BoxedRefinement::refine(predicate.clone(), 42).unwrap();
+//! assert_eq!(*value, 42);
+//!
+//! let err = BoxedRefinement::refine(predicate, 5).unwrap_err();
+//! assert_eq!(
+//!     err.to_string(),
+//!     "refinement violated: must be greater than 10 and must be even"
+//! );
+//! ```
+use alloc::{boxed::Box, format, string::String, sync::Arc, vec::Vec};
+use core::{fmt::Display, marker::PhantomData, ops::Deref};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+use crate::{error::ErrorKind, ErrorMessage, Predicate, RefinementError, StatefulPredicate};
+
+/// An object-safe assertion that must hold for a value to be refined by a [BoxedRefinement].
+pub trait DynPredicate<T> {
+    /// Whether a value satisfies the predicate.
+    ///
+    /// As with [Predicate::test], implementations **must** be pure.
+    fn test(&self, value: &T) -> bool;
+
+    /// An error message to display when the predicate doesn't hold.
+    fn error(&self) -> ErrorMessage;
+
+    /// The error to report when `value` does not satisfy the predicate.
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(ErrorKind::Custom, self.error())
+    }
+}
+
+/// A boxed [DynPredicate] that can be shared between threads.
+pub type BoxedPredicate<T> = Box<dyn DynPredicate<T> + Send + Sync>;
+
+impl<T, D: DynPredicate<T> + ?Sized> DynPredicate<T> for Box<D> {
+    fn test(&self, value: &T) -> bool {
+        (**self).test(value)
+    }
+
+    fn error(&self) -> ErrorMessage {
+        (**self).error()
+    }
+
+    fn violation(&self, value: &T) -> RefinementError {
+        (**self).violation(value)
+    }
+}
+
+impl<T, D: DynPredicate<T> + ?Sized> DynPredicate<T> for Arc<D> {
+    fn test(&self, value: &T) -> bool {
+        (**self).test(value)
+    }
+
+    fn error(&self) -> ErrorMessage {
+        (**self).error()
+    }
+
+    fn violation(&self, value: &T) -> RefinementError {
+        (**self).violation(value)
+    }
+}
+
+/// A [DynPredicate] that checks the static [Predicate] `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Static<P>(PhantomData<fn() -> P>);
+
+impl<P> Static<P> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T, P: Predicate<T>> DynPredicate<T> for Static<P> {
+    fn test(&self, value: &T) -> bool {
+        P::test(value)
+    }
+
+    fn error(&self) -> ErrorMessage {
+        P::error()
+    }
+
+    fn violation(&self, value: &T) -> RefinementError {
+        P::violation(value)
+    }
+}
+
+/// A [DynPredicate] that checks the [StatefulPredicate] `P` using its state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Stateful<P>(pub P);
+
+impl<T, P: StatefulPredicate<T>> DynPredicate<T> for Stateful<P> {
+    fn test(&self, value: &T) -> bool {
+        self.0.test(value)
+    }
+
+    fn error(&self) -> ErrorMessage {
+        self.0.error()
+    }
+
+    fn violation(&self, value: &T) -> RefinementError {
+        self.0.violation(value)
+    }
+}
+
+/// A [DynPredicate] defined by a function. See [from_fn].
+#[derive(Clone, Copy, Debug)]
+pub struct FromFn<F> {
+    test: F,
+    message: &'static str,
+}
+
+/// Creates a [DynPredicate] from a pure function and an error message.
+pub fn from_fn<T, F: Fn(&T) -> bool>(test: F, message: &'static str) -> FromFn<F> {
+    FromFn { test, message }
+}
+
+impl<T, F: Fn(&T) -> bool> DynPredicate<T> for FromFn<F> {
+    fn test(&self, value: &T) -> bool {
+        (self.test)(value)
+    }
+
+    fn error(&self) -> ErrorMessage {
+        String::from(self.message)
+    }
+}
+
+/// Logical conjunction of any number of [dynamic predicates](DynPredicate).
+///
+/// Violations report each of the predicates that failed, like [And](crate::boolean::And).
+pub struct DynAnd<T>(Vec<BoxedPredicate<T>>);
+
+impl<T> DynAnd<T> {
+    pub fn new(predicates: Vec<BoxedPredicate<T>>) -> Self {
+        Self(predicates)
+    }
+}
+
+impl<T> DynPredicate<T> for DynAnd<T> {
+    fn test(&self, value: &T) -> bool {
+        self.0.iter().all(|predicate| predicate.test(value))
+    }
+
+    fn error(&self) -> ErrorMessage {
+        let messages: Vec<_> = self.0.iter().map(|predicate| predicate.error()).collect();
+        messages.join(" and ")
+    }
+
+    fn violation(&self, value: &T) -> RefinementError {
+        let causes = self
+            .0
+            .iter()
+            .filter(|predicate| !predicate.test(value))
+            .map(|predicate| predicate.violation(value))
+            .collect();
+        RefinementError::new(ErrorKind::And, self.error()).with_causes(causes)
+    }
+}
+
+/// Logical disjunction of any number of [dynamic predicates](DynPredicate).
+pub struct DynOr<T>(Vec<BoxedPredicate<T>>);
+
+impl<T> DynOr<T> {
+    pub fn new(predicates: Vec<BoxedPredicate<T>>) -> Self {
+        Self(predicates)
+    }
+}
+
+impl<T> DynPredicate<T> for DynOr<T> {
+    fn test(&self, value: &T) -> bool {
+        self.0.iter().any(|predicate| predicate.test(value))
+    }
+
+    fn error(&self) -> ErrorMessage {
+        let messages: Vec<_> = self.0.iter().map(|predicate| predicate.error()).collect();
+        messages.join(" or ")
+    }
+
+    fn violation(&self, value: &T) -> RefinementError {
+        let causes = self
+            .0
+            .iter()
+            .map(|predicate| predicate.violation(value))
+            .collect();
+        RefinementError::new(ErrorKind::Or, self.error()).with_causes(causes)
+    }
+}
+
+/// Logical negation of a [dynamic predicate](DynPredicate).
+pub struct DynNot<T>(BoxedPredicate<T>);
+
+impl<T> DynNot<T> {
+    pub fn new(predicate: BoxedPredicate<T>) -> Self {
+        Self(predicate)
+    }
+}
+
+impl<T> DynPredicate<T> for DynNot<T> {
+    fn test(&self, value: &T) -> bool {
+        !self.0.test(value)
+    }
+
+    fn error(&self) -> ErrorMessage {
+        format!("not {}", self.0.error())
+    }
+
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(ErrorKind::Not, self.error())
+    }
+}
+
+/// A refinement of a type `T` certifying that a [DynPredicate] holds.
+///
+/// The predicate is retained alongside the value so that modifications can be re-certified.
+pub struct BoxedRefinement<T> {
+    value: T,
+    predicate: Arc<dyn DynPredicate<T> + Send + Sync>,
+}
+
+impl<T> BoxedRefinement<T> {
+    /// Attempts to refine a runtime value with `predicate`.
+    pub fn refine(
+        predicate: Arc<dyn DynPredicate<T> + Send + Sync>,
+        value: T,
+    ) -> Result<Self, RefinementError> {
+        if predicate.test(&value) {
+            Ok(Self { value, predicate })
+        } else {
+            Err(predicate.violation(&value))
+        }
+    }
+
+    /// Attempts a modification of a refined value, re-certifying that the predicate still holds
+    /// after the modification is complete.
+    pub fn modify<F: FnOnce(T) -> T>(self, fun: F) -> Result<Self, RefinementError> {
+        Self::refine(self.predicate, fun(self.value))
+    }
+
+    /// Attempts a replacement of a refined value, re-certifying that the predicate holds for the
+    /// new value.
+    pub fn replace(self, value: T) -> Result<Self, RefinementError> {
+        Self::refine(self.predicate, value)
+    }
+
+    /// The predicate that certifies the value.
+    pub fn predicate(&self) -> &Arc<dyn DynPredicate<T> + Send + Sync> {
+        &self.predicate
+    }
+
+    /// Destructively removes the refined value from the `BoxedRefinement` wrapper.
+    pub fn take(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for BoxedRefinement<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: Clone> Clone for BoxedRefinement<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for BoxedRefinement<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("BoxedRefinement").field(&self.value).finish()
+    }
+}
+
+impl<T: Display> Display for BoxedRefinement<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for BoxedRefinement<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boundable::unsigned::*, error::Bound, string::Trimmed};
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn test_static() {
+        let predicate: Arc<dyn DynPredicate<u8> + Send + Sync> =
+            Arc::new(Static::<LessThan<10>>::new());
+        assert!(BoxedRefinement::refine(predicate.clone(), 5).is_ok());
+        let err = BoxedRefinement::refine(predicate, 10).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LessThan(Bound::Unsigned(10)));
+    }
+
+    #[test]
+    fn test_or_not() {
+        let predicate = Arc::new(DynOr::<u8>::new(vec![
+            Box::new(Static::<LessThan<10>>::new()),
+            Box::new(DynNot::new(Box::new(Static::<LessThan<100>>::new()))),
+        ]));
+        assert!(BoxedRefinement::refine(predicate.clone(), 5).is_ok());
+        assert!(BoxedRefinement::refine(predicate.clone(), 150).is_ok());
+        let err = BoxedRefinement::refine(predicate, 50).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Or);
+        assert_eq!(err.causes().len(), 2);
+    }
+
+    #[test]
+    fn test_modify() {
+        let predicate = Arc::new(Static::<Trimmed>::new());
+        let value = BoxedRefinement::refine(predicate, "foo".to_string()).unwrap();
+        let value = value.modify(|s| s + "bar").unwrap();
+        assert_eq!(*value, "foobar");
+        assert!(value.modify(|s| s + " ").is_err());
+    }
+}
@@ -0,0 +1,1086 @@
+//! Structured refinement errors.
+//!
+//! Every failed refinement produces a [RefinementError]. In addition to a human-readable message,
+//! the error carries a machine-readable [ErrorKind] describing the violated predicate (including
+//! any statically known bounds) and, when an allocator is available, the name of the refined field
+//! and the nested causes of combinator failures.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boolean::And, boundable::unsigned::*, error::{Bound, ErrorKind}};
+//!
+//! type Size = Refinement<u8, And<GreaterThan<10>, LessThan<100>>>;
+//!
+//! let err = Size::refine(5).unwrap_err();
+//! assert_eq!(err.kind(), ErrorKind::And);
+//! assert_eq!(err.causes().len(), 1);
+//! assert_eq!(err.causes()[0].kind(), ErrorKind::GreaterThan(Bound::Unsigned(10)));
+//! assert_eq!(err.causes()[0].kind().code(), "greater_than");
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+use core::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{ser::SerializeMap, Serialize, Serializer};
+#[cfg(feature = "alloc")]
+use thiserror::Error;
+
+use crate::ErrorMessage;
+
+/// A statically known bound of a [boundable](crate::boundable) predicate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Bound {
+    Unsigned(usize),
+    Signed(isize),
+}
+
+impl Display for Bound {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Bound::Unsigned(value) => write!(f, "{}", value),
+            Bound::Signed(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// The kind of [Predicate](crate::Predicate) that was violated.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorKind {
+    GreaterThan(Bound),
+    GreaterThanEqual(Bound),
+    LessThan(Bound),
+    LessThanEqual(Bound),
+    Equals(Bound),
+    Modulo {
+        divisor: Bound,
+        remainder: Bound,
+    },
+    StartsWith(&'static str),
+    EndsWith(&'static str),
+    Contains(&'static str),
+    Trimmed,
+    NoWhitespace,
+    Ascii,
+    Regex(&'static str),
+    Control,
+    Digit,
+    Lowercase,
+    Uppercase,
+    Numeric,
+    Whitespace,
+    HexDigit,
+    True,
+    False,
+    And,
+    Or,
+    Xor,
+    Not,
+    All,
+    AllKeys,
+    AllValues,
+    Sorted,
+    /// Arithmetic on a refinement produced a value that is not representable by its base type.
+    Overflow,
+    /// A predicate that does not provide a more specific kind.
+    Custom,
+}
+
+impl ErrorKind {
+    /// A stable, machine-readable identifier for the kind of predicate.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            ErrorKind::GreaterThan(_) => "greater_than",
+            ErrorKind::GreaterThanEqual(_) => "greater_than_equal",
+            ErrorKind::LessThan(_) => "less_than",
+            ErrorKind::LessThanEqual(_) => "less_than_equal",
+            ErrorKind::Equals(_) => "equals",
+            ErrorKind::Modulo { .. } => "modulo",
+            ErrorKind::StartsWith(_) => "starts_with",
+            ErrorKind::EndsWith(_) => "ends_with",
+            ErrorKind::Contains(_) => "contains",
+            ErrorKind::Trimmed => "trimmed",
+            ErrorKind::NoWhitespace => "no_whitespace",
+            ErrorKind::Ascii => "ascii",
+            ErrorKind::Regex(_) => "regex",
+            ErrorKind::Control => "control",
+            ErrorKind::Digit => "digit",
+            ErrorKind::Lowercase => "lowercase",
+            ErrorKind::Uppercase => "uppercase",
+            ErrorKind::Numeric => "numeric",
+            ErrorKind::Whitespace => "whitespace",
+            ErrorKind::HexDigit => "hex_digit",
+            ErrorKind::True => "true",
+            ErrorKind::False => "false",
+            ErrorKind::And => "and",
+            ErrorKind::Or => "or",
+            ErrorKind::Xor => "xor",
+            ErrorKind::Not => "not",
+            ErrorKind::All => "all",
+            ErrorKind::AllKeys => "all_keys",
+            ErrorKind::AllValues => "all_values",
+            ErrorKind::Sorted => "sorted",
+            ErrorKind::Overflow => "overflow",
+            ErrorKind::Custom => "custom",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErrorKind::GreaterThan(bound) => write!(f, "must be greater than {}", bound),
+            ErrorKind::GreaterThanEqual(bound) => {
+                write!(f, "must be greater than or equal to {}", bound)
+            }
+            ErrorKind::LessThan(bound) => write!(f, "must be less than {}", bound),
+            ErrorKind::LessThanEqual(bound) => write!(f, "must be less than or equal to {}", bound),
+            ErrorKind::Equals(bound) => write!(f, "must be equal to {}", bound),
+            ErrorKind::Modulo { divisor, remainder } => write!(
+                f,
+                "must be divisible by {} with a remainder of {}",
+                divisor, remainder
+            ),
+            ErrorKind::StartsWith(prefix) => write!(f, "must start with '{}'", prefix),
+            ErrorKind::EndsWith(suffix) => write!(f, "must end with '{}'", suffix),
+            ErrorKind::Contains(substr) => write!(f, "must contain '{}'", substr),
+            ErrorKind::Trimmed => write!(f, "must not start or end with whitespace"),
+            ErrorKind::NoWhitespace => write!(f, "must not contain whitespace"),
+            ErrorKind::Ascii => write!(f, "must be ASCII"),
+            ErrorKind::Regex(regex) => write!(f, "must match regular expression {}", regex),
+            ErrorKind::Control => write!(f, "must be a control character"),
+            ErrorKind::Digit => write!(f, "must be a digit"),
+            ErrorKind::Lowercase => write!(f, "must be a lowercase character"),
+            ErrorKind::Uppercase => write!(f, "must be an uppercase character"),
+            ErrorKind::Numeric => write!(f, "must be a numeric character"),
+            ErrorKind::Whitespace => write!(f, "must be a whitespace character"),
+            ErrorKind::HexDigit => write!(f, "must be a valid hex character"),
+            ErrorKind::True => write!(f, "true predicate"),
+            ErrorKind::False => write!(f, "false predicate"),
+            ErrorKind::And => write!(f, "conjunction"),
+            ErrorKind::Or => write!(f, "disjunction"),
+            ErrorKind::Xor => write!(f, "exclusive disjunction"),
+            ErrorKind::Not => write!(f, "negation"),
+            ErrorKind::All => write!(f, "every element must satisfy the predicate"),
+            ErrorKind::AllKeys => write!(f, "every key must satisfy the predicate"),
+            ErrorKind::AllValues => write!(f, "every value must satisfy the predicate"),
+            ErrorKind::Sorted => write!(f, "must be sorted"),
+            ErrorKind::Overflow => write!(f, "must not overflow"),
+            ErrorKind::Custom => write!(f, "custom predicate"),
+        }
+    }
+}
+
+/// The message of a [RefinementError], which may not have been rendered yet.
+///
+/// Comparison and hashing are performed on the rendered message, so that errors with the same
+/// text are equal however their messages were provided.
+#[derive(Clone)]
+enum Message {
+    Rendered(ErrorMessage),
+    Deferred(fn() -> ErrorMessage),
+}
+
+impl Message {
+    fn render(&self) -> ErrorMessage {
+        match self {
+            Message::Rendered(message) => ErrorMessage::clone(message),
+            Message::Deferred(message) => message(),
+        }
+    }
+}
+
+impl core::fmt::Debug for Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.render(), f)
+    }
+}
+
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.render() == other.render()
+    }
+}
+
+impl Eq for Message {}
+
+impl PartialOrd for Message {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Message {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        Ord::cmp(&self.render(), &other.render())
+    }
+}
+
+impl core::hash::Hash for Message {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.render().hash(state);
+    }
+}
+
+/// An [Error] that can result from failed refinement.
+///
+/// Without the `alloc` feature, combinators cannot record their nested causes. Instead, [And]
+/// reports the error of the conjunct that failed, so that the error still describes a concrete
+/// [ErrorKind] wherever possible.
+///
+/// [Error]: core::error::Error
+/// [And]: crate::boolean::And
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "alloc", derive(Error))]
+pub struct RefinementError {
+    kind: ErrorKind,
+    message: Message,
+    overridden: bool,
+    #[cfg(feature = "alloc")]
+    context: Option<Box<Context>>,
+}
+
+/// Optional error context, boxed so that the happy path of `Result<_, RefinementError>` stays small.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Context {
+    name: Option<String>,
+    value: Option<String>,
+    path: Vec<PathSegment>,
+    causes: Vec<RefinementError>,
+}
+
+/// A segment of the location of an element within a collection.
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathSegment {
+    /// The position of an element within a sequence.
+    Index(usize),
+    /// A rendering of the key of an entry within a map.
+    Key(String),
+}
+
+#[cfg(feature = "alloc")]
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PathSegment::Index(index) => write!(f, "[{}]", index),
+            PathSegment::Key(key) => write!(f, "[{}]", key),
+        }
+    }
+}
+
+impl RefinementError {
+    /// Creates a new error for a violated predicate of the given kind.
+    pub fn new(kind: ErrorKind, message: ErrorMessage) -> Self {
+        Self::from_parts(kind, Message::Rendered(message))
+    }
+
+    /// Creates a new error whose message is only rendered when it is requested.
+    ///
+    /// This avoids building (and, with `alloc`, allocating) a message for errors that are
+    /// discarded without being displayed.
+    pub fn deferred(kind: ErrorKind, message: fn() -> ErrorMessage) -> Self {
+        Self::from_parts(kind, Message::Deferred(message))
+    }
+
+    fn from_parts(kind: ErrorKind, message: Message) -> Self {
+        Self {
+            kind,
+            message,
+            overridden: false,
+            #[cfg(feature = "alloc")]
+            context: None,
+        }
+    }
+
+    /// The kind of predicate that was violated.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// A description of the predicate that was violated.
+    ///
+    /// For a failed conjunction, this describes only the conjuncts that were violated, unless the
+    /// message was [overridden](RefinementError::with_message).
+    pub fn message(&self) -> ErrorMessage {
+        #[cfg(feature = "alloc")]
+        if self.is_aggregate() && !self.overridden {
+            let messages: Vec<_> = self
+                .violations()
+                .iter()
+                .map(|v| match v.path() {
+                    [] => v.message(),
+                    path => format!("element {}: {}", render_path(path), v.message()),
+                })
+                .collect();
+            return messages.join(" and ");
+        }
+        self.message.render()
+    }
+
+    /// The name of the refinement that was violated, if it was [named](crate::Named).
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    pub fn name(&self) -> Option<&str> {
+        self.context.as_ref()?.name.as_deref()
+    }
+
+    /// A rendering of the value that was rejected, if it was captured.
+    ///
+    /// Values are only captured when explicitly requested, e.g. by
+    /// [Refinement::refine_verbose](crate::Refinement::refine_verbose).
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    pub fn value(&self) -> Option<&str> {
+        self.context.as_ref()?.value.as_deref()
+    }
+
+    /// The errors of the nested predicates that caused this error, for combinators.
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    pub fn causes(&self) -> &[RefinementError] {
+        self.context
+            .as_ref()
+            .map_or(&[], |context| context.causes.as_slice())
+    }
+
+    /// The location of the violating element, relative to the outermost refined collection.
+    ///
+    /// The path is empty unless the error was produced by an element-wise predicate such as
+    /// [All](crate::collection::All).
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    pub fn path(&self) -> &[PathSegment] {
+        self.context
+            .as_ref()
+            .map_or(&[], |context| context.path.as_slice())
+    }
+
+    /// Records that this error occurred within the element at `segment` of an enclosing
+    /// collection.
+    ///
+    /// The segment is prepended to the paths of this error and all of its causes, so that every
+    /// violation carries its full location.
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    pub fn at(mut self, segment: PathSegment) -> Self {
+        let context = self.context_mut();
+        context.path.insert(0, segment.clone());
+        context.causes = core::mem::take(&mut context.causes)
+            .into_iter()
+            .map(|cause| cause.at(segment.clone()))
+            .collect();
+        self
+    }
+
+    /// Each individual predicate violation that makes up this error.
+    ///
+    /// Failed conjunctions and element-wise predicates are flattened into the individual
+    /// violations that they contain, so that callers can enumerate every violated requirement
+    /// along with its [path](RefinementError::path). Any other error is a single violation.
+    ///
+    /// ```
+    /// use refined::{prelude::*, boolean::And, boundable::unsigned::*, error::*};
+    ///
+    /// type Test = Refinement<u8, And<GreaterThan<10>, And<Even, LessThan<100>>>>;
+    ///
+    /// let err = Test::refine(5).unwrap_err();
+    /// let kinds: Vec<_> = err.violations().iter().map(|v| v.kind()).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     vec![
+    ///         ErrorKind::GreaterThan(Bound::Unsigned(10)),
+    ///         ErrorKind::Modulo { divisor: Bound::Unsigned(2), remainder: Bound::Unsigned(0) },
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "refinement violated: must be greater than 10 and must be divisible by 2 with a remainder of 0"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    pub fn violations(&self) -> Vec<&RefinementError> {
+        if self.is_aggregate() {
+            self.causes()
+                .iter()
+                .flat_map(RefinementError::violations)
+                .collect()
+        } else {
+            vec![self]
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_aggregate(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::And | ErrorKind::All | ErrorKind::AllKeys | ErrorKind::AllValues
+        ) && !self.causes().is_empty()
+    }
+
+    /// Attaches the errors of nested predicates to this error.
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    pub fn with_causes(mut self, causes: Vec<RefinementError>) -> Self {
+        self.context_mut().causes = causes;
+        self
+    }
+
+    /// Replaces the description of the violated predicate with `message`.
+    ///
+    /// The rest of the error, including its [kind](RefinementError::kind) and any nested causes,
+    /// is preserved.
+    pub fn with_message(mut self, message: ErrorMessage) -> Self {
+        self.message = Message::Rendered(message);
+        self.overridden = true;
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    fn context_mut(&mut self) -> &mut Context {
+        self.context.get_or_insert_with(Box::default)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn with_value<T: Display + ?Sized>(mut self, value: &T) -> Self {
+        self.context_mut().value = Some(format!("{}", value));
+        self
+    }
+
+    /// Records that this error occurred within the field `parent`, so that its name becomes the
+    /// path of the field, e.g. `address.zip` or `items[3].size`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn within(mut self, parent: &str) -> Self {
+        let context = self.context_mut();
+        context.name = Some(match context.name.take() {
+            Some(inner) if inner.starts_with('[') => format!("{}{}", parent, inner),
+            Some(inner) => format!("{}.{}", parent, inner),
+            None => String::from(parent),
+        });
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn named(mut self, name: &str) -> Self {
+        let context = self.context_mut();
+        context.name = Some(match context.name.take() {
+            Some(inner) => format!("{} {}", name, inner),
+            None => String::from(name),
+        });
+        self
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl core::error::Error for RefinementError {}
+
+#[cfg(not(feature = "alloc"))]
+impl Display for RefinementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            _ if self.overridden => write!(f, "refinement violated: {}", self.message()),
+            ErrorKind::Custom => write!(f, "refinement violated: {}", self.message()),
+            kind => write!(f, "refinement violated: {}", kind),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn render_path(path: &[PathSegment]) -> String {
+    path.iter().map(|segment| format!("{}", segment)).collect()
+}
+
+#[cfg(feature = "alloc")]
+impl Display for RefinementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "refinement violated: ")?;
+        if let Some(name) = self.name() {
+            write!(f, "{} ", name)?;
+        }
+        if !self.is_aggregate() && !self.path().is_empty() {
+            write!(f, "element {}: ", render_path(self.path()))?;
+        }
+        write!(f, "{}", self.message())?;
+        if let Some(value) = self.value() {
+            write!(f, " (got {})", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every [RefinementError] produced while validating a group of values.
+///
+/// This is returned by validations that check each value rather than stopping at the first
+/// failure, such as the constructors generated by `#[derive(Refined)]` with the `macros` feature.
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan, error::RefinementErrors};
+///
+/// type Small = Refinement<u8, LessThan<10>>;
+///
+/// let mut errors = RefinementErrors::new();
+/// let a = errors.check("a", Small::refine(5));
+/// let b = errors.check("b", Small::refine(50));
+/// let c = errors.check("c", Small::refine(100));
+///
+/// assert!(a.is_some() && b.is_none() && c.is_none());
+/// assert_eq!(
+///     errors.to_string(),
+///     "refinement violated: b must be less than 10; refinement violated: c must be less than 10"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+pub struct RefinementErrors(Vec<RefinementError>);
+
+#[cfg(feature = "alloc")]
+impl RefinementErrors {
+    /// Creates an empty collection of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the error of a failed refinement, naming it `name` unless it was already
+    /// [named](crate::Named).
+    ///
+    /// Returns the refined value if the refinement succeeded.
+    pub fn check<T>(&mut self, name: &str, result: Result<T, RefinementError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.push(match err.name() {
+                    Some(_) => err,
+                    None => err.named(name),
+                });
+                None
+            }
+        }
+    }
+
+    /// Records the errors of a failed group of refinements that belong to the field `name`, such as
+    /// the fields of a nested struct. Each error is named after its path from this field, e.g.
+    /// `address.zip`.
+    ///
+    /// Returns the value if every refinement succeeded.
+    ///
+    /// ```
+    /// use refined::{prelude::*, boundable::unsigned::LessThan, error::RefinementErrors};
+    ///
+    /// type Small = Refinement<u8, LessThan<10>>;
+    ///
+    /// let mut inner = RefinementErrors::new();
+    /// inner.check("zip", Small::refine(50));
+    ///
+    /// let mut errors = RefinementErrors::new();
+    /// let address = errors.nest("address", Err::<(), _>(inner));
+    ///
+    /// assert!(address.is_none());
+    /// assert_eq!(
+    ///     errors.to_string(),
+    ///     "refinement violated: address.zip must be less than 10"
+    /// );
+    /// ```
+    pub fn nest<T>(&mut self, name: &str, result: Result<T, RefinementErrors>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(errors) => {
+                self.0
+                    .extend(errors.0.into_iter().map(|err| err.within(name)));
+                None
+            }
+        }
+    }
+
+    /// Records an error.
+    pub fn push(&mut self, err: RefinementError) {
+        self.0.push(err);
+    }
+
+    /// Whether no errors have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The number of recorded errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The recorded errors, in the order that they were recorded.
+    pub fn errors(&self) -> &[RefinementError] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for RefinementErrors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, err) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", err)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<RefinementError> for RefinementErrors {
+    fn from(err: RefinementError) -> Self {
+        Self(vec![err])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl IntoIterator for RefinementErrors {
+    type Item = RefinementError;
+    type IntoIter = vec::IntoIter<RefinementError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> IntoIterator for &'a RefinementErrors {
+    type Item = &'a RefinementError;
+    type IntoIter = core::slice::Iter<'a, RefinementError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Serializes the kind-specific parameters of an [ErrorKind], such as its bounds.
+#[cfg(feature = "serde")]
+struct Params(ErrorKind);
+
+#[cfg(feature = "serde")]
+impl Serialize for Bound {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Bound::Unsigned(value) => serializer.serialize_u64(*value as u64),
+            Bound::Signed(value) => serializer.serialize_i64(*value as i64),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Params {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match &self.0 {
+            ErrorKind::GreaterThan(bound)
+            | ErrorKind::GreaterThanEqual(bound)
+            | ErrorKind::LessThan(bound)
+            | ErrorKind::LessThanEqual(bound)
+            | ErrorKind::Equals(bound) => map.serialize_entry("bound", bound)?,
+            ErrorKind::Modulo { divisor, remainder } => {
+                map.serialize_entry("divisor", divisor)?;
+                map.serialize_entry("remainder", remainder)?;
+            }
+            ErrorKind::StartsWith(prefix) => map.serialize_entry("prefix", prefix)?,
+            ErrorKind::EndsWith(suffix) => map.serialize_entry("suffix", suffix)?,
+            ErrorKind::Contains(substring) => map.serialize_entry("substring", substring)?,
+            ErrorKind::Regex(pattern) => map.serialize_entry("pattern", pattern)?,
+            _ => {}
+        }
+        map.end()
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl Serialize for PathSegment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            PathSegment::Index(index) => serializer.serialize_u64(*index as u64),
+            PathSegment::Key(key) => serializer.serialize_str(key),
+        }
+    }
+}
+
+/// Serializes the error as an object with a stable shape, suitable for API error payloads.
+///
+/// ```json
+/// {
+///   "code": "less_than",
+///   "message": "must be less than 100",
+///   "params": { "bound": 100 },
+///   "name": "size",
+///   "value": null,
+///   "path": [],
+///   "causes": []
+/// }
+/// ```
+///
+/// `name`, `value`, `path` and `causes` are only present when the `alloc` feature is enabled.
+#[cfg(feature = "serde")]
+#[doc(cfg(feature = "serde"))]
+impl Serialize for RefinementError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", self.kind.code())?;
+        map.serialize_entry("message", &self.message())?;
+        map.serialize_entry("params", &Params(self.kind))?;
+        #[cfg(feature = "alloc")]
+        {
+            map.serialize_entry("name", &self.name())?;
+            map.serialize_entry("value", &self.value())?;
+            map.serialize_entry("path", self.path())?;
+            map.serialize_entry("causes", self.causes())?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes the errors as a sequence of [RefinementError]s.
+#[cfg(all(feature = "serde", feature = "alloc"))]
+#[doc(cfg(feature = "serde"))]
+impl Serialize for RefinementErrors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Renders refinement errors as [miette] diagnostics.
+///
+/// The diagnostic code is derived from the [ErrorKind], the help text describes each individual
+/// violation, and captured values (see
+/// [Refinement::refine_verbose](crate::Refinement::refine_verbose)) are labeled as source code.
+#[cfg(feature = "miette")]
+#[doc(cfg(feature = "miette"))]
+impl miette::Diagnostic for RefinementError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(format!("refined::{}", self.kind.code())))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let help: Vec<_> = self
+            .violations()
+            .iter()
+            .map(|violation| match violation.kind {
+                _ if violation.overridden => violation.message(),
+                ErrorKind::Custom => violation.message(),
+                kind => format!("{}", kind),
+            })
+            .collect();
+        Some(Box::new(help.join("; ")))
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        self.context
+            .as_ref()?
+            .value
+            .as_ref()
+            .map(|value| value as _)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let value = self.value()?;
+        Some(Box::new(core::iter::once(miette::LabeledSpan::at(
+            0..value.len(),
+            self.message(),
+        ))))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+        if self.causes().is_empty() {
+            None
+        } else {
+            Some(Box::new(
+                self.causes()
+                    .iter()
+                    .map(|cause| cause as &dyn miette::Diagnostic),
+            ))
+        }
+    }
+}
+
+/// Converts the error into a [validator::ValidationError], so that refinement failures can be
+/// reported alongside those of the [validator] crate.
+///
+/// The code is that of the [ErrorKind], the message is preserved, and the kind-specific
+/// parameters (and the captured value, if any) become parameters of the validation error.
+#[cfg(feature = "validator")]
+#[doc(cfg(feature = "validator"))]
+impl From<RefinementError> for validator::ValidationError {
+    fn from(err: RefinementError) -> Self {
+        let mut error =
+            validator::ValidationError::new(err.kind.code()).with_message(err.message().into());
+        match err.kind {
+            ErrorKind::GreaterThan(bound)
+            | ErrorKind::GreaterThanEqual(bound)
+            | ErrorKind::LessThan(bound)
+            | ErrorKind::LessThanEqual(bound)
+            | ErrorKind::Equals(bound) => error.add_param("bound".into(), &bound),
+            ErrorKind::Modulo { divisor, remainder } => {
+                error.add_param("divisor".into(), &divisor);
+                error.add_param("remainder".into(), &remainder);
+            }
+            ErrorKind::StartsWith(prefix) => error.add_param("prefix".into(), &prefix),
+            ErrorKind::EndsWith(suffix) => error.add_param("suffix".into(), &suffix),
+            ErrorKind::Contains(substring) => error.add_param("substring".into(), &substring),
+            ErrorKind::Regex(pattern) => error.add_param("pattern".into(), &pattern),
+            _ => {}
+        }
+        if let Some(value) = err.value() {
+            error.add_param("value".into(), &value);
+        }
+        error
+    }
+}
+
+/// Converts the errors into [validator::ValidationErrors], keyed by the name of each refined field.
+///
+/// Errors without a name are reported under `__all__`, as [validator] does for schema-level
+/// validation.
+#[cfg(feature = "validator")]
+#[doc(cfg(feature = "validator"))]
+impl From<RefinementErrors> for validator::ValidationErrors {
+    fn from(errs: RefinementErrors) -> Self {
+        let mut errors = validator::ValidationErrors::new();
+        for err in errs {
+            let field = String::from(err.name().unwrap_or("__all__"));
+            let kind = errors
+                .errors_mut()
+                .entry(field.into())
+                .or_insert_with(|| validator::ValidationErrorsKind::Field(Vec::new()));
+            if let validator::ValidationErrorsKind::Field(field) = kind {
+                field.push(err.into());
+            }
+        }
+        errors
+    }
+}
+
+/// Converts the error into a Python `ValueError` with the message of the error, so that refinement
+/// failures can be raised from `#[pyfunction]`s with `?`.
+#[cfg(feature = "pyo3")]
+#[doc(cfg(feature = "pyo3"))]
+impl From<RefinementError> for pyo3::PyErr {
+    fn from(err: RefinementError) -> Self {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Converts the errors into a Python `ValueError` with the message of the errors.
+#[cfg(feature = "pyo3")]
+#[doc(cfg(feature = "pyo3"))]
+impl From<RefinementErrors> for pyo3::PyErr {
+    fn from(errs: RefinementErrors) -> Self {
+        pyo3::exceptions::PyValueError::new_err(errs.to_string())
+    }
+}
+
+/// Converts the errors into a JavaScript `Error`, so that refinement failures can be thrown across
+/// the wasm-bindgen boundary.
+///
+/// The error is named `RefinementError` and its message is that of the errors. Its `errors` property
+/// holds each error in its serialized form (see the [Serialize] implementation of
+/// [RefinementError]), so that JavaScript callers can inspect the code, parameters, and location of
+/// every violation.
+#[cfg(feature = "wasm")]
+#[doc(cfg(feature = "wasm"))]
+impl From<RefinementErrors> for wasm_bindgen::JsValue {
+    fn from(errs: RefinementErrors) -> Self {
+        let error = js_sys::Error::new(&errs.to_string());
+        error.set_name("RefinementError");
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        if let Ok(errors) = errs.serialize(&serializer) {
+            // Setting a property of a fresh `Error` cannot fail.
+            let _ = js_sys::Reflect::set(&error, &"errors".into(), &errors);
+        }
+        error.into()
+    }
+}
+
+/// Converts the error into a JavaScript `Error`, as for [RefinementErrors] containing only this
+/// error.
+#[cfg(feature = "wasm")]
+#[doc(cfg(feature = "wasm"))]
+impl From<RefinementError> for wasm_bindgen::JsValue {
+    fn from(err: RefinementError) -> Self {
+        RefinementErrors::from(err).into()
+    }
+}
+
+#[cfg(all(test, not(feature = "alloc")))]
+mod no_alloc_tests {
+    use core::fmt::Write;
+
+    use crate::{boundable::unsigned::*, error::*, *};
+
+    struct Buffer([u8; 64], usize);
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.1 + s.len();
+            self.0[self.1..end].copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_interval_reports_failing_bound() {
+        let err = Refinement::<u8, ClosedInterval<10, 20>>::refine(25).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LessThanEqual(Bound::Unsigned(20)));
+
+        let mut buffer = Buffer([0; 64], 0);
+        write!(buffer, "{}", err).unwrap();
+        assert_eq!(
+            core::str::from_utf8(&buffer.0[..buffer.1]).unwrap(),
+            "refinement violated: must be less than or equal to 20"
+        );
+    }
+
+    #[test]
+    fn test_with_message() {
+        predicate_alias!(Small, LessThan<10>, "must be small");
+        let err = Refinement::<u8, Small>::refine(25).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LessThan(Bound::Unsigned(10)));
+
+        let mut buffer = Buffer([0; 64], 0);
+        write!(buffer, "{}", err).unwrap();
+        assert_eq!(
+            core::str::from_utf8(&buffer.0[..buffer.1]).unwrap(),
+            "refinement violated: must be small"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{boolean::*, boundable::*, error::*, *};
+    use alloc::{format, string::ToString, vec, vec::Vec};
+
+    #[test]
+    fn test_boundable_kind() {
+        let err = Refinement::<i8, signed::LessThanEqual<-3>>::refine(0).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LessThanEqual(Bound::Signed(-3)));
+        assert!(err.causes().is_empty());
+    }
+
+    #[test]
+    fn test_combinator_causes() {
+        type Test = Refinement<u8, Or<unsigned::LessThan<5>, unsigned::GreaterThan<10>>>;
+        let err = Test::refine(7).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Or);
+        let kinds: Vec<_> = err.causes().iter().map(|e| e.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ErrorKind::LessThan(Bound::Unsigned(5)),
+                ErrorKind::GreaterThan(Bound::Unsigned(10))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_and_reports_failing_conjuncts() {
+        type Test = Refinement<u8, unsigned::ClosedInterval<10, 20>>;
+        let err = Test::refine(25).unwrap_err();
+        assert_eq!(err.violations().len(), 1);
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be less than or equal to 20"
+        );
+    }
+
+    #[test]
+    fn test_deferred_message() {
+        let deferred = RefinementError::deferred(ErrorKind::Custom, || "custom".to_string());
+        let rendered = RefinementError::new(ErrorKind::Custom, "custom".to_string());
+        assert_eq!(deferred, rendered);
+        assert_ne!(
+            RefinementError::deferred(ErrorKind::Custom, || "must be even".to_string()),
+            RefinementError::deferred(ErrorKind::Custom, || "must be odd".to_string())
+        );
+        assert_ne!(
+            rendered,
+            RefinementError::new(ErrorKind::Custom, "other".to_string())
+        );
+        assert_eq!(deferred.message(), "custom");
+        assert_eq!(format!("{}", deferred), "refinement violated: custom");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        type_string!(Scores, "scores");
+        type Test = Named<Scores, Refinement<Vec<u8>, collection::All<unsigned::LessThan<10>>>>;
+        let err = Test::refine(vec![1, 20]).unwrap_err();
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "code": "all",
+                "message": "element [1]: must be less than 10",
+                "params": {},
+                "name": "scores",
+                "value": null,
+                "path": [],
+                "causes": [{
+                    "code": "less_than",
+                    "message": "must be less than 10",
+                    "params": { "bound": 10 },
+                    "name": null,
+                    "value": null,
+                    "path": [1],
+                    "causes": []
+                }]
+            })
+        );
+    }
+
+    #[cfg(feature = "miette")]
+    #[test]
+    fn test_diagnostic() {
+        use miette::Diagnostic;
+
+        let err = Refinement::<u8, unsigned::LessThan<100>>::refine_verbose(123).unwrap_err();
+        assert_eq!(err.code().unwrap().to_string(), "refined::less_than");
+        assert_eq!(err.help().unwrap().to_string(), "must be less than 100");
+        let labels: Vec<_> = err.labels().unwrap().collect();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].len(), 3);
+    }
+
+    #[test]
+    fn test_named() {
+        type_string!(Port, "port");
+        type Test = Named<Port, Refinement<u16, unsigned::GreaterThan<1024>>>;
+        let err = Test::refine(80).unwrap_err();
+        assert_eq!(err.name(), Some("port"));
+        assert_eq!(err.message(), "must be greater than 1024");
+    }
+
+    #[cfg(feature = "validator")]
+    #[test]
+    fn test_validation_errors() {
+        use validator::{ValidationErrors, ValidationErrorsKind};
+
+        let mut errs = RefinementErrors::new();
+        errs.check(
+            "size",
+            Refinement::<u8, unsigned::LessThan<10>>::refine_verbose(20),
+        );
+        errs.push(Refinement::<u8, unsigned::GreaterThan<10>>::refine(5).unwrap_err());
+        let errors = ValidationErrors::from(errs);
+
+        let ValidationErrorsKind::Field(size) = &errors.errors()["size"] else {
+            panic!("expected field errors");
+        };
+        assert_eq!(size[0].code, "less_than");
+        assert_eq!(size[0].message.as_deref(), Some("must be less than 10"));
+        assert_eq!(size[0].params["bound"], 10);
+        assert_eq!(size[0].params["value"], "20");
+        assert!(errors.errors().contains_key("__all__"));
+    }
+}
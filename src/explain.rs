@@ -0,0 +1,185 @@
+//! Explanations of why values do or do not satisfy predicates.
+//!
+//! A [RefinementError](crate::RefinementError) reports the parts of a predicate that were
+//! violated as a sentence (or a list of sentences), which is hard to follow for deeply combined
+//! predicates. An [Explanation] instead mirrors the structure of the predicate, recording whether each nested predicate was
+//! satisfied along with the quantity that it measured, such as the length of a string.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boolean::{And, Or}, boundable::unsigned::{GreaterThan, LessThan}, string::Trimmed};
+//!
+//! type Name = Refinement<String, And<Trimmed, Or<GreaterThan<3>, LessThan<2>>>>;
+//!
+//! let explanation = Name::why_invalid(&"abc".to_string()).unwrap();
+//! assert!(!explanation.satisfied);
+//! assert!(explanation.children[0].satisfied);
+//! assert_eq!(
+//!     explanation.to_string(),
+//!     "\
+//! failed: conjunction
+//!   passed: must not start or end with whitespace
+//!   failed: disjunction
+//!     failed: must be greater than 3 (measured 3)
+//!     failed: must be less than 2 (measured 3)"
+//! );
+//!
+//! assert!(Name::why_invalid(&"abcd".to_string()).is_none());
+//! ```
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use crate::{
+    error::{Bound, ErrorKind, PathSegment},
+    ErrorMessage, Predicate,
+};
+
+/// A tree explaining whether a value satisfies a predicate and each of its nested predicates.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Explanation {
+    /// The kind of the predicate.
+    pub kind: ErrorKind,
+    /// The error message of the predicate.
+    pub message: ErrorMessage,
+    /// Whether the value satisfies the predicate.
+    pub satisfied: bool,
+    /// The bounding value that the predicate measured, such as the length of a string.
+    pub measured: Option<Bound>,
+    /// The location of the value within the value explained by the parent, for the elements of
+    /// collections.
+    pub location: Option<PathSegment>,
+    /// The explanations of the predicates that the predicate combines.
+    pub children: Vec<Explanation>,
+}
+
+impl Explanation {
+    /// Creates an explanation of a predicate that does not combine other predicates.
+    pub fn new(kind: ErrorKind, message: ErrorMessage, satisfied: bool) -> Self {
+        Self {
+            kind,
+            message,
+            satisfied,
+            measured: None,
+            location: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates an explanation of whether `value` satisfies the predicate `P` as a whole.
+    pub fn of<T, P: Predicate<T> + ?Sized>(value: &T) -> Self {
+        Self::new(P::kind(), P::error(), P::test(value))
+    }
+
+    /// Records the bounding value that the predicate measured.
+    pub fn measuring(mut self, measured: Bound) -> Self {
+        self.measured = Some(measured);
+        self
+    }
+
+    /// Records the location of the explained value within its parent.
+    pub fn at(mut self, segment: PathSegment) -> Self {
+        self.location = Some(segment);
+        self
+    }
+
+    /// Records the explanations of the predicates that the predicate combines.
+    pub fn with_children(mut self, children: Vec<Explanation>) -> Self {
+        self.children = children;
+        self
+    }
+
+    fn render(&self, f: &mut Formatter<'_>, depth: usize) -> fmt::Result {
+        let outcome = if self.satisfied { "passed" } else { "failed" };
+        write!(f, "{:indent$}{}: ", "", outcome, indent = depth * 2)?;
+        if let Some(location) = &self.location {
+            write!(f, "{} ", location)?;
+        }
+        if self.children.is_empty() {
+            write!(f, "{}", self.message)?;
+        } else {
+            write!(f, "{}", self.kind)?;
+        }
+        if let Some(measured) = self.measured {
+            write!(f, " (measured {})", measured)?;
+        }
+        for child in &self.children {
+            writeln!(f)?;
+            child.render(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders the explanation as an indented tree, with one predicate per line.
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.render(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::*, boundable::*, collection::*, error::*, normalize::*, *};
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn test_explain_collection() {
+        type Test = Refinement<alloc::vec::Vec<i8>, All<Not<signed::LessThan<0>>>>;
+        let explanation = Test::why_invalid(&vec![1, -2]).unwrap();
+        assert_eq!(explanation.kind, ErrorKind::All);
+        assert_eq!(
+            explanation.to_string(),
+            "\
+failed: every element must satisfy the predicate
+  passed: [0] negation
+    failed: must be less than 0 (measured 1)
+  failed: [1] negation
+    passed: must be less than 0 (measured -2)"
+        );
+        assert!(Test::why_invalid(&vec![]).is_none());
+    }
+
+    #[test]
+    fn test_explain_normalized() {
+        predicate_alias!(Short, Normalize<Trim, unsigned::LessThan<4>>, "must be short");
+        type Test = Refinement<alloc::string::String, Short>;
+        assert!(Test::why_invalid(&"  abc  ".to_string()).is_none());
+        let explanation = Test::why_invalid(&" abcd ".to_string()).unwrap();
+        assert_eq!(explanation.kind, ErrorKind::LessThan(Bound::Unsigned(4)));
+        assert_eq!(explanation.measured, Some(Bound::Unsigned(4)));
+        assert_eq!(
+            explanation.to_string(),
+            "failed: must be short (measured 4)"
+        );
+
+        let explanation = <Short as Predicate<&str>>::explain(&" ab");
+        assert!(!explanation.satisfied);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_assert_refines() {
+        type Test = Refinement<u8, And<unsigned::GreaterThan<5>, unsigned::LessThan<10>>>;
+        assert_refines!(7, Test);
+        assert_not_refines!(10u8, Test, "{} is too large", 10);
+
+        let panic = std::panic::catch_unwind(|| assert_refines!(12, Test)).unwrap_err();
+        assert_eq!(
+            panic.downcast_ref::<alloc::string::String>().unwrap(),
+            "\
+assertion `12` refines `Test` failed
+  value: 12
+failed: conjunction
+  passed: must be greater than 5 (measured 12)
+  failed: must be less than 10 (measured 12)"
+        );
+
+        let panic =
+            std::panic::catch_unwind(|| assert_not_refines!(7, Test, "too small")).unwrap_err();
+        assert!(panic
+            .downcast_ref::<alloc::string::String>()
+            .unwrap()
+            .starts_with("assertion `7` does not refine `Test` failed: too small\n  value: 7\n"));
+    }
+}
@@ -0,0 +1,240 @@
+//! [actix-web](https://docs.rs/actix-web) extractors for refined payloads.
+//!
+//! [RefinedJson], [RefinedQuery] and [RefinedPath] extract a `T` from the body, query string and
+//! route parameters of a request respectively, failing with a [Rejection] that reports every
+//! violated refinement. The response for a rejection can be customized by registering a
+//! [RefinedConfig] as app data.
+//!
+//! # Example
+//!
+//! ```
+//! use actix_web::{error, web, App, HttpResponse};
+//! use refined::{
+//!     prelude::*,
+//!     boundable::unsigned::ClosedInterval,
+//!     extract::actix::{RefinedConfig, RefinedJson},
+//! };
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Order {
+//!     quantity: Refinement<u8, ClosedInterval<1, 10>>,
+//! }
+//!
+//! async fn order(RefinedJson(order): RefinedJson<Order>) -> String {
+//!     format!("ordered {}", *order.quantity)
+//! }
+//!
+//! let app = App::new()
+//!     .app_data(RefinedConfig::default().error_handler(|rejection, _req| {
+//!         error::InternalError::from_response(rejection, HttpResponse::BadRequest().finish())
+//!             .into()
+//!     }))
+//!     .route("/orders", web::post().to(order));
+//! ```
+use std::{boxed::Box, future::Future, pin::Pin, string::ToString, sync::Arc};
+
+use ::actix_web::{
+    dev::Payload,
+    http::{header, StatusCode},
+    web::Bytes,
+    Error, FromRequest, HttpMessage, HttpRequest, HttpResponse, ResponseError,
+};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use super::ExtractError;
+
+/// The rejection of the refined extractors.
+#[derive(Debug, Error)]
+pub enum Rejection {
+    /// The request body did not have a JSON `Content-Type`.
+    #[error("expected request with `Content-Type: application/json`")]
+    MissingJsonContentType,
+    /// The request data could not be deserialized or refined.
+    #[error(transparent)]
+    Extract(#[from] ExtractError),
+}
+
+impl ResponseError for Rejection {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Rejection::MissingJsonContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Rejection::Extract(ExtractError::Deserialize(_)) => StatusCode::BAD_REQUEST,
+            Rejection::Extract(ExtractError::Refinement(_)) => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        match self {
+            Rejection::Extract(err @ ExtractError::Refinement(_)) => response
+                .insert_header((header::CONTENT_TYPE, "application/json"))
+                .body(err.body().unwrap_or_default()),
+            _ => response.body(self.to_string()),
+        }
+    }
+}
+
+type ErrorHandler = dyn Fn(Rejection, &HttpRequest) -> Error + Send + Sync;
+
+/// Configuration of the refined extractors, registered as app data.
+///
+/// By default, a [Rejection] is converted into an [Error] using its [ResponseError] implementation.
+#[derive(Clone, Default)]
+pub struct RefinedConfig {
+    error_handler: Option<Arc<ErrorHandler>>,
+}
+
+impl RefinedConfig {
+    /// Sets a custom mapping from rejections to errors, e.g. to change the shape of the response.
+    pub fn error_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rejection, &HttpRequest) -> Error + Send + Sync + 'static,
+    {
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
+
+    fn reject(req: &HttpRequest, rejection: Rejection) -> Error {
+        match req
+            .app_data::<Self>()
+            .and_then(|config| config.error_handler.as_ref())
+        {
+            Some(handler) => handler(rejection, req),
+            None => rejection.into(),
+        }
+    }
+}
+
+/// Whether the request declares a JSON body, e.g. `application/json` or `application/problem+json`.
+fn is_json(req: &HttpRequest) -> bool {
+    let Ok(Some(mime)) = req.mime_type() else {
+        return false;
+    };
+    mime.type_() == "application"
+        && (mime.subtype() == "json" || mime.suffix().is_some_and(|suffix| suffix == "json"))
+}
+
+/// Extracts a `T` from a JSON request body.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefinedJson<T>(pub T);
+
+impl<T: DeserializeOwned + 'static> FromRequest for RefinedJson<T> {
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        let bytes = Bytes::from_request(&req, payload);
+        Box::pin(async move {
+            if !is_json(&req) {
+                return Err(RefinedConfig::reject(
+                    &req,
+                    Rejection::MissingJsonContentType,
+                ));
+            }
+            let bytes = bytes.await?;
+            super::json(&bytes)
+                .map(Self)
+                .map_err(|err| RefinedConfig::reject(&req, err.into()))
+        })
+    }
+}
+
+/// Extracts a `T` from the query string of a request.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefinedQuery<T>(pub T);
+
+impl<T: DeserializeOwned> FromRequest for RefinedQuery<T> {
+    type Error = Error;
+    type Future = std::future::Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        std::future::ready(
+            super::form(req.query_string())
+                .map(Self)
+                .map_err(|err| RefinedConfig::reject(req, err.into())),
+        )
+    }
+}
+
+/// Extracts a `T` from the route parameters of a request.
+///
+/// Parameters are deserialized by name, so `T` must be a struct (or map) rather than a tuple or a
+/// single value.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefinedPath<T>(pub T);
+
+impl<T: DeserializeOwned> FromRequest for RefinedPath<T> {
+    type Error = Error;
+    type Future = std::future::Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        std::future::ready(
+            super::params(req.match_info().iter())
+                .map(Self)
+                .map_err(|err| RefinedConfig::reject(req, err.into())),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::actix_web::test::TestRequest;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, Refinement};
+
+    #[derive(Debug, Deserialize)]
+    struct Params {
+        size: Refinement<u8, LessThan<100>>,
+    }
+
+    #[tokio::test]
+    async fn test_json() {
+        let (req, mut payload) = TestRequest::post()
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .set_payload(r#"{"size": 5}"#)
+            .to_http_parts();
+        let RefinedJson(params) = RefinedJson::<Params>::from_request(&req, &mut payload)
+            .await
+            .unwrap();
+        assert_eq!(*params.size, 5);
+
+        let (req, mut payload) = TestRequest::post()
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .set_payload(r#"{"size": 150}"#)
+            .to_http_parts();
+        let err = RefinedJson::<Params>::from_request(&req, &mut payload)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_handler() {
+        let req = TestRequest::get()
+            .uri("/?size=150")
+            .app_data(RefinedConfig::default().error_handler(|rejection, _| {
+                ::actix_web::error::InternalError::new(rejection, StatusCode::IM_A_TEAPOT).into()
+            }))
+            .to_http_request();
+        let err = RefinedQuery::<Params>::extract(&req).await.unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::IM_A_TEAPOT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_path() {
+        let req = TestRequest::get().param("size", "5").to_http_request();
+        let RefinedPath(params) = RefinedPath::<Params>::extract(&req).await.unwrap();
+        assert_eq!(*params.size, 5);
+    }
+}
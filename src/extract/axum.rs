@@ -0,0 +1,198 @@
+//! [axum](https://docs.rs/axum) extractors for refined payloads.
+//!
+//! [RefinedJson], [RefinedQuery] and [RefinedPath] extract a `T` from the body, query string and
+//! route parameters of a request respectively, rejecting the request with a [Rejection] that
+//! reports every violated refinement.
+//!
+//! # Example
+//!
+//! ```
+//! use axum::{routing::post, Router};
+//! use refined::{prelude::*, boundable::unsigned::ClosedInterval, extract::axum::RefinedJson};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Order {
+//!     quantity: Refinement<u8, ClosedInterval<1, 10>>,
+//! }
+//!
+//! async fn order(RefinedJson(order): RefinedJson<Order>) -> String {
+//!     format!("ordered {}", *order.quantity)
+//! }
+//!
+//! let app: Router = Router::new().route("/orders", post(order));
+//! ```
+use std::string::ToString;
+
+use ::axum::{
+    body::{Body, Bytes},
+    extract::{
+        rejection::{BytesRejection, RawPathParamsRejection},
+        FromRequest, FromRequestParts, RawPathParams, Request,
+    },
+    http::{header, request::Parts, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use super::ExtractError;
+
+/// The rejection of the refined extractors.
+#[derive(Debug, Error)]
+pub enum Rejection {
+    /// The request body did not have a JSON `Content-Type`.
+    #[error("expected request with `Content-Type: application/json`")]
+    MissingJsonContentType,
+    /// The request body could not be read.
+    #[error(transparent)]
+    Body(#[from] BytesRejection),
+    /// The route parameters could not be read.
+    #[error(transparent)]
+    Path(#[from] RawPathParamsRejection),
+    /// The request data could not be deserialized or refined.
+    #[error(transparent)]
+    Extract(#[from] ExtractError),
+}
+
+impl IntoResponse for Rejection {
+    fn into_response(self) -> Response {
+        match self {
+            Rejection::MissingJsonContentType => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, self.to_string()).into_response()
+            }
+            Rejection::Body(rejection) => rejection.into_response(),
+            Rejection::Path(rejection) => rejection.into_response(),
+            Rejection::Extract(err) => match err.body() {
+                Some(body) => (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    [(header::CONTENT_TYPE, "application/json")],
+                    Body::from(body),
+                )
+                    .into_response(),
+                None => (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+            },
+        }
+    }
+}
+
+/// Whether the request declares a JSON body, e.g. `application/json` or `application/problem+json`.
+fn is_json(headers: &HeaderMap) -> bool {
+    let Some(content_type) = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+    essence.eq_ignore_ascii_case("application/json")
+        || (essence.starts_with("application/") && essence.ends_with("+json"))
+}
+
+/// Extracts a `T` from a JSON request body.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefinedJson<T>(pub T);
+
+impl<S: Send + Sync, T: DeserializeOwned> FromRequest<S> for RefinedJson<T> {
+    type Rejection = Rejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !is_json(req.headers()) {
+            return Err(Rejection::MissingJsonContentType);
+        }
+        let bytes = Bytes::from_request(req, state).await?;
+        Ok(Self(super::json(&bytes)?))
+    }
+}
+
+/// Extracts a `T` from the query string of a request.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefinedQuery<T>(pub T);
+
+impl<S: Send + Sync, T: DeserializeOwned> FromRequestParts<S> for RefinedQuery<T> {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(super::form(parts.uri.query().unwrap_or_default())?))
+    }
+}
+
+/// Extracts a `T` from the route parameters of a request.
+///
+/// Parameters are deserialized by name, so `T` must be a struct (or map) rather than a tuple or a
+/// single value.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefinedPath<T>(pub T);
+
+impl<S: Send + Sync, T: DeserializeOwned> FromRequestParts<S> for RefinedPath<T> {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let params = RawPathParams::from_request_parts(parts, state).await?;
+        Ok(Self(super::params(&params)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::axum::http::Method;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, Refinement};
+
+    #[derive(Debug, Deserialize)]
+    struct Params {
+        size: Refinement<u8, LessThan<100>>,
+    }
+
+    fn request(uri: &str, content_type: &str, body: &'static str) -> Request {
+        Request::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_json() {
+        let req = request("/", "application/json", r#"{"size": 5}"#);
+        let RefinedJson(params) = RefinedJson::<Params>::from_request(req, &()).await.unwrap();
+        assert_eq!(*params.size, 5);
+
+        let req = request("/", "application/json", r#"{"size": 150}"#);
+        let rejection = RefinedJson::<Params>::from_request(req, &())
+            .await
+            .unwrap_err();
+        let response = rejection.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let req = request("/", "text/plain", r#"{"size": 5}"#);
+        let rejection = RefinedJson::<Params>::from_request(req, &())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            rejection.into_response().status(),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let (mut parts, _) = request("/?size=5", "", "").into_parts();
+        let RefinedQuery(params) = RefinedQuery::<Params>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+        assert_eq!(*params.size, 5);
+
+        let (mut parts, _) = request("/?size=150", "", "").into_parts();
+        let rejection = RefinedQuery::<Params>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            rejection,
+            Rejection::Extract(ExtractError::Refinement(_))
+        ));
+    }
+}
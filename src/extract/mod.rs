@@ -0,0 +1,146 @@
+//! Web framework extractors for refined payloads.
+//!
+//! The extractors in this module deserialize request data while [collecting](crate::de) every
+//! violated refinement, so that a single response can report all of the invalid fields of a
+//! request at once. Refinement failures are reported with status `422 Unprocessable Entity` and a
+//! JSON body listing each [RefinementError](crate::RefinementError):
+//!
+//! ```json
+//! {
+//!   "errors": [
+//!     {
+//!       "code": "less_than",
+//!       "message": "must be less than 100",
+//!       "params": { "bound": 100 },
+//!       "name": null,
+//!       "value": null,
+//!       "path": ["size"],
+//!       "causes": []
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Requests that can't be deserialized at all are rejected with `400 Bad Request`.
+use std::string::{String, ToString};
+
+use serde::{de::DeserializeOwned, Serialize};
+use thiserror::Error;
+
+use crate::{de, error::RefinementErrors};
+
+#[doc(cfg(feature = "actix"))]
+#[cfg(feature = "actix")]
+pub mod actix;
+#[doc(cfg(feature = "axum"))]
+#[cfg(feature = "axum")]
+pub mod axum;
+
+/// The reason that request data could not be extracted, independent of the web framework.
+#[derive(Debug, Error)]
+pub enum ExtractError {
+    /// The request data could not be deserialized, regardless of refinement.
+    #[error("failed to deserialize request: {0}")]
+    Deserialize(String),
+    /// The request data was deserialized, but at least one refinement was violated.
+    #[error("{0}")]
+    Refinement(RefinementErrors),
+}
+
+impl ExtractError {
+    /// The JSON body reporting the refinement errors, if any.
+    pub fn body(&self) -> Option<std::vec::Vec<u8>> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            errors: &'a RefinementErrors,
+        }
+
+        match self {
+            ExtractError::Refinement(errors) => serde_json::to_vec(&Body { errors }).ok(),
+            ExtractError::Deserialize(_) => None,
+        }
+    }
+}
+
+impl<E: ToString> From<de::Error<E>> for ExtractError {
+    fn from(err: de::Error<E>) -> Self {
+        match err {
+            de::Error::Deserialize(err) => ExtractError::Deserialize(err.to_string()),
+            de::Error::Refinement(errors) => ExtractError::Refinement(errors),
+        }
+    }
+}
+
+/// Deserializes a JSON document, collecting every violated refinement.
+pub(crate) fn json<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ExtractError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let value = de::from_deserializer(&mut deserializer)?;
+    deserializer
+        .end()
+        .map_err(|err| ExtractError::Deserialize(err.to_string()))?;
+    Ok(value)
+}
+
+/// Deserializes a URL-encoded query string or form, collecting every violated refinement.
+pub(crate) fn form<T: DeserializeOwned>(input: &str) -> Result<T, ExtractError> {
+    let deserializer =
+        serde_urlencoded::Deserializer::new(form_urlencoded::parse(input.as_bytes()));
+    Ok(de::from_strings(deserializer)?)
+}
+
+/// Deserializes named parameters, such as those of a route, collecting every violated refinement.
+pub(crate) fn params<'a, T: DeserializeOwned>(
+    params: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Result<T, ExtractError> {
+    let params: std::vec::Vec<_> = params.into_iter().collect();
+    let encoded = serde_urlencoded::to_string(params)
+        .map_err(|err| ExtractError::Deserialize(err.to_string()))?;
+    form(&encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, Refinement};
+
+    #[derive(Debug, Deserialize)]
+    struct Params {
+        size: Refinement<u8, LessThan<100>>,
+        count: Refinement<u8, LessThan<10>>,
+    }
+
+    #[test]
+    fn test_json() {
+        let params: Params = json(br#"{"size": 5, "count": 1}"#).unwrap();
+        assert_eq!(*params.size, 5);
+
+        let err = json::<Params>(br#"{"size": 150, "count": 10}"#).unwrap_err();
+        let ExtractError::Refinement(errors) = &err else {
+            panic!("expected refinement errors");
+        };
+        assert_eq!(errors.len(), 2);
+        let body: serde_json::Value = serde_json::from_slice(&err.body().unwrap()).unwrap();
+        assert_eq!(body["errors"][0]["path"], serde_json::json!(["size"]));
+        assert_eq!(body["errors"][1]["path"], serde_json::json!(["count"]));
+
+        assert!(matches!(
+            json::<Params>(br#"{"size": 5}"#),
+            Err(ExtractError::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn test_form() {
+        let params: Params = form("size=5&count=1").unwrap();
+        assert_eq!(*params.count, 1);
+        let Err(ExtractError::Refinement(errors)) = form::<Params>("size=150&count=20") else {
+            panic!("expected refinement errors");
+        };
+        assert_eq!(errors.len(), 2);
+
+        let params: Params = super::params([("size", "50"), ("count", "5")]).unwrap();
+        assert_eq!(*params.size, 50);
+    }
+}
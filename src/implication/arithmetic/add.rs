@@ -1,9 +1,7 @@
-use core::{marker::PhantomData, ops::Add};
+use core::ops::Add;
 
 use crate::{boundable::*, Predicate, Refinement};
 
-use super::*;
-
 impl<
         const A: usize,
         Type: unsigned::UnsignedBoundable + Add<Output = Type>,
@@ -15,7 +13,7 @@ where
     type Output = Refinement<Type, unsigned::LessThan<{ A + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -30,7 +28,7 @@ where
     type Output = Refinement<Type, unsigned::LessThanEqual<{ A + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -45,7 +43,7 @@ where
     type Output = Refinement<Type, unsigned::GreaterThan<{ A + B::UMIN }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -60,7 +58,7 @@ where
     type Output = Refinement<Type, unsigned::GreaterThanEqual<{ A + B::UMIN }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -76,7 +74,7 @@ where
     type Output = Refinement<Type, unsigned::OpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -92,7 +90,7 @@ where
     type Output = Refinement<Type, unsigned::ClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -109,7 +107,7 @@ where
         Refinement<Type, unsigned::OpenClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -126,7 +124,7 @@ where
         Refinement<Type, unsigned::ClosedOpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -251,7 +249,7 @@ where
     type Output = Refinement<Type, signed::LessThan<{ A + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -266,7 +264,7 @@ where
     type Output = Refinement<Type, signed::LessThanEqual<{ A + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -281,7 +279,7 @@ where
     type Output = Refinement<Type, signed::GreaterThan<{ A + B::UMIN }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -296,7 +294,7 @@ where
     type Output = Refinement<Type, signed::GreaterThanEqual<{ A + B::UMIN }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -312,7 +310,7 @@ where
     type Output = Refinement<Type, signed::OpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -328,7 +326,7 @@ where
     type Output = Refinement<Type, signed::ClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -345,7 +343,7 @@ where
         Refinement<Type, signed::OpenClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -362,7 +360,7 @@ where
         Refinement<Type, signed::ClosedOpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        Refinement::proven(self.0 + rhs.0)
     }
 }
 
@@ -0,0 +1,79 @@
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Div, DivAssign, Mul, MulAssign, Rem,
+    RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+use core::marker::PhantomData;
+
+use crate::{Predicate, Refinement};
+
+macro_rules! assign {
+    ($($assign:ident, $assign_method:ident => $op:ident, $method:ident);*) => {
+        $(
+            /// Compound assignment is implemented wherever the corresponding operator produces a
+            /// refinement by the same predicate, so that the result is known to satisfy it. Other
+            /// updates can be checked at runtime with [Refinement::try_update].
+            impl<T: Copy, P: Predicate<T>, Rhs> $assign<Rhs> for Refinement<T, P>
+            where
+                Self: $op<Rhs, Output = Self>,
+            {
+                fn $assign_method(&mut self, rhs: Rhs) {
+                    *self = $op::$method(Refinement(self.0, PhantomData), rhs);
+                }
+            }
+        )*
+    };
+}
+
+assign!(
+    AddAssign, add_assign => Add, add;
+    SubAssign, sub_assign => Sub, sub;
+    MulAssign, mul_assign => Mul, mul;
+    DivAssign, div_assign => Div, div;
+    RemAssign, rem_assign => Rem, rem;
+    BitAndAssign, bitand_assign => BitAnd, bitand;
+    BitOrAssign, bitor_assign => BitOr, bitor;
+    ShlAssign, shl_assign => Shl, shl;
+    ShrAssign, shr_assign => Shr, shr
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned, implication::UnsignedConst, prelude::*};
+
+    #[test]
+    fn test_add_assign() {
+        let mut total = Refinement::<u32, unsigned::GreaterThanEqual<10>>::refine(10).unwrap();
+        for delta in [3, 5, 8] {
+            total += Refinement::<u32, unsigned::GreaterThanEqual<0>>::refine(delta).unwrap();
+        }
+        assert_eq!(*total, 26);
+        total += UnsignedConst::<0>;
+        assert_eq!(*total, 26);
+    }
+
+    #[test]
+    fn test_div_assign() {
+        let mut value = Refinement::<u8, unsigned::LessThan<100>>::refine(99).unwrap();
+        value /= Refinement::<u8, unsigned::ClosedInterval<1, 10>>::refine(3).unwrap();
+        assert_eq!(*value, 33);
+    }
+
+    #[test]
+    fn test_rem_assign() {
+        let mut value = Refinement::<u16, unsigned::LessThan<60>>::refine(59).unwrap();
+        value %= Refinement::<u16, unsigned::ClosedInterval<1, 60>>::refine(7).unwrap();
+        assert_eq!(*value, 3);
+    }
+
+    #[test]
+    fn test_bit_assign() {
+        let mut flags = Refinement::<u8, unsigned::LessThanEqual<15>>::refine(0b1011).unwrap();
+        flags &= Refinement::<u8, unsigned::LessThanEqual<15>>::refine(0b0110).unwrap();
+        assert_eq!(*flags, 0b0010);
+        flags |= Refinement::<u8, unsigned::LessThanEqual<8>>::refine(0b1000).unwrap();
+        assert_eq!(*flags, 0b1010);
+        flags >>= Refinement::<u8, unsigned::GreaterThanEqual<0>>::refine(1).unwrap();
+        assert_eq!(*flags, 0b0101);
+    }
+}
@@ -0,0 +1,139 @@
+use core::ops::{BitAnd, BitOr, Shl, Shr};
+
+use crate::{boundable::*, Predicate, Refinement};
+
+use super::*;
+
+/// Masking never sets a bit that is unset in the mask, so the result is at most the greatest value
+/// of the mask, whatever the other operand.
+impl<
+        Type: unsigned::UnsignedBoundable + BitAnd<Output = Type>,
+        A: Predicate<Type>,
+        B: UnsignedMax<Type> + Predicate<Type>,
+    > BitAnd<Refinement<Type, B>> for Refinement<Type, A>
+where
+    Refinement<Type, unsigned::LessThanEqual<{ B::UMAX }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ B::UMAX }>>;
+
+    fn bitand(self, rhs: Refinement<Type, B>) -> Self::Output {
+        Refinement::proven(self.0 & rhs.0)
+    }
+}
+
+impl<
+        Type: unsigned::UnsignedBoundable + BitOr<Output = Type>,
+        A: UnsignedMax<Type> + Predicate<Type>,
+        B: UnsignedMax<Type> + Predicate<Type>,
+    > BitOr<Refinement<Type, B>> for Refinement<Type, A>
+where
+    Refinement<Type, unsigned::LessThanEqual<{ max_bit_or(A::UMAX, B::UMAX) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ max_bit_or(A::UMAX, B::UMAX) }>>;
+
+    fn bitor(self, rhs: Refinement<Type, B>) -> Self::Output {
+        Refinement::proven(self.0 | rhs.0)
+    }
+}
+
+/// Shifts are implemented for the primitive unsigned types, for which shifting by at least the
+/// number of bits in the type shifts out every bit rather than overflowing.
+macro_rules! shift {
+    ($($ty:ty),*) => {
+        $(
+            impl<A: UnsignedMax<$ty> + Predicate<$ty>, B: UnsignedMax<$ty> + Predicate<$ty>>
+                Shl<Refinement<$ty, B>> for Refinement<$ty, A>
+            where
+                Refinement<$ty, unsigned::LessThanEqual<{ max_shl(A::UMAX, B::UMAX) }>>: Sized,
+            {
+                type Output =
+                    Refinement<$ty, unsigned::LessThanEqual<{ max_shl(A::UMAX, B::UMAX) }>>;
+
+                fn shl(self, rhs: Refinement<$ty, B>) -> Self::Output {
+                    let shifted = u32::try_from(rhs.0)
+                        .ok()
+                        .and_then(|shift| self.0.checked_shl(shift))
+                        .unwrap_or(0);
+                    Refinement::proven(shifted)
+                }
+            }
+
+            impl<A: UnsignedMax<$ty> + Predicate<$ty>, B: UnsignedMin<$ty> + Predicate<$ty>>
+                Shr<Refinement<$ty, B>> for Refinement<$ty, A>
+            where
+                Refinement<$ty, unsigned::LessThanEqual<{ max_shr(A::UMAX, B::UMIN) }>>: Sized,
+            {
+                type Output =
+                    Refinement<$ty, unsigned::LessThanEqual<{ max_shr(A::UMAX, B::UMIN) }>>;
+
+                fn shr(self, rhs: Refinement<$ty, B>) -> Self::Output {
+                    let shifted = u32::try_from(rhs.0)
+                        .ok()
+                        .and_then(|shift| self.0.checked_shr(shift))
+                        .unwrap_or(0);
+                    Refinement::proven(shifted)
+                }
+            }
+        )*
+    };
+}
+
+shift!(u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod unsigned_tests {
+    use super::*;
+    use crate::{boolean::True, prelude::*};
+
+    #[test]
+    fn test_bitand_mask() {
+        let a = Refinement::<u8, True>::refine(0b1011_0110).unwrap();
+        let mask = Refinement::<u8, unsigned::Equals<0b1111>>::refine(0b1111).unwrap();
+        let c: Refinement<u8, unsigned::LessThanEqual<0b1111>> = a & mask;
+        assert_eq!(*c, 0b0110);
+    }
+
+    #[test]
+    fn test_bitand_lt() {
+        let a = Refinement::<u16, unsigned::GreaterThan<1000>>::refine(1023).unwrap();
+        let b = Refinement::<u16, unsigned::LessThan<64>>::refine(40).unwrap();
+        let c: Refinement<u16, unsigned::LessThanEqual<63>> = a & b;
+        assert_eq!(*c, 40);
+    }
+
+    #[test]
+    fn test_bitor() {
+        let a = Refinement::<u8, unsigned::LessThan<5>>::refine(4).unwrap();
+        let b = Refinement::<u8, unsigned::ClosedInterval<8, 9>>::refine(9).unwrap();
+        let c: Refinement<u8, unsigned::LessThanEqual<15>> = a | b;
+        assert_eq!(*c, 13);
+    }
+
+    #[test]
+    fn test_shl() {
+        let a = Refinement::<u8, unsigned::LessThanEqual<3>>::refine(3).unwrap();
+        let b = Refinement::<u8, unsigned::LessThan<4>>::refine(3).unwrap();
+        let c: Refinement<u8, unsigned::LessThanEqual<24>> = a << b;
+        assert_eq!(*c, 24);
+    }
+
+    #[test]
+    fn test_shl_out_of_range() {
+        let a = Refinement::<u8, unsigned::LessThan<200>>::refine(199).unwrap();
+        let b = Refinement::<u8, unsigned::LessThanEqual<100>>::refine(100).unwrap();
+        let c: Refinement<u8, unsigned::LessThanEqual<{ usize::MAX }>> = a << b;
+        assert_eq!(*c, 0);
+    }
+
+    #[test]
+    fn test_shr() {
+        let a = Refinement::<u32, unsigned::LessThan<1000>>::refine(999).unwrap();
+        let b = Refinement::<u32, unsigned::ClosedInterval<2, 4>>::refine(2).unwrap();
+        let c: Refinement<u32, unsigned::LessThanEqual<249>> = a >> b;
+        assert_eq!(*c, 249);
+
+        let b = Refinement::<u32, unsigned::GreaterThan<40>>::refine(41).unwrap();
+        let c: Refinement<u32, unsigned::LessThanEqual<0>> = a >> b;
+        assert_eq!(*c, 0);
+    }
+}
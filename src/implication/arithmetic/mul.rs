@@ -1,4 +1,4 @@
-use core::{marker::PhantomData, ops::Mul};
+use core::ops::Mul;
 
 use crate::{boundable::*, Predicate, Refinement};
 
@@ -15,7 +15,7 @@ where
     type Output = Refinement<Type, unsigned::LessThan<{ (A - 1) * B::UMAX + 1 }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -30,7 +30,7 @@ where
     type Output = Refinement<Type, unsigned::LessThanEqual<{ A * B::UMAX }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -45,7 +45,7 @@ where
     type Output = Refinement<Type, unsigned::GreaterThan<{ (A + 1) * B::UMIN - 1 }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -60,7 +60,7 @@ where
     type Output = Refinement<Type, unsigned::GreaterThanEqual<{ A * B::UMIN }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -82,7 +82,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -98,7 +98,7 @@ where
     type Output = Refinement<Type, unsigned::ClosedInterval<{ MIN * B::UMIN }, { MAX * B::UMAX }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -118,7 +118,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -138,7 +138,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -260,7 +260,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -288,7 +288,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -316,7 +316,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
@@ -344,7 +344,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        Refinement::proven(self.0 * rhs.0)
     }
 }
 
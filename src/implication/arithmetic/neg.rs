@@ -0,0 +1,110 @@
+use core::ops::Neg;
+
+use crate::{
+    boundable::*,
+    implication::{Assert, IsTrue},
+    Refinement,
+};
+
+impl<const MIN: isize, const MAX: isize, Type: signed::SignedBoundableMin + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::OpenInterval<MIN, MAX>>
+where
+    Assert<{ MIN >= Type::TYPE_MIN }>: IsTrue,
+    Refinement<Type, signed::OpenInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::OpenInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        Refinement::proven(-self.0)
+    }
+}
+
+impl<const MIN: isize, const MAX: isize, Type: signed::SignedBoundableMin + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
+where
+    Assert<{ MIN > Type::TYPE_MIN }>: IsTrue,
+    Refinement<Type, signed::ClosedInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::ClosedInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        Refinement::proven(-self.0)
+    }
+}
+
+impl<const MIN: isize, const MAX: isize, Type: signed::SignedBoundableMin + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
+where
+    Assert<{ MIN >= Type::TYPE_MIN }>: IsTrue,
+    Refinement<Type, signed::ClosedOpenInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::ClosedOpenInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        Refinement::proven(-self.0)
+    }
+}
+
+impl<const MIN: isize, const MAX: isize, Type: signed::SignedBoundableMin + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
+where
+    Assert<{ MIN > Type::TYPE_MIN }>: IsTrue,
+    Refinement<Type, signed::OpenClosedInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::OpenClosedInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        Refinement::proven(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod signed_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_open_interval_neg() {
+        let a = Refinement::<i8, signed::OpenInterval<-30, -15>>::refine(-29).unwrap();
+        let b: Refinement<i8, signed::OpenInterval<15, 30>> = -a;
+        assert_eq!(*b, 29);
+    }
+
+    #[test]
+    fn test_type_min_neg() {
+        let a = Refinement::<i8, signed::ClosedInterval<-127, 0>>::refine(-127).unwrap();
+        let b: Refinement<i8, signed::ClosedInterval<0, 127>> = -a;
+        assert_eq!(*b, 127);
+        let a = Refinement::<i8, signed::OpenInterval<-128, 0>>::refine(-127).unwrap();
+        let b: Refinement<i8, signed::OpenInterval<0, 128>> = -a;
+        assert_eq!(*b, 127);
+    }
+
+    #[test]
+    fn test_closed_interval_neg() {
+        let a = Refinement::<i16, signed::ClosedInterval<-5, 100>>::refine(100).unwrap();
+        let b: Refinement<i16, signed::ClosedInterval<-100, 5>> = -a;
+        assert_eq!(*b, -100);
+    }
+
+    #[test]
+    fn test_open_closed_interval_neg() {
+        let a = Refinement::<i32, signed::OpenClosedInterval<-11, 20>>::refine(-10).unwrap();
+        let b: Refinement<i32, signed::ClosedOpenInterval<-20, 11>> = -a;
+        assert_eq!(*b, 10);
+    }
+
+    #[test]
+    fn test_closed_open_interval_neg() {
+        let a = Refinement::<i8, signed::ClosedOpenInterval<-50, 100>>::refine(-50).unwrap();
+        let b: Refinement<i8, signed::OpenClosedInterval<-100, 50>> = -a;
+        assert_eq!(*b, 50);
+    }
+
+    #[test]
+    fn test_neg_neg() {
+        let a = Refinement::<i8, signed::ClosedOpenInterval<-3, 7>>::refine(6).unwrap();
+        let b: Refinement<i8, signed::ClosedOpenInterval<-3, 7>> = -(-a);
+        assert_eq!(*b, 6);
+    }
+}
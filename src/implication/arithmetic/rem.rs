@@ -0,0 +1,58 @@
+use core::ops::Rem;
+
+use crate::{boundable::*, Predicate, Refinement};
+
+/// The remainder of a division is always less than the divisor, so it is less than the greatest
+/// value that the divisor can take, whatever the dividend.
+impl<
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        A: Predicate<Type>,
+        B: UnsignedMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, A>
+where
+    Refinement<Type, unsigned::LessThan<{ B::UMAX }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThan<{ B::UMAX }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        Refinement::proven(self.0 % rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod unsigned_tests {
+    use super::*;
+    use crate::{boolean::True, prelude::*};
+
+    #[test]
+    fn test_lt_rem_lt() {
+        let a = Refinement::<u8, unsigned::LessThan<100>>::refine(47).unwrap();
+        let b = Refinement::<u8, unsigned::LessThan<11>>::refine(10).unwrap();
+        let c: Refinement<u8, unsigned::LessThan<10>> = a % b;
+        assert_eq!(*c, 7);
+    }
+
+    #[test]
+    fn test_unbounded_rem_closed_interval() {
+        let a = Refinement::<u32, True>::refine(1_000_003).unwrap();
+        let b = Refinement::<u32, unsigned::ClosedInterval<1, 60>>::refine(60).unwrap();
+        let c: Refinement<u32, unsigned::LessThan<60>> = a % b;
+        assert_eq!(*c, 43);
+    }
+
+    #[test]
+    fn test_gt_rem_equals() {
+        let a = Refinement::<u16, unsigned::GreaterThan<1000>>::refine(1234).unwrap();
+        let b = Refinement::<u16, unsigned::Equals<24>>::refine(24).unwrap();
+        let c: Refinement<u16, unsigned::LessThan<24>> = a % b;
+        assert_eq!(*c, 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rem_zero() {
+        let a = Refinement::<u8, unsigned::LessThan<10>>::refine(5).unwrap();
+        let b = Refinement::<u8, unsigned::LessThanEqual<5>>::refine(0).unwrap();
+        let _ = a % b;
+    }
+}
@@ -0,0 +1,146 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    boundable::*,
+    implication::{Assert, IsTrue},
+    Predicate, Refinement,
+};
+
+/// An unsigned constant that refinements can be operated on with, shifting or scaling their bounds
+/// as a refinement by [Equals](unsigned::Equals) would.
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use refined::{prelude::*, boundable::unsigned::LessThan, implication::UnsignedConst};
+///
+/// let value = Refinement::<u8, LessThan<100>>::refine(99).unwrap();
+/// let shifted: Refinement<u8, LessThan<105>> = value + UnsignedConst::<5>;
+/// assert_eq!(*shifted, 104);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnsignedConst<const K: usize>;
+
+/// A signed constant that refinements can be operated on with, shifting or scaling their bounds as
+/// a refinement by [Equals](signed::Equals) would.
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use refined::{prelude::*, boundable::signed::ClosedInterval, implication::SignedConst};
+///
+/// let value = Refinement::<i8, ClosedInterval<-5, 5>>::refine(-3).unwrap();
+/// let scaled: Refinement<i8, ClosedInterval<-15, 15>> = value * SignedConst::<3>;
+/// assert_eq!(*scaled, -9);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SignedConst<const K: isize>;
+
+macro_rules! scalar {
+    ($const:ident, $k:ty, $equals:ty, [$($ty:ty),*], $op:ident, $method:ident) => {
+        $(
+            impl<const K: $k, P: Predicate<$ty>> $op<$const<K>> for Refinement<$ty, P>
+            where
+                Assert<{ (K >= <$ty>::MIN as $k) & (K <= <$ty>::MAX as $k) }>: IsTrue,
+                Refinement<$ty, P>: $op<Refinement<$ty, $equals>>,
+            {
+                type Output = <Refinement<$ty, P> as $op<Refinement<$ty, $equals>>>::Output;
+
+                fn $method(self, _: $const<K>) -> Self::Output {
+                    self.$method(Refinement::<$ty, $equals>::proven(K as $ty))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! unsigned_scalar {
+    ($($op:ident, $method:ident);*) => {
+        $(
+            scalar!(
+                UnsignedConst,
+                usize,
+                unsigned::Equals<K>,
+                [u8, u16, u32, u64, usize],
+                $op,
+                $method
+            );
+        )*
+    };
+}
+
+macro_rules! signed_scalar {
+    ($($op:ident, $method:ident);*) => {
+        $(
+            scalar!(
+                SignedConst,
+                isize,
+                signed::Equals<K>,
+                [i8, i16, i32, i64, isize],
+                $op,
+                $method
+            );
+        )*
+    };
+}
+
+unsigned_scalar!(Add, add; Sub, sub; Mul, mul; Div, div);
+signed_scalar!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+#[cfg(test)]
+mod unsigned_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_lt_add_const() {
+        let a = Refinement::<u8, unsigned::LessThan<100>>::refine(99).unwrap();
+        let b: Refinement<u8, unsigned::LessThan<105>> = a + UnsignedConst::<5>;
+        assert_eq!(*b, 104);
+    }
+
+    #[test]
+    fn test_gte_mul_const() {
+        let a = Refinement::<u32, unsigned::GreaterThanEqual<10>>::refine(12).unwrap();
+        let b: Refinement<u32, unsigned::GreaterThanEqual<30>> = a * UnsignedConst::<3>;
+        assert_eq!(*b, 36);
+    }
+
+    #[test]
+    fn test_closed_interval_const() {
+        let a = Refinement::<u16, unsigned::ClosedInterval<10, 20>>::refine(15).unwrap();
+        let b: Refinement<u16, unsigned::ClosedInterval<5, 15>> = a - UnsignedConst::<5>;
+        assert_eq!(*b, 10);
+        let c: Refinement<u16, unsigned::ClosedInterval<2, 4>> = a / UnsignedConst::<5>;
+        assert_eq!(*c, 3);
+    }
+}
+
+#[cfg(test)]
+mod signed_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gt_add_const() {
+        let a = Refinement::<i8, signed::GreaterThan<-10>>::refine(-9).unwrap();
+        let b: Refinement<i8, signed::GreaterThan<-15>> = a + SignedConst::<-5>;
+        assert_eq!(*b, -14);
+    }
+
+    #[test]
+    fn test_closed_interval_mul_const() {
+        let a = Refinement::<i32, signed::ClosedInterval<-5, 10>>::refine(10).unwrap();
+        let b: Refinement<i32, signed::ClosedInterval<-20, 10>> = a * SignedConst::<-2>;
+        assert_eq!(*b, -20);
+    }
+
+    #[test]
+    fn test_open_interval_sub_const() {
+        let a = Refinement::<i16, signed::OpenInterval<-3, 3>>::refine(2).unwrap();
+        let b: Refinement<i16, signed::OpenInterval<-103, -97>> = a - SignedConst::<100>;
+        assert_eq!(*b, -98);
+    }
+}
@@ -1,9 +1,7 @@
-use core::{marker::PhantomData, ops::Sub};
+use core::ops::Sub;
 
 use crate::{boundable::*, Predicate, Refinement};
 
-use super::*;
-
 impl<
         const MIN: usize,
         Type: unsigned::UnsignedBoundable + Sub<Output = Type>,
@@ -15,7 +13,7 @@ where
     type Output = Refinement<Type, unsigned::GreaterThan<{ MIN - B::UMAX }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -30,7 +28,7 @@ where
     type Output = Refinement<Type, unsigned::GreaterThanEqual<{ MIN - B::UMAX }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 impl<
@@ -45,7 +43,7 @@ where
     type Output = Refinement<Type, unsigned::OpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -61,7 +59,7 @@ where
     type Output = Refinement<Type, unsigned::ClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -78,7 +76,7 @@ where
         Refinement<Type, unsigned::OpenClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -95,7 +93,7 @@ where
         Refinement<Type, unsigned::ClosedOpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -197,7 +195,7 @@ where
     type Output = Refinement<Type, signed::OpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -213,7 +211,7 @@ where
     type Output = Refinement<Type, signed::ClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -230,7 +228,7 @@ where
         Refinement<Type, signed::OpenClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
@@ -247,7 +245,7 @@ where
         Refinement<Type, signed::ClosedOpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        Refinement::proven(self.0 - rhs.0)
     }
 }
 
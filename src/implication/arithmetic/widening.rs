@@ -0,0 +1,96 @@
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+use crate::{boundable::BoundingPredicate, Predicate, Refinement};
+
+/// Implements arithmetic between refinements of numeric types of different widths by widening the
+/// narrower operand losslessly, whose predicate holds regardless of the width when it depends only
+/// on the bounding value. The result is the result of the operation in the wider type.
+macro_rules! widen {
+    ($($narrow:ty => $($wide:ty),+);* $(;)?) => {
+        $($(
+            widen!(@op $narrow, $wide, Add, add);
+            widen!(@op $narrow, $wide, Sub, sub);
+            widen!(@op $narrow, $wide, Mul, mul);
+            widen!(@op $narrow, $wide, Div, div);
+            widen!(@op $narrow, $wide, Rem, rem);
+        )+)*
+    };
+    (@op $narrow:ty, $wide:ty, $op:ident, $method:ident) => {
+        impl<A, B> $op<Refinement<$wide, B>> for Refinement<$narrow, A>
+        where
+            A: Predicate<$narrow> + Predicate<$wide> + BoundingPredicate,
+            B: Predicate<$wide>,
+            Refinement<$wide, A>: $op<Refinement<$wide, B>>,
+        {
+            type Output = <Refinement<$wide, A> as $op<Refinement<$wide, B>>>::Output;
+
+            fn $method(self, rhs: Refinement<$wide, B>) -> Self::Output {
+                Refinement::<$wide, A>(<$wide>::from(self.0), PhantomData).$method(rhs)
+            }
+        }
+
+        impl<A, B> $op<Refinement<$narrow, B>> for Refinement<$wide, A>
+        where
+            A: Predicate<$wide>,
+            B: Predicate<$narrow> + Predicate<$wide> + BoundingPredicate,
+            Refinement<$wide, A>: $op<Refinement<$wide, B>>,
+        {
+            type Output = <Refinement<$wide, A> as $op<Refinement<$wide, B>>>::Output;
+
+            fn $method(self, rhs: Refinement<$narrow, B>) -> Self::Output {
+                self.$method(Refinement::<$wide, B>(<$wide>::from(rhs.0), PhantomData))
+            }
+        }
+    };
+}
+
+widen!(
+    u8 => u16, u32, usize;
+    u16 => u32, usize;
+    i8 => i16, i32, isize;
+    i16 => i32, isize;
+);
+
+#[cfg(target_pointer_width = "64")]
+widen!(
+    u8 => u64;
+    u16 => u64;
+    u32 => u64;
+    i8 => i64;
+    i16 => i64;
+    i32 => i64;
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::*, prelude::*};
+
+    #[test]
+    fn test_unsigned_widening() {
+        let a = Refinement::<u8, unsigned::LessThan<100>>::refine(99).unwrap();
+        let b = Refinement::<u16, unsigned::LessThan<1000>>::refine(999).unwrap();
+        let c: Refinement<u16, unsigned::LessThan<1099>> = a + b;
+        assert_eq!(*c, 1098);
+        let c: Refinement<u16, unsigned::LessThan<1099>> = b + a;
+        assert_eq!(*c, 1098);
+
+        let d = Refinement::<u64, unsigned::ClosedInterval<1, 10>>::refine(4).unwrap();
+        let e: Refinement<u64, unsigned::LessThan<100>> = a / d;
+        assert_eq!(*e, 24);
+        let e: Refinement<u64, unsigned::LessThan<10>> = a % d;
+        assert_eq!(*e, 3);
+    }
+
+    #[test]
+    fn test_signed_widening() {
+        let a = Refinement::<i8, signed::ClosedInterval<-10, 10>>::refine(-10).unwrap();
+        let b = Refinement::<i32, signed::ClosedInterval<-1000, 1000>>::refine(1000).unwrap();
+        let c: Refinement<i32, signed::ClosedInterval<-10000, 10000>> = a * b;
+        assert_eq!(*c, -10000);
+        let c: Refinement<i32, signed::ClosedInterval<-1010, 1010>> = b - a;
+        assert_eq!(*c, 1010);
+    }
+}
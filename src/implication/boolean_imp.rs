@@ -4,6 +4,27 @@ use crate::boolean::*;
 
 use super::Implies;
 
+/// Proof that `And<A, B>` implies `A`.
+pub enum AndLeft {}
+
+/// Proof that `And<A, B>` implies `B`.
+pub enum AndRight {}
+
+/// Proof that `A` implies `Or<A, B>`.
+pub enum OrLeft {}
+
+/// Proof that `B` implies `Or<A, B>`.
+pub enum OrRight {}
+
+/// Proof that `Not<Not<A>>` implies `A`.
+pub enum DoubleNegation {}
+
+/// Proof that every predicate implies [True].
+pub enum Trivially {}
+
+/// Proof that [False] implies every predicate.
+pub enum Vacuously {}
+
 impl<F1, T1, F2, T2> Implies<And<T1, T2>> for And<F1, F2>
 where
     F1: Implies<T1>,
@@ -13,3 +34,88 @@ where
         And::<T1, T2>(PhantomData, PhantomData)
     }
 }
+
+impl<A: Default, B> Implies<A, AndLeft> for And<A, B> {
+    fn imply(self) -> A {
+        A::default()
+    }
+}
+
+impl<A, B: Default> Implies<B, AndRight> for And<A, B> {
+    fn imply(self) -> B {
+        B::default()
+    }
+}
+
+impl<A, B> Implies<Or<A, B>, OrLeft> for A {
+    fn imply(self) -> Or<A, B> {
+        Or::<A, B>(PhantomData, PhantomData)
+    }
+}
+
+impl<A, B> Implies<Or<A, B>, OrRight> for B {
+    fn imply(self) -> Or<A, B> {
+        Or::<A, B>(PhantomData, PhantomData)
+    }
+}
+
+impl<A: Default> Implies<A, DoubleNegation> for Not<Not<A>> {
+    fn imply(self) -> A {
+        A::default()
+    }
+}
+
+impl<P> Implies<True, Trivially> for P {
+    fn imply(self) -> True {
+        True
+    }
+}
+
+impl<P: Default> Implies<P, Vacuously> for False {
+    fn imply(self) -> P {
+        P::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::*, boundable::*, character::IsDigit, *};
+
+    #[test]
+    fn test_and_implication() {
+        let a = Refinement::<u8, unsigned::ClosedInterval<1, 10>>::refine(5).unwrap();
+        let _: Refinement<u8, unsigned::GreaterThanEqual<1>> = a.imply();
+        let _: Refinement<u8, unsigned::LessThanEqual<10>> = a.imply();
+        let _: Refinement<u8, unsigned::ClosedInterval<0, 20>> = a.imply();
+    }
+
+    #[test]
+    fn test_or_implication() {
+        let l = Refinement::<i32, signed::Negative>::refine(-5).unwrap();
+        let either: Refinement<i32, Or<signed::Negative, signed::Even>> = l.imply();
+        assert_eq!(*either, -5);
+        let either: Refinement<i32, Or<signed::Even, signed::Negative>> = l.imply();
+        assert_eq!(*either, -5);
+    }
+
+    #[test]
+    fn test_always_never_implication() {
+        let l = Refinement::<u8, unsigned::LessThan<10>>::refine(5).unwrap();
+        let a: Refinement<u8, Always> = l.imply();
+        assert_eq!(*a, 5);
+        let d = Refinement::<char, IsDigit>::refine('7').unwrap();
+        let _: Refinement<char, Always> = d.imply();
+
+        fn vacuous(n: Refinement<u8, Never>) -> Refinement<u8, unsigned::Equals<3>> {
+            n.imply()
+        }
+        assert!(Refinement::<u8, Never>::refine(3).map(vacuous).is_err());
+    }
+
+    #[test]
+    fn test_double_negation_implication() {
+        let n = Refinement::<u8, Not<Not<unsigned::LessThan<10>>>>::refine(5).unwrap();
+        let l: Refinement<u8, unsigned::LessThan<10>> = n.imply();
+        assert_eq!(*l, 5);
+    }
+}
@@ -1,10 +1,10 @@
-use crate::boundable::*;
+use crate::{boolean::Not, boundable::*};
 
 use super::{Assert, Implies, IsTrue};
 
 impl<const F: isize, const T: isize> Implies<signed::GreaterThan<T>> for signed::GreaterThan<F>
 where
-    Assert<{ F > T }>: IsTrue,
+    Assert<{ F >= T }>: IsTrue,
 {
     fn imply(self) -> signed::GreaterThan<T> {
         signed::GreaterThan::<T>
@@ -41,7 +41,7 @@ where
 
 impl<const F: isize, const T: isize> Implies<signed::LessThan<T>> for signed::LessThan<F>
 where
-    Assert<{ F < T }>: IsTrue,
+    Assert<{ F <= T }>: IsTrue,
 {
     fn imply(self) -> signed::LessThan<T> {
         signed::LessThan::<T>
@@ -112,9 +112,28 @@ where
     }
 }
 
+impl<const F: isize, const T: isize> Implies<signed::Equals<T>> for signed::Equals<F>
+where
+    Assert<{ F == T }>: IsTrue,
+{
+    fn imply(self) -> signed::Equals<T> {
+        signed::Equals::<T>
+    }
+}
+
+impl<const FDIV: isize, const FMOD: isize, const TDIV: isize, const TMOD: isize>
+    Implies<signed::Modulo<TDIV, TMOD>> for signed::Modulo<FDIV, FMOD>
+where
+    Assert<{ (FDIV % TDIV == 0) & (FMOD % TDIV == TMOD) }>: IsTrue,
+{
+    fn imply(self) -> signed::Modulo<TDIV, TMOD> {
+        signed::Modulo::<TDIV, TMOD>
+    }
+}
+
 impl<const F: usize, const T: usize> Implies<unsigned::GreaterThan<T>> for unsigned::GreaterThan<F>
 where
-    Assert<{ F > T }>: IsTrue,
+    Assert<{ F >= T }>: IsTrue,
 {
     fn imply(self) -> unsigned::GreaterThan<T> {
         unsigned::GreaterThan::<T>
@@ -153,7 +172,7 @@ where
 
 impl<const F: usize, const T: usize> Implies<unsigned::LessThan<T>> for unsigned::LessThan<F>
 where
-    Assert<{ F < T }>: IsTrue,
+    Assert<{ F <= T }>: IsTrue,
 {
     fn imply(self) -> unsigned::LessThan<T> {
         unsigned::LessThan::<T>
@@ -227,6 +246,205 @@ where
     }
 }
 
+impl<const F: usize, const T: usize> Implies<unsigned::Equals<T>> for unsigned::Equals<F>
+where
+    Assert<{ F == T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::Equals<T> {
+        unsigned::Equals::<T>
+    }
+}
+
+impl<const FDIV: usize, const FMOD: usize, const TDIV: usize, const TMOD: usize>
+    Implies<unsigned::Modulo<TDIV, TMOD>> for unsigned::Modulo<FDIV, FMOD>
+where
+    Assert<{ FDIV.is_multiple_of(TDIV) & (FMOD % TDIV == TMOD) }>: IsTrue,
+{
+    fn imply(self) -> unsigned::Modulo<TDIV, TMOD> {
+        unsigned::Modulo::<TDIV, TMOD>
+    }
+}
+
+impl<const F: isize, const T: isize> Implies<Not<signed::Equals<T>>> for Not<signed::Equals<F>>
+where
+    Assert<{ F == T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Equals<T>> {
+        Not::default()
+    }
+}
+
+impl<const MIN: isize> Implies<signed::NonZero> for signed::GreaterThan<MIN>
+where
+    Assert<{ MIN >= 0 }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const MIN: isize> Implies<signed::NonZero> for signed::GreaterThanEqual<MIN>
+where
+    Assert<{ MIN > 0 }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const MAX: isize> Implies<signed::NonZero> for signed::LessThan<MAX>
+where
+    Assert<{ MAX <= 0 }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const MAX: isize> Implies<signed::NonZero> for signed::LessThanEqual<MAX>
+where
+    Assert<{ MAX < 0 }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const VAL: isize> Implies<signed::NonZero> for signed::Equals<VAL>
+where
+    Assert<{ VAL != 0 }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const MIN: isize, const MAX: isize> Implies<signed::NonZero> for signed::OpenInterval<MIN, MAX>
+where
+    Assert<{ (MIN >= 0) | (MAX <= 0) }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const MIN: isize, const MAX: isize> Implies<signed::NonZero>
+    for signed::OpenClosedInterval<MIN, MAX>
+where
+    Assert<{ (MIN >= 0) | (MAX < 0) }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const MIN: isize, const MAX: isize> Implies<signed::NonZero>
+    for signed::ClosedOpenInterval<MIN, MAX>
+where
+    Assert<{ (MIN > 0) | (MAX <= 0) }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const MIN: isize, const MAX: isize> Implies<signed::NonZero>
+    for signed::ClosedInterval<MIN, MAX>
+where
+    Assert<{ (MIN > 0) | (MAX < 0) }>: IsTrue,
+{
+    fn imply(self) -> signed::NonZero {
+        signed::NonZero::default()
+    }
+}
+
+impl<const F: usize, const T: usize> Implies<Not<unsigned::Equals<T>>> for Not<unsigned::Equals<F>>
+where
+    Assert<{ F == T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Equals<T>> {
+        Not::default()
+    }
+}
+
+impl<const MIN: usize> Implies<unsigned::NonZero> for unsigned::GreaterThan<MIN> {
+    fn imply(self) -> unsigned::NonZero {
+        unsigned::NonZero::default()
+    }
+}
+
+impl<const MIN: usize> Implies<unsigned::NonZero> for unsigned::GreaterThanEqual<MIN>
+where
+    Assert<{ MIN > 0 }>: IsTrue,
+{
+    fn imply(self) -> unsigned::NonZero {
+        unsigned::NonZero::default()
+    }
+}
+
+impl<const VAL: usize> Implies<unsigned::NonZero> for unsigned::Equals<VAL>
+where
+    Assert<{ VAL != 0 }>: IsTrue,
+{
+    fn imply(self) -> unsigned::NonZero {
+        unsigned::NonZero::default()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Implies<unsigned::NonZero>
+    for unsigned::OpenInterval<MIN, MAX>
+{
+    fn imply(self) -> unsigned::NonZero {
+        unsigned::NonZero::default()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Implies<unsigned::NonZero>
+    for unsigned::OpenClosedInterval<MIN, MAX>
+{
+    fn imply(self) -> unsigned::NonZero {
+        unsigned::NonZero::default()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Implies<unsigned::NonZero>
+    for unsigned::ClosedOpenInterval<MIN, MAX>
+where
+    Assert<{ MIN > 0 }>: IsTrue,
+{
+    fn imply(self) -> unsigned::NonZero {
+        unsigned::NonZero::default()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Implies<unsigned::NonZero>
+    for unsigned::ClosedInterval<MIN, MAX>
+where
+    Assert<{ MIN > 0 }>: IsTrue,
+{
+    fn imply(self) -> unsigned::NonZero {
+        unsigned::NonZero::default()
+    }
+}
+
+impl<const MIN: usize> Implies<unsigned::GreaterThan<MIN>> for unsigned::NonZero
+where
+    Assert<{ MIN == 0 }>: IsTrue,
+{
+    fn imply(self) -> unsigned::GreaterThan<MIN> {
+        unsigned::GreaterThan::<MIN>
+    }
+}
+
+impl<const MIN: usize> Implies<unsigned::GreaterThanEqual<MIN>> for unsigned::NonZero
+where
+    Assert<{ MIN <= 1 }>: IsTrue,
+{
+    fn imply(self) -> unsigned::GreaterThanEqual<MIN> {
+        unsigned::GreaterThanEqual::<MIN>
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,6 +454,7 @@ mod tests {
     fn test_signed_gt_implication() {
         let g = Refinement::<i32, signed::GreaterThan<15>>::refine(20).unwrap();
         let _: Refinement<i32, signed::GreaterThan<10>> = g.imply();
+        let _: Refinement<i32, signed::GreaterThan<15>> = g.imply();
         let _: Refinement<i32, signed::GreaterThanEqual<15>> = g.imply();
         // let r: Refinement<i32, signed::GreaterThan<20>> = g.imply();
     }
@@ -252,6 +471,7 @@ mod tests {
     fn test_signed_lt_implication() {
         let l = Refinement::<i32, signed::LessThan<15>>::refine(10).unwrap();
         let _: Refinement<i32, signed::LessThan<16>> = l.imply();
+        let _: Refinement<i32, signed::LessThan<15>> = l.imply();
         let _: Refinement<i32, signed::LessThanEqual<15>> = l.imply();
         // let _: Refinement<i32, signed::LessThan<10>> = l.imply();
     }
@@ -300,6 +520,7 @@ mod tests {
     fn test_unsigned_gt_implication() {
         let g = Refinement::<usize, unsigned::GreaterThan<15>>::refine(20).unwrap();
         let _: Refinement<usize, unsigned::GreaterThan<10>> = g.imply();
+        let _: Refinement<usize, unsigned::GreaterThan<15>> = g.imply();
         let _: Refinement<usize, unsigned::GreaterThanEqual<15>> = g.imply();
         // let _: Refinement<usize, unsigned::GreaterThan<16>> = g.imply();
     }
@@ -316,6 +537,7 @@ mod tests {
     fn test_unsigned_lt_implication() {
         let l = Refinement::<usize, unsigned::LessThan<15>>::refine(10).unwrap();
         let _: Refinement<usize, unsigned::LessThan<16>> = l.imply();
+        let _: Refinement<usize, unsigned::LessThan<15>> = l.imply();
         let _: Refinement<usize, unsigned::LessThanEqual<15>> = l.imply();
         // let _: Refinement<usize, unsigned::LessThan<10>> = l.imply();
     }
@@ -366,4 +588,48 @@ mod tests {
         let smaller = Refinement::<u8, unsigned::OpenInterval<25, 75>>::refine(50).unwrap();
         assert_eq!(bigger, smaller.imply());
     }
+
+    #[test]
+    fn test_signed_non_zero_implication() {
+        let g = Refinement::<i32, signed::GreaterThan<0>>::refine(1).unwrap();
+        let _: Refinement<i32, signed::NonZero> = g.imply();
+        let l = Refinement::<i32, signed::LessThanEqual<-1>>::refine(-1).unwrap();
+        let _: Refinement<i32, signed::NonZero> = l.imply();
+        let i = Refinement::<i32, signed::ClosedInterval<-10, -1>>::refine(-5).unwrap();
+        let _: Refinement<i32, signed::NonZero> = i.imply();
+        // let i = Refinement::<i32, signed::ClosedInterval<-10, 10>>::refine(5).unwrap();
+        // let _: Refinement<i32, signed::NonZero> = i.imply();
+    }
+
+    #[test]
+    fn test_unsigned_non_zero_implication() {
+        let g = Refinement::<u32, unsigned::GreaterThan<0>>::refine(1).unwrap();
+        let _: Refinement<u32, unsigned::NonZero> = g.imply();
+        let i = Refinement::<u32, unsigned::ClosedInterval<1, 10>>::refine(5).unwrap();
+        let _: Refinement<u32, unsigned::NonZero> = i.imply();
+        let n = Refinement::<u32, unsigned::NonZero>::refine(5).unwrap();
+        let _: Refinement<u32, unsigned::NonZero> = n.imply();
+        let _: Refinement<u32, unsigned::GreaterThanEqual<1>> = n.imply();
+        // let i = Refinement::<u32, unsigned::ClosedInterval<0, 10>>::refine(5).unwrap();
+        // let _: Refinement<u32, unsigned::NonZero> = i.imply();
+    }
+
+    #[test]
+    fn test_signed_modulo_implication() {
+        let m = Refinement::<i32, signed::Modulo<12, 5>>::refine(17).unwrap();
+        let _: Refinement<i32, signed::Modulo<4, 1>> = m.imply();
+        let _: Refinement<i32, signed::Modulo<12, 5>> = m.imply();
+        let e = Refinement::<i32, signed::Equals<7>>::refine(7).unwrap();
+        let _: Refinement<i32, signed::Equals<7>> = e.imply();
+        // let _: Refinement<i32, signed::Modulo<5, 1>> = m.imply();
+    }
+
+    #[test]
+    fn test_unsigned_modulo_implication() {
+        let m = Refinement::<u32, unsigned::Divisible<4>>::refine(8).unwrap();
+        let _: Refinement<u32, unsigned::Even> = m.imply();
+        let e = Refinement::<u32, unsigned::Equals<7>>::refine(7).unwrap();
+        let _: Refinement<u32, unsigned::Equals<7>> = e.imply();
+        // let _: Refinement<u32, unsigned::Divisible<8>> = m.imply();
+    }
 }
@@ -0,0 +1,28 @@
+use crate::character::*;
+
+use super::Implies;
+
+impl Implies<IsHexDigit> for IsDigit {
+    fn imply(self) -> IsHexDigit {
+        IsHexDigit
+    }
+}
+
+impl Implies<IsNumeric> for IsDigit {
+    fn imply(self) -> IsNumeric {
+        IsNumeric
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{character::*, *};
+
+    #[test]
+    fn test_digit_implication() {
+        let d = Refinement::<char, IsDigit>::refine('7').unwrap();
+        let h: Refinement<char, IsHexDigit> = d.imply();
+        let n: Refinement<char, IsNumeric> = d.imply();
+        assert_eq!((*h, *n), ('7', '7'));
+    }
+}
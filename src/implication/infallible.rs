@@ -0,0 +1,129 @@
+use crate::{
+    boundable::{SignedRange, UnsignedRange},
+    Refinement, RefinementOps,
+};
+
+use super::{contains_range, Assert, IsTrue};
+
+/// Infallible refinement of values whose type lies entirely within the range of the predicate.
+///
+/// For example, every `u8` is less than 256, so refining a `u8` by `LessThan<256>` can never fail:
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use refined::{prelude::*, boundable::unsigned::{LessThan, LessThanEqual}, InfallibleRefine};
+///
+/// let byte: Refinement<u8, LessThan<256>> = Refinement::refine_infallible(200);
+/// let max: Refinement<u8, LessThanEqual<255>> = Refinement::refine_infallible(u8::MAX);
+/// assert_eq!(*byte, 200);
+/// assert_eq!(*max, 255);
+/// ```
+///
+/// Predicates that some values of the type do not satisfy must be refined fallibly:
+///
+/// ```compile_fail
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use refined::{prelude::*, boundable::unsigned::LessThan, InfallibleRefine};
+///
+/// let byte: Refinement<u8, LessThan<255>> = Refinement::refine_infallible(200);
+/// ```
+pub trait InfallibleRefine: RefinementOps {
+    /// Refines a runtime value that is guaranteed to satisfy the predicate.
+    fn refine_infallible(value: Self::T) -> Self;
+}
+
+/// Implements infallible refinement of unsigned integers by predicates whose range contains the
+/// range of the type.
+macro_rules! unsigned_infallible {
+    ($($t:ty),*) => {
+        $(
+            impl<P> InfallibleRefine for Refinement<$t, P>
+            where
+                P: UnsignedRange<$t>,
+                Assert<
+                    {
+                        contains_range(
+                            <$t>::MIN as i128,
+                            <$t>::MAX as i128,
+                            <$t>::MIN as i128,
+                            <$t>::MAX as i128,
+                            <P as UnsignedRange<$t>>::MIN as i128,
+                            <P as UnsignedRange<$t>>::MAX as i128,
+                            <$t>::MIN as i128,
+                            <$t>::MAX as i128,
+                        )
+                    },
+                >: IsTrue,
+            {
+                fn refine_infallible(value: $t) -> Self {
+                    Refinement::proven(value)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements infallible refinement of signed integers by predicates whose range contains the
+/// range of the type.
+macro_rules! signed_infallible {
+    ($($t:ty),*) => {
+        $(
+            impl<P> InfallibleRefine for Refinement<$t, P>
+            where
+                P: SignedRange<$t>,
+                Assert<
+                    {
+                        contains_range(
+                            <$t>::MIN as i128,
+                            <$t>::MAX as i128,
+                            <$t>::MIN as i128,
+                            <$t>::MAX as i128,
+                            <P as SignedRange<$t>>::MIN as i128,
+                            <P as SignedRange<$t>>::MAX as i128,
+                            <$t>::MIN as i128,
+                            <$t>::MAX as i128,
+                        )
+                    },
+                >: IsTrue,
+            {
+                fn refine_infallible(value: $t) -> Self {
+                    Refinement::proven(value)
+                }
+            }
+        )*
+    };
+}
+
+unsigned_infallible!(u8, u16, u32, u64, usize);
+signed_infallible!(i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::*, *};
+
+    #[test]
+    fn test_unsigned_infallible() {
+        let value: Refinement<u8, unsigned::LessThan<256>> = Refinement::refine_infallible(255);
+        assert_eq!(*value, 255);
+        let value: Refinement<u16, unsigned::ClosedInterval<0, 65535>> =
+            Refinement::refine_infallible(0);
+        assert_eq!(*value, 0);
+        let value: Refinement<u32, unsigned::GreaterThanEqual<0>> =
+            Refinement::refine_infallible(u32::MAX);
+        assert_eq!(*value, u32::MAX);
+    }
+
+    #[test]
+    fn test_signed_infallible() {
+        let value: Refinement<i8, signed::ClosedInterval<-128, 127>> =
+            Refinement::refine_infallible(-128);
+        assert_eq!(*value, -128);
+        let value: Refinement<i16, signed::GreaterThan<-40000>> =
+            Refinement::refine_infallible(i16::MIN);
+        assert_eq!(*value, i16::MIN);
+    }
+}
@@ -37,7 +37,30 @@
 /// for an example of how the internals work currently. To create your own implementation, you need some version
 /// of `Assert` and `IsTrue`. The idea is that the generic const expr in the `Implies` implementation trait
 /// bound will only resolve to a valid `IsTrue` implementation when the logical predicate is satisfied.
-pub trait Implies<T> {
+///
+/// ## Proofs
+///
+/// The `Proof` parameter distinguishes the rules of the [boolean](crate::boolean) combinators, such as
+/// `And<A, B>` implying `A` for any `A`, from the rules between concrete predicates, which would otherwise
+/// overlap. It is inferred when calling [Implies::imply], so it only needs to be named in bounds that accept
+/// these rules; bounds that leave it out accept only the rules between concrete predicates.
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use refined::{prelude::*, boolean::{Not, Or}, boundable::unsigned::{ClosedInterval, Even, LessThan, LessThanEqual}};
+///
+/// let level: Refinement<u8, ClosedInterval<1, 10>> = Refinement::refine(5).unwrap();
+/// let at_most_10: Refinement<u8, LessThanEqual<10>> = level.imply();
+/// let either: Refinement<u8, Or<LessThanEqual<10>, Even>> = at_most_10.imply();
+///
+/// let twice_negated: Refinement<u8, Not<Not<LessThan<100>>>> = Refinement::refine(99).unwrap();
+/// let under_100: Refinement<u8, LessThan<100>> = twice_negated.imply();
+/// assert_eq!(*either, 5);
+/// assert_eq!(*under_100, 99);
+/// ```
+pub trait Implies<T, Proof = ()> {
     fn imply(self) -> T;
 }
 
@@ -49,6 +72,20 @@ impl IsTrue for Assert<true> {}
 
 mod boolean_imp;
 mod boundable_imp;
+mod character_imp;
+mod infallible;
+mod monotone;
+mod signedness;
+mod simplify;
+#[cfg(feature = "alloc")]
+mod string_imp;
+mod widening;
+
+pub use boolean_imp::{AndLeft, AndRight, DoubleNegation, OrLeft, OrRight, Trivially, Vacuously};
+pub use infallible::InfallibleRefine;
+pub use monotone::*;
+pub use signedness::contains_range;
+pub use simplify::Simplify;
 
 #[doc(cfg(feature = "arithmetic"))]
 #[cfg(feature = "arithmetic")]
@@ -0,0 +1,218 @@
+//! Value transformations that are known to preserve a refinement.
+//!
+//! Some predicates are closed in one direction: if a value is less than 100, then so is any
+//! smaller value. Transformations that only ever move a value in that direction therefore preserve
+//! the refinement without needing to re-check it.
+//!
+//! [SaturatingAdd], [SaturatingSub] and [DivBy] are known to be monotonic, while any other function
+//! can be declared to be with [Decreasing] or [Increasing]. Monotonic transformations only apply to
+//! primitive [Integer]s, whose ordering agrees with the value that their predicates bound.
+//!
+//! ```
+//! #![allow(incomplete_features)]
+//! #![feature(generic_const_exprs)]
+//!
+//! use refined::{prelude::*, boundable::unsigned::LessThan, implication::{Decreasing, DivBy}};
+//!
+//! let value = Refinement::<u8, LessThan<100>>::refine(80).unwrap();
+//! let halved = value.map_monotonic(DivBy(2));
+//! assert_eq!(*halved, 40);
+//! // SAFETY: the remainder of an unsigned value never exceeds it
+//! let remainder = halved.map_monotonic(unsafe { Decreasing::new(|v| v % 7) });
+//! assert_eq!(*remainder, 5);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::{
+    boolean::{And, Or, True},
+    boundable::{signed, unsigned},
+    Predicate, Refinement,
+};
+
+/// Predicates that continue to hold for any value less than or equal to a value that satisfies them.
+pub trait DownwardClosed {}
+
+/// Predicates that continue to hold for any value greater than or equal to a value that satisfies
+/// them.
+pub trait UpwardClosed {}
+
+impl<const MAX: usize> DownwardClosed for unsigned::LessThan<MAX> {}
+impl<const MAX: usize> DownwardClosed for unsigned::LessThanEqual<MAX> {}
+impl<const MAX: isize> DownwardClosed for signed::LessThan<MAX> {}
+impl<const MAX: isize> DownwardClosed for signed::LessThanEqual<MAX> {}
+impl DownwardClosed for True {}
+impl<A: DownwardClosed, B: DownwardClosed> DownwardClosed for And<A, B> {}
+impl<A: DownwardClosed, B: DownwardClosed> DownwardClosed for Or<A, B> {}
+
+impl<const MIN: usize> UpwardClosed for unsigned::GreaterThan<MIN> {}
+impl<const MIN: usize> UpwardClosed for unsigned::GreaterThanEqual<MIN> {}
+impl<const MIN: isize> UpwardClosed for signed::GreaterThan<MIN> {}
+impl<const MIN: isize> UpwardClosed for signed::GreaterThanEqual<MIN> {}
+impl UpwardClosed for True {}
+impl<A: UpwardClosed, B: UpwardClosed> UpwardClosed for And<A, B> {}
+impl<A: UpwardClosed, B: UpwardClosed> UpwardClosed for Or<A, B> {}
+
+/// A function that never increases its input.
+pub struct Decreasing<F>(F);
+
+impl<F> Decreasing<F> {
+    /// Declares that `f` never increases its input.
+    ///
+    /// # Safety
+    ///
+    /// `f(x) <= x` must hold for every `x` that the function is applied to. Refinements produced
+    /// from a function violating this contract may not satisfy their predicate, which is undefined
+    /// behavior when the `optimized` feature is enabled.
+    pub const unsafe fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+/// A function that never decreases its input.
+pub struct Increasing<F>(F);
+
+impl<F> Increasing<F> {
+    /// Declares that `f` never decreases its input.
+    ///
+    /// # Safety
+    ///
+    /// `f(x) >= x` must hold for every `x` that the function is applied to. Refinements produced
+    /// from a function violating this contract may not satisfy their predicate, which is undefined
+    /// behavior when the `optimized` feature is enabled.
+    pub const unsafe fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+/// Adds an unsigned amount to a value, saturating at the maximum of its type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SaturatingAdd<U>(pub U);
+
+/// Subtracts an unsigned amount from a value, saturating at the minimum of its type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SaturatingSub<U>(pub U);
+
+/// Divides an unsigned value by a divisor.
+///
+/// Dividing by zero panics, as with the `/` operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DivBy<U>(pub U);
+
+/// Primitive integer types, whose ordering agrees with the value bounded by their predicates.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Integer: sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Transformations that preserve the predicate `P` over values of type `T`.
+pub trait Monotonic<T, P> {
+    /// Applies the transformation to a value satisfying `P`, producing a value that satisfies it
+    /// too.
+    fn apply(self, value: T) -> T;
+}
+
+impl<T: Integer, P: DownwardClosed, F: FnOnce(T) -> T> Monotonic<T, P> for Decreasing<F> {
+    fn apply(self, value: T) -> T {
+        (self.0)(value)
+    }
+}
+
+impl<T: Integer, P: UpwardClosed, F: FnOnce(T) -> T> Monotonic<T, P> for Increasing<F> {
+    fn apply(self, value: T) -> T {
+        (self.0)(value)
+    }
+}
+
+macro_rules! unsigned_monotonic {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl Integer for $t {}
+
+            impl<P: UpwardClosed> Monotonic<$t, P> for SaturatingAdd<$t> {
+                fn apply(self, value: $t) -> $t {
+                    value.saturating_add(self.0)
+                }
+            }
+
+            impl<P: DownwardClosed> Monotonic<$t, P> for SaturatingSub<$t> {
+                fn apply(self, value: $t) -> $t {
+                    value.saturating_sub(self.0)
+                }
+            }
+
+            impl<P: DownwardClosed> Monotonic<$t, P> for DivBy<$t> {
+                fn apply(self, value: $t) -> $t {
+                    value / self.0
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! signed_monotonic {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl Integer for $t {}
+
+            impl<P: UpwardClosed> Monotonic<$t, P> for SaturatingAdd<$u> {
+                fn apply(self, value: $t) -> $t {
+                    value.saturating_add_unsigned(self.0)
+                }
+            }
+
+            impl<P: DownwardClosed> Monotonic<$t, P> for SaturatingSub<$u> {
+                fn apply(self, value: $t) -> $t {
+                    value.saturating_sub_unsigned(self.0)
+                }
+            }
+        )*
+    };
+}
+
+unsigned_monotonic!(u8, u16, u32, u64, usize);
+signed_monotonic!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+impl<T, P: Predicate<T>> Refinement<T, P> {
+    /// Transforms the refined value with a function that is known to preserve the predicate.
+    pub fn map_monotonic<M: Monotonic<T, P>>(self, f: M) -> Self {
+        let value = f.apply(self.0);
+        debug_assert!(P::test(&value));
+        Refinement(value, PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::*, *};
+
+    #[test]
+    fn test_map_monotonic_decreasing() {
+        let value = Refinement::<u8, unsigned::LessThan<100>>::refine(99).unwrap();
+        let halved = value.map_monotonic(unsafe { Decreasing::new(|v| v / 2) });
+        assert_eq!(*halved, 49);
+    }
+
+    #[test]
+    fn test_map_monotonic_known() {
+        let value = Refinement::<u16, unsigned::LessThan<100>>::refine(99).unwrap();
+        assert_eq!(*value.map_monotonic(DivBy(10)), 9);
+        assert_eq!(*value.map_monotonic(SaturatingSub(200)), 0);
+        let value = Refinement::<i8, signed::GreaterThan<-10>>::refine(120).unwrap();
+        assert_eq!(*value.map_monotonic(SaturatingAdd(200)), i8::MAX);
+        let value = Refinement::<i8, signed::LessThan<0>>::refine(-120).unwrap();
+        assert_eq!(*value.map_monotonic(SaturatingSub(200)), i8::MIN);
+    }
+
+    #[test]
+    fn test_map_monotonic_increasing() {
+        let value = Refinement::<i32, signed::GreaterThan<0>>::refine(5).unwrap();
+        let doubled = value.map_monotonic(unsafe { Increasing::new(|v| v * 2) });
+        assert_eq!(*doubled, 10);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    boundable::{SignedRange, UnsignedRange},
+    Refinement,
+};
+
+use super::{Assert, Implies, IsTrue};
+
+/// Whether every value within both `min..=max` and the range of the source type is also within both
+/// `target_min..=target_max` and the range of the target type.
+#[doc(hidden)]
+#[allow(clippy::too_many_arguments)]
+pub const fn contains_range(
+    min: i128,
+    max: i128,
+    type_min: i128,
+    type_max: i128,
+    target_min: i128,
+    target_max: i128,
+    target_type_min: i128,
+    target_type_max: i128,
+) -> bool {
+    let min = if min > type_min { min } else { type_min };
+    let max = if max < type_max { max } else { type_max };
+    min > max
+        || (target_min <= min
+            && target_type_min <= min
+            && max <= target_max
+            && max <= target_type_max)
+}
+
+/// Implements implication from refinements of unsigned integers into refinements of signed
+/// integers when every value that satisfies the source predicate also satisfies the target.
+macro_rules! unsigned_to_signed {
+    ($($from:ty => $($to:ty),+);* $(;)?) => {
+        $($(
+            impl<F, T> Implies<Refinement<$to, T>> for Refinement<$from, F>
+            where
+                F: UnsignedRange<$from>,
+                T: SignedRange<$to>,
+                Assert<
+                    {
+                        contains_range(
+                            <F as UnsignedRange<$from>>::MIN as i128,
+                            <F as UnsignedRange<$from>>::MAX as i128,
+                            <$from>::MIN as i128,
+                            <$from>::MAX as i128,
+                            <T as SignedRange<$to>>::MIN as i128,
+                            <T as SignedRange<$to>>::MAX as i128,
+                            <$to>::MIN as i128,
+                            <$to>::MAX as i128,
+                        )
+                    },
+                >: IsTrue,
+            {
+                fn imply(self) -> Refinement<$to, T> {
+                    Refinement::proven(self.0 as $to)
+                }
+            }
+        )+)*
+    };
+}
+
+/// Implements implication from refinements of signed integers into refinements of unsigned
+/// integers when every value that satisfies the source predicate also satisfies the target.
+macro_rules! signed_to_unsigned {
+    ($($from:ty => $($to:ty),+);* $(;)?) => {
+        $($(
+            impl<F, T> Implies<Refinement<$to, T>> for Refinement<$from, F>
+            where
+                F: SignedRange<$from>,
+                T: UnsignedRange<$to>,
+                Assert<
+                    {
+                        contains_range(
+                            <F as SignedRange<$from>>::MIN as i128,
+                            <F as SignedRange<$from>>::MAX as i128,
+                            <$from>::MIN as i128,
+                            <$from>::MAX as i128,
+                            <T as UnsignedRange<$to>>::MIN as i128,
+                            <T as UnsignedRange<$to>>::MAX as i128,
+                            <$to>::MIN as i128,
+                            <$to>::MAX as i128,
+                        )
+                    },
+                >: IsTrue,
+            {
+                fn imply(self) -> Refinement<$to, T> {
+                    Refinement::proven(self.0 as $to)
+                }
+            }
+        )+)*
+    };
+}
+
+unsigned_to_signed!(
+    u8 => i8, i16, i32, isize;
+    u16 => i8, i16, i32, isize;
+    u32 => i8, i16, i32, isize;
+    usize => i8, i16, i32, isize;
+);
+
+signed_to_unsigned!(
+    i8 => u8, u16, u32, usize;
+    i16 => u8, u16, u32, usize;
+    i32 => u8, u16, u32, usize;
+    isize => u8, u16, u32, usize;
+);
+
+#[cfg(target_pointer_width = "64")]
+unsigned_to_signed!(
+    u8 => i64;
+    u16 => i64;
+    u32 => i64;
+    u64 => i8, i16, i32, i64, isize;
+    usize => i64;
+);
+
+#[cfg(target_pointer_width = "64")]
+signed_to_unsigned!(
+    i8 => u64;
+    i16 => u64;
+    i32 => u64;
+    i64 => u8, u16, u32, u64, usize;
+    isize => u64;
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::*, *};
+
+    #[test]
+    fn test_unsigned_to_signed() {
+        let small = Refinement::<u8, unsigned::LessThan<100>>::refine(99).unwrap();
+        let signed: Refinement<i16, signed::ClosedInterval<0, 99>> = small.imply();
+        assert_eq!(*signed, 99);
+        let signed: Refinement<i8, signed::GreaterThanEqual<0>> = small.imply();
+        assert_eq!(*signed, 99);
+
+        // The range of the source type bounds predicates with only a lower bound.
+        let large = Refinement::<u16, unsigned::GreaterThan<10>>::refine(60000).unwrap();
+        let signed: Refinement<i32, signed::GreaterThan<10>> = large.imply();
+        assert_eq!(*signed, 60000);
+    }
+
+    #[test]
+    fn test_signed_to_unsigned() {
+        let offset = Refinement::<i32, signed::ClosedInterval<0, 255>>::refine(255).unwrap();
+        let unsigned: Refinement<u8, unsigned::LessThanEqual<255>> = offset.imply();
+        assert_eq!(*unsigned, 255);
+        let unsigned: Refinement<usize, unsigned::ClosedInterval<0, 1000>> = offset.imply();
+        assert_eq!(*unsigned, 255);
+
+        let positive = Refinement::<i8, signed::Positive>::refine(127).unwrap();
+        let unsigned: Refinement<u16, unsigned::GreaterThan<0>> = positive.imply();
+        assert_eq!(*unsigned, 127);
+    }
+}
@@ -0,0 +1,177 @@
+//! Type-level simplification of predicate compositions.
+//!
+//! Compositions of bounds of the same kind are redundant: a value that is less than 5 and less
+//! than 10 is simply less than 5. [Simplify] reduces these compositions to the single predicate
+//! that they are equivalent to, which keeps the bounds of implication and arithmetic tight and the
+//! error messages of the resulting refinements short.
+//!
+//! ```
+//! #![allow(incomplete_features)]
+//! #![feature(generic_const_exprs)]
+//!
+//! use refined::{prelude::*, boolean::And, boundable::unsigned::LessThan};
+//!
+//! let value = Refinement::<u8, And<LessThan<5>, LessThan<10>>>::refine(3).unwrap();
+//! let simplified: Refinement<u8, LessThan<5>> = value.simplify();
+//! assert_eq!(*simplified, 3);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::{
+    boolean::{And, Not, Or},
+    boundable::{signed, unsigned},
+    Predicate, Refinement,
+};
+
+/// Predicates that are equivalent to a simpler predicate.
+///
+/// Only the outermost composition is simplified, so nested compositions are simplified by
+/// simplifying their parts first.
+///
+/// This trait is sealed, since [Refinement::simplify] relies on each simplification being
+/// equivalent to the composition that it simplifies.
+pub trait Simplify: sealed::Sealed {
+    /// The simplest known predicate that is satisfied by exactly the same values.
+    type Output;
+}
+
+mod sealed {
+    /// Prevents [Simplify](super::Simplify) from being implemented outside of this module.
+    pub trait Sealed {}
+}
+
+/// The smaller of two unsigned bounds.
+pub const fn umin(a: usize, b: usize) -> usize {
+    if a <= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// The larger of two unsigned bounds.
+pub const fn umax(a: usize, b: usize) -> usize {
+    if a >= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// The smaller of two signed bounds.
+pub const fn smin(a: isize, b: isize) -> isize {
+    if a <= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// The larger of two signed bounds.
+pub const fn smax(a: isize, b: isize) -> isize {
+    if a >= b {
+        a
+    } else {
+        b
+    }
+}
+
+macro_rules! simplify {
+    ($ty:ty, $module:ident, $pred:ident, $and:ident, $or:ident) => {
+        impl<const A: $ty, const B: $ty> sealed::Sealed
+            for And<$module::$pred<A>, $module::$pred<B>>
+        {
+        }
+
+        impl<const A: $ty, const B: $ty> sealed::Sealed
+            for Or<$module::$pred<A>, $module::$pred<B>>
+        {
+        }
+
+        impl<const A: $ty, const B: $ty> Simplify for And<$module::$pred<A>, $module::$pred<B>>
+        where
+            $module::$pred<{ $and(A, B) }>: Sized,
+        {
+            type Output = $module::$pred<{ $and(A, B) }>;
+        }
+
+        impl<const A: $ty, const B: $ty> Simplify for Or<$module::$pred<A>, $module::$pred<B>>
+        where
+            $module::$pred<{ $or(A, B) }>: Sized,
+        {
+            type Output = $module::$pred<{ $or(A, B) }>;
+        }
+    };
+}
+
+simplify!(usize, unsigned, LessThan, umin, umax);
+simplify!(usize, unsigned, LessThanEqual, umin, umax);
+simplify!(usize, unsigned, GreaterThan, umax, umin);
+simplify!(usize, unsigned, GreaterThanEqual, umax, umin);
+simplify!(isize, signed, LessThan, smin, smax);
+simplify!(isize, signed, LessThanEqual, smin, smax);
+simplify!(isize, signed, GreaterThan, smax, smin);
+simplify!(isize, signed, GreaterThanEqual, smax, smin);
+
+impl<P> sealed::Sealed for Not<Not<P>> {}
+
+impl<P> Simplify for Not<Not<P>> {
+    type Output = P;
+}
+
+impl<T, P: Predicate<T> + Simplify> Refinement<T, P>
+where
+    P::Output: Predicate<T>,
+{
+    /// Converts the refinement into its [simplified](Simplify) predicate, which is satisfied by
+    /// exactly the same values.
+    pub fn simplify(self) -> Refinement<T, P::Output> {
+        debug_assert!(P::Output::test(&self.0));
+        Refinement(self.0, PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::*, boundable::*, *};
+
+    #[test]
+    fn test_simplify_and() {
+        let value = Refinement::<u8, And<unsigned::LessThan<5>, unsigned::LessThan<10>>>::refine(4)
+            .unwrap();
+        let simplified: Refinement<u8, unsigned::LessThan<5>> = value.simplify();
+        assert_eq!(*simplified, 4);
+
+        let value = Refinement::<
+            i8,
+            And<signed::GreaterThanEqual<-3>, signed::GreaterThanEqual<-7>>,
+        >::refine(-3)
+        .unwrap();
+        let simplified: Refinement<i8, signed::GreaterThanEqual<-3>> = value.simplify();
+        assert_eq!(*simplified, -3);
+    }
+
+    #[test]
+    fn test_simplify_or() {
+        let value =
+            Refinement::<u16, Or<unsigned::GreaterThan<50>, unsigned::GreaterThan<20>>>::refine(21)
+                .unwrap();
+        let simplified: Refinement<u16, unsigned::GreaterThan<20>> = value.simplify();
+        assert_eq!(*simplified, 21);
+
+        let value =
+            Refinement::<i32, Or<signed::LessThanEqual<-1>, signed::LessThanEqual<9>>>::refine(9)
+                .unwrap();
+        let simplified: Refinement<i32, signed::LessThanEqual<9>> = value.simplify();
+        assert_eq!(*simplified, 9);
+    }
+
+    #[test]
+    fn test_simplify_nested() {
+        type Inner = And<unsigned::LessThan<5>, unsigned::LessThan<10>>;
+        let value = Refinement::<u8, Not<Not<Inner>>>::refine(3).unwrap();
+        let simplified: Refinement<u8, unsigned::LessThan<5>> = value.simplify().simplify();
+        assert_eq!(*simplified, 3);
+    }
+}
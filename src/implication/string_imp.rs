@@ -0,0 +1,146 @@
+use crate::{string::*, TypeString};
+
+use super::{Assert, Implies, IsTrue};
+
+/// Whether `value` starts with `prefix`, in a const context.
+const fn starts_with(value: &str, prefix: &str) -> bool {
+    let (value, prefix) = (value.as_bytes(), prefix.as_bytes());
+    if prefix.len() > value.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if value[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Whether `value` ends with `suffix`, in a const context.
+const fn ends_with(value: &str, suffix: &str) -> bool {
+    let (value, suffix) = (value.as_bytes(), suffix.as_bytes());
+    if suffix.len() > value.len() {
+        return false;
+    }
+    let offset = value.len() - suffix.len();
+    let mut i = 0;
+    while i < suffix.len() {
+        if value[offset + i] != suffix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Whether `value` contains `substr`, in a const context.
+const fn contains(value: &str, substr: &str) -> bool {
+    let (value, substr) = (value.as_bytes(), substr.as_bytes());
+    if substr.len() > value.len() {
+        return false;
+    }
+    let mut start = 0;
+    while start + substr.len() <= value.len() {
+        let mut i = 0;
+        while i < substr.len() && value[start + i] == substr[i] {
+            i += 1;
+        }
+        if i == substr.len() {
+            return true;
+        }
+        start += 1;
+    }
+    false
+}
+
+impl<F: TypeString, T: TypeString> Implies<StartsWith<T>> for StartsWith<F>
+where
+    Assert<{ starts_with(F::VALUE, T::VALUE) }>: IsTrue,
+{
+    fn imply(self) -> StartsWith<T> {
+        StartsWith::default()
+    }
+}
+
+impl<F: TypeString, T: TypeString> Implies<EndsWith<T>> for EndsWith<F>
+where
+    Assert<{ ends_with(F::VALUE, T::VALUE) }>: IsTrue,
+{
+    fn imply(self) -> EndsWith<T> {
+        EndsWith::default()
+    }
+}
+
+impl<F: TypeString, T: TypeString> Implies<Contains<T>> for Contains<F>
+where
+    Assert<{ contains(F::VALUE, T::VALUE) }>: IsTrue,
+{
+    fn imply(self) -> Contains<T> {
+        Contains::default()
+    }
+}
+
+impl<F: TypeString, T: TypeString> Implies<Contains<T>> for StartsWith<F>
+where
+    Assert<{ contains(F::VALUE, T::VALUE) }>: IsTrue,
+{
+    fn imply(self) -> Contains<T> {
+        Contains::default()
+    }
+}
+
+impl<F: TypeString, T: TypeString> Implies<Contains<T>> for EndsWith<F>
+where
+    Assert<{ contains(F::VALUE, T::VALUE) }>: IsTrue,
+{
+    fn imply(self) -> Contains<T> {
+        Contains::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use crate::{boundable::unsigned, string::*, type_string, *};
+
+    type_string!(FooBar, "foo/bar");
+    type_string!(Foo, "foo");
+    type_string!(Bar, "bar");
+    type_string!(OBa, "o/ba");
+
+    #[test]
+    fn test_prefix_implication() {
+        let s =
+            Refinement::<String, StartsWith<FooBar>>::refine("foo/bar/baz".to_string()).unwrap();
+        let _: Refinement<String, StartsWith<Foo>> = s.clone().imply();
+        let _: Refinement<String, StartsWith<FooBar>> = s.clone().imply();
+        let _: Refinement<String, Contains<OBa>> = s.imply();
+        // let _: Refinement<String, StartsWith<Bar>> = s.imply();
+    }
+
+    #[test]
+    fn test_suffix_implication() {
+        let s = Refinement::<String, EndsWith<FooBar>>::refine("/foo/bar".to_string()).unwrap();
+        let _: Refinement<String, EndsWith<Bar>> = s.clone().imply();
+        let _: Refinement<String, Contains<Foo>> = s.imply();
+        // let _: Refinement<String, EndsWith<Foo>> = s.imply();
+    }
+
+    #[test]
+    fn test_contains_implication() {
+        let s = Refinement::<String, Contains<FooBar>>::refine("a foo/bar b".to_string()).unwrap();
+        let _: Refinement<String, Contains<Bar>> = s.clone().imply();
+        let _: Refinement<String, Contains<OBa>> = s.imply();
+    }
+
+    #[test]
+    fn test_length_implication() {
+        let s = Refinement::<String, unsigned::ClosedInterval<2, 5>>::refine("abc".to_string())
+            .unwrap();
+        let l: Refinement<String, unsigned::ClosedInterval<1, 10>> = s.imply();
+        assert_eq!(l.len(), 3);
+    }
+}
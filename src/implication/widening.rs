@@ -0,0 +1,70 @@
+use crate::{boundable::BoundingPredicate, Predicate, Refinement};
+
+use super::Implies;
+
+/// Implements implication between refinements of numeric types that are widened losslessly, by any
+/// rule whose target depends only on the bounding value.
+macro_rules! widen {
+    ($($from:ty => $($to:ty),+);* $(;)?) => {
+        $($(
+            impl<F, T, Proof> Implies<Refinement<$to, T>, Proof> for Refinement<$from, F>
+            where
+                F: Predicate<$from> + Implies<T, Proof>,
+                T: Predicate<$to> + BoundingPredicate,
+            {
+                fn imply(self) -> Refinement<$to, T> {
+                    Refinement::proven(<$to>::from(self.0))
+                }
+            }
+        )+)*
+    };
+}
+
+widen!(
+    u8 => u16, u32, usize;
+    u16 => u32, usize;
+    i8 => i16, i32, isize;
+    i16 => i32, isize;
+);
+
+#[cfg(target_pointer_width = "64")]
+widen!(
+    u8 => u64;
+    u16 => u64;
+    u32 => u64;
+    i8 => i64;
+    i16 => i64;
+    i32 => i64;
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::*, boundable::*, *};
+
+    #[test]
+    fn test_widening() {
+        let small = Refinement::<u8, unsigned::LessThan<100>>::refine(99).unwrap();
+        let wide: Refinement<u32, unsigned::LessThan<100>> = small.imply();
+        assert_eq!(*wide, 99);
+        let wide: Refinement<usize, unsigned::LessThanEqual<100>> = small.imply();
+        assert_eq!(*wide, 99);
+
+        let level = Refinement::<i16, signed::ClosedInterval<-10, 10>>::refine(-10).unwrap();
+        let wide: Refinement<i64, signed::ClosedInterval<-20, 10>> = level.imply();
+        assert_eq!(*wide, -10);
+        let wide: Refinement<i32, signed::GreaterThanEqual<-10>> = level.imply();
+        assert_eq!(*wide, -10);
+    }
+
+    #[test]
+    fn test_widening_combinators() {
+        let even = Refinement::<u16, And<unsigned::Even, unsigned::NonZero>>::refine(8).unwrap();
+        let wide: Refinement<u64, And<unsigned::Even, unsigned::NonZero>> = even.imply();
+        assert_eq!(*wide, 8);
+        let even = Refinement::<u8, unsigned::Even>::refine(8).unwrap();
+        let wide: Refinement<u32, Or<unsigned::Even, unsigned::Equals<1>>> = even.imply();
+        assert_eq!(*wide, 8);
+        let wide: Refinement<u16, unsigned::Divisible<2>> = even.imply();
+        assert_eq!(*wide, 8);
+    }
+}
@@ -0,0 +1,357 @@
+//! Structured introspection of predicates.
+//!
+//! Error messages describe a predicate in English, which is convenient for users but not for
+//! tooling. [PredicateInfo] instead exposes the structure of a predicate: its
+//! [kind](PredicateInfo::KIND), which carries parameters such as bounds and patterns, and the
+//! predicates that it combines. A [Visitor] walks this structure without allocating, while
+//! [Description] (with the `alloc` feature) collects it into a tree.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{boolean::Not, boundable::unsigned::{ClosedInterval, Even}, error::*, info::*};
+//!
+//! let description = Description::of::<ClosedInterval<1, 10>>();
+//! assert_eq!(description.kind, ErrorKind::And);
+//! assert_eq!(
+//!     description.children[0].kind,
+//!     ErrorKind::GreaterThanEqual(Bound::Unsigned(1))
+//! );
+//! assert_eq!(
+//!     description.children[1].kind,
+//!     ErrorKind::LessThanEqual(Bound::Unsigned(10))
+//! );
+//!
+//! let odd = Description::of::<Not<Even>>();
+//! assert_eq!(odd.kind, ErrorKind::Not);
+//! assert_eq!(odd.children.len(), 1);
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    boolean::{And, False, Not, Or, True, Xor},
+    boundable::{signed, unsigned},
+    character, collection,
+    error::{Bound, ErrorKind},
+    normalize, project,
+};
+#[cfg(feature = "alloc")]
+use crate::{string, TypeString};
+
+/// Predicates that can describe their structure.
+///
+/// Combinators report the predicates that they combine as their children, while wrappers that
+/// behave exactly like another predicate, such as [Normalize](normalize::Normalize), describe
+/// themselves as that predicate.
+pub trait PredicateInfo {
+    /// The kind of the predicate, including its parameters such as bounds and patterns.
+    const KIND: ErrorKind;
+
+    /// Visits each predicate that this predicate combines, in order.
+    ///
+    /// The default implementation visits nothing, as is the case for predicates that don't
+    /// combine other predicates.
+    fn children<V: Visitor>(_visitor: &mut V) {}
+}
+
+/// A walk over the structure of a predicate.
+///
+/// Visitors are called with each child of a predicate, and recurse into its children by calling
+/// [PredicateInfo::children] themselves.
+pub trait Visitor {
+    /// Visits the predicate `P`.
+    fn visit<P: PredicateInfo>(&mut self);
+}
+
+/// The structure of a predicate, collected into a tree.
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Description {
+    /// The kind of the predicate.
+    pub kind: ErrorKind,
+    /// The descriptions of the predicates that the predicate combines.
+    pub children: Vec<Description>,
+}
+
+#[cfg(feature = "alloc")]
+impl Description {
+    /// The description of the predicate `P`.
+    pub fn of<P: PredicateInfo>() -> Self {
+        let mut children = Children(Vec::new());
+        P::children(&mut children);
+        Self {
+            kind: P::KIND,
+            children: children.0,
+        }
+    }
+}
+
+/// Collects the descriptions of the children of a predicate.
+#[cfg(feature = "alloc")]
+struct Children(Vec<Description>);
+
+#[cfg(feature = "alloc")]
+impl Visitor for Children {
+    fn visit<P: PredicateInfo>(&mut self) {
+        self.0.push(Description::of::<P>());
+    }
+}
+
+impl PredicateInfo for True {
+    const KIND: ErrorKind = ErrorKind::True;
+}
+
+impl PredicateInfo for False {
+    const KIND: ErrorKind = ErrorKind::False;
+}
+
+impl<A: PredicateInfo, B: PredicateInfo> PredicateInfo for And<A, B> {
+    const KIND: ErrorKind = ErrorKind::And;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        visitor.visit::<A>();
+        visitor.visit::<B>();
+    }
+}
+
+impl<A: PredicateInfo, B: PredicateInfo> PredicateInfo for Or<A, B> {
+    const KIND: ErrorKind = ErrorKind::Or;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        visitor.visit::<A>();
+        visitor.visit::<B>();
+    }
+}
+
+impl<A: PredicateInfo, B: PredicateInfo> PredicateInfo for Xor<A, B> {
+    const KIND: ErrorKind = ErrorKind::Xor;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        visitor.visit::<A>();
+        visitor.visit::<B>();
+    }
+}
+
+impl<P: PredicateInfo> PredicateInfo for Not<P> {
+    const KIND: ErrorKind = ErrorKind::Not;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        visitor.visit::<P>();
+    }
+}
+
+impl<const MIN: usize> PredicateInfo for unsigned::GreaterThan<MIN> {
+    const KIND: ErrorKind = ErrorKind::GreaterThan(Bound::Unsigned(MIN));
+}
+
+impl<const MIN: usize> PredicateInfo for unsigned::GreaterThanEqual<MIN> {
+    const KIND: ErrorKind = ErrorKind::GreaterThanEqual(Bound::Unsigned(MIN));
+}
+
+impl<const MAX: usize> PredicateInfo for unsigned::LessThan<MAX> {
+    const KIND: ErrorKind = ErrorKind::LessThan(Bound::Unsigned(MAX));
+}
+
+impl<const MAX: usize> PredicateInfo for unsigned::LessThanEqual<MAX> {
+    const KIND: ErrorKind = ErrorKind::LessThanEqual(Bound::Unsigned(MAX));
+}
+
+impl<const VAL: usize> PredicateInfo for unsigned::Equals<VAL> {
+    const KIND: ErrorKind = ErrorKind::Equals(Bound::Unsigned(VAL));
+}
+
+impl<const DIV: usize, const MOD: usize> PredicateInfo for unsigned::Modulo<DIV, MOD> {
+    const KIND: ErrorKind = ErrorKind::Modulo {
+        divisor: Bound::Unsigned(DIV),
+        remainder: Bound::Unsigned(MOD),
+    };
+}
+
+impl<const MIN: isize> PredicateInfo for signed::GreaterThan<MIN> {
+    const KIND: ErrorKind = ErrorKind::GreaterThan(Bound::Signed(MIN));
+}
+
+impl<const MIN: isize> PredicateInfo for signed::GreaterThanEqual<MIN> {
+    const KIND: ErrorKind = ErrorKind::GreaterThanEqual(Bound::Signed(MIN));
+}
+
+impl<const MAX: isize> PredicateInfo for signed::LessThan<MAX> {
+    const KIND: ErrorKind = ErrorKind::LessThan(Bound::Signed(MAX));
+}
+
+impl<const MAX: isize> PredicateInfo for signed::LessThanEqual<MAX> {
+    const KIND: ErrorKind = ErrorKind::LessThanEqual(Bound::Signed(MAX));
+}
+
+impl<const VAL: isize> PredicateInfo for signed::Equals<VAL> {
+    const KIND: ErrorKind = ErrorKind::Equals(Bound::Signed(VAL));
+}
+
+impl<const DIV: isize, const MOD: isize> PredicateInfo for signed::Modulo<DIV, MOD> {
+    const KIND: ErrorKind = ErrorKind::Modulo {
+        divisor: Bound::Signed(DIV),
+        remainder: Bound::Signed(MOD),
+    };
+}
+
+impl PredicateInfo for character::IsControl {
+    const KIND: ErrorKind = ErrorKind::Control;
+}
+
+impl PredicateInfo for character::IsDigit {
+    const KIND: ErrorKind = ErrorKind::Digit;
+}
+
+impl PredicateInfo for character::IsLowercase {
+    const KIND: ErrorKind = ErrorKind::Lowercase;
+}
+
+impl PredicateInfo for character::IsUppercase {
+    const KIND: ErrorKind = ErrorKind::Uppercase;
+}
+
+impl PredicateInfo for character::IsNumeric {
+    const KIND: ErrorKind = ErrorKind::Numeric;
+}
+
+impl PredicateInfo for character::IsWhitespace {
+    const KIND: ErrorKind = ErrorKind::Whitespace;
+}
+
+impl PredicateInfo for character::IsHexDigit {
+    const KIND: ErrorKind = ErrorKind::HexDigit;
+}
+
+impl<P: PredicateInfo> PredicateInfo for collection::All<P> {
+    const KIND: ErrorKind = ErrorKind::All;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        visitor.visit::<P>();
+    }
+}
+
+impl<P: PredicateInfo> PredicateInfo for collection::AllKeys<P> {
+    const KIND: ErrorKind = ErrorKind::AllKeys;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        visitor.visit::<P>();
+    }
+}
+
+impl<P: PredicateInfo> PredicateInfo for collection::AllValues<P> {
+    const KIND: ErrorKind = ErrorKind::AllValues;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        visitor.visit::<P>();
+    }
+}
+
+impl PredicateInfo for collection::Sorted {
+    const KIND: ErrorKind = ErrorKind::Sorted;
+}
+
+impl<F, P: PredicateInfo> PredicateInfo for normalize::Normalize<F, P> {
+    const KIND: ErrorKind = P::KIND;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        P::children(visitor);
+    }
+}
+
+impl<F, P: PredicateInfo> PredicateInfo for project::On<F, P> {
+    const KIND: ErrorKind = P::KIND;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        P::children(visitor);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: TypeString> PredicateInfo for string::StartsWith<S> {
+    const KIND: ErrorKind = ErrorKind::StartsWith(S::VALUE);
+}
+
+#[cfg(feature = "alloc")]
+impl<S: TypeString> PredicateInfo for string::EndsWith<S> {
+    const KIND: ErrorKind = ErrorKind::EndsWith(S::VALUE);
+}
+
+#[cfg(feature = "alloc")]
+impl<S: TypeString> PredicateInfo for string::Contains<S> {
+    const KIND: ErrorKind = ErrorKind::Contains(S::VALUE);
+}
+
+#[cfg(feature = "alloc")]
+impl PredicateInfo for string::Trimmed {
+    const KIND: ErrorKind = ErrorKind::Trimmed;
+}
+
+#[cfg(feature = "alloc")]
+impl PredicateInfo for string::NoWhitespace {
+    const KIND: ErrorKind = ErrorKind::NoWhitespace;
+}
+
+#[cfg(feature = "alloc")]
+impl PredicateInfo for string::IsAscii {
+    const KIND: ErrorKind = ErrorKind::Ascii;
+}
+
+#[cfg(feature = "regex")]
+impl<S: TypeString> PredicateInfo for string::Regex<S> {
+    const KIND: ErrorKind = ErrorKind::Regex(S::VALUE);
+}
+
+#[cfg(feature = "tracing")]
+impl<P: PredicateInfo> PredicateInfo for crate::trace::Untraced<P> {
+    const KIND: ErrorKind = P::KIND;
+
+    fn children<V: Visitor>(visitor: &mut V) {
+        P::children(visitor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the predicates within a predicate, including itself.
+    struct Count(usize);
+
+    impl Visitor for Count {
+        fn visit<P: PredicateInfo>(&mut self) {
+            self.0 += 1;
+            P::children(self);
+        }
+    }
+
+    #[test]
+    fn test_visitor() {
+        let mut count = Count(0);
+        count.visit::<And<unsigned::ClosedInterval<1, 10>, Not<unsigned::Even>>>();
+        assert_eq!(count.0, 6);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_description() {
+        type Test = collection::All<
+            Or<normalize::Normalize<normalize::Trim, string::Trimmed>, signed::Odd>,
+        >;
+        let description = Description::of::<Test>();
+        assert_eq!(description.kind, ErrorKind::All);
+        let or = &description.children[0];
+        assert_eq!(or.kind, ErrorKind::Or);
+        assert_eq!(or.children[0].kind, ErrorKind::Trimmed);
+        assert!(or.children[0].children.is_empty());
+        assert_eq!(or.children[1].kind, ErrorKind::Not);
+        assert_eq!(
+            or.children[1].children[0].kind,
+            ErrorKind::Modulo {
+                divisor: Bound::Signed(2),
+                remainder: Bound::Signed(0),
+            }
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Refinement of the items of iterators.
+//!
+//! [RefineIterator] extends every iterator with adapters for validating batches of values, either
+//! eagerly or lazily as a stream, which report failures at the
+//! [index](crate::error::PathSegment::Index) of the offending item.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::LessThan, iter::RefineIterator};
+//!
+//! type Percentage = Refinement<u8, LessThan<101>>;
+//!
+//! let percentages = [10, 50, 100].into_iter().refine_all::<Percentage>().unwrap();
+//! assert_eq!(percentages.len(), 3);
+//!
+//! let err = [10, 150].into_iter().refine_all::<Percentage>().unwrap_err();
+//! assert_eq!(err.to_string(), "refinement violated: element [1]: must be less than 101");
+//!
+//! let (valid, invalid) = [10, 150, 20, 200]
+//!     .into_iter()
+//!     .partition_refined::<LessThan<101>>();
+//! assert_eq!(valid, vec![Percentage::refine(10).unwrap(), Percentage::refine(20).unwrap()]);
+//! assert_eq!(invalid[0].0, 150);
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{iter::FusedIterator, marker::PhantomData};
+
+#[cfg(feature = "alloc")]
+use crate::{error::PathSegment, RefinementOps};
+use crate::{Predicate, Refinement, RefinementError};
+
+/// Locates an error at the `index` of the item that it rejected.
+#[cfg(feature = "alloc")]
+fn at(err: RefinementError, index: usize) -> RefinementError {
+    err.at(PathSegment::Index(index))
+}
+
+/// Errors can't be located without an allocator.
+#[cfg(not(feature = "alloc"))]
+fn at(err: RefinementError, _index: usize) -> RefinementError {
+    err
+}
+
+/// Refinement adapters for iterators.
+pub trait RefineIterator: Iterator + Sized {
+    /// Lazily refines every item by `P`, yielding rejected items along with their errors so that
+    /// they can be routed elsewhere without interrupting the stream.
+    fn refine_each<P: Predicate<Self::Item>>(self) -> RefineIter<Self, P> {
+        RefineIter {
+            iter: self,
+            index: 0,
+            predicate: PhantomData,
+        }
+    }
+
+    /// Refines every item as `R`, failing on the first item that is rejected.
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    fn refine_all<R: RefinementOps<T = Self::Item>>(self) -> Result<Vec<R>, RefinementError> {
+        self.enumerate()
+            .map(|(index, item)| R::refine(item).map_err(|err| at(err, index)))
+            .collect()
+    }
+
+    /// Refines every item by `P`, separating the refined items from the rejected items and their
+    /// errors.
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    #[allow(clippy::type_complexity)]
+    fn partition_refined<P: Predicate<Self::Item>>(
+        self,
+    ) -> (
+        Vec<Refinement<Self::Item, P>>,
+        Vec<(Self::Item, RefinementError)>,
+    ) {
+        let mut refined = Vec::new();
+        let mut rejected = Vec::new();
+        for (index, item) in self.enumerate() {
+            match Refinement::refine_or_return(item) {
+                Ok(item) => refined.push(item),
+                Err((item, err)) => rejected.push((item, at(err, index))),
+            }
+        }
+        (refined, rejected)
+    }
+}
+
+impl<I: Iterator> RefineIterator for I {}
+
+/// An iterator that lazily refines the items of another iterator, created by
+/// [refine_each](RefineIterator::refine_each).
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan};
+///
+/// let mut dead_letters = Vec::new();
+/// let total: u32 = (0..1_000_000u32)
+///     .refine_each::<LessThan<10>>()
+///     .filter_map(|record| record.map_err(|rejected| dead_letters.push(rejected)).ok())
+///     .map(|value| *value)
+///     .sum();
+/// assert_eq!(total, 45);
+/// assert_eq!(dead_letters.len(), 999_990);
+/// assert_eq!(dead_letters[0].0, 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RefineIter<I, P> {
+    iter: I,
+    index: usize,
+    predicate: PhantomData<P>,
+}
+
+impl<I: Iterator, P: Predicate<I::Item>> Iterator for RefineIter<I, P> {
+    type Item = Result<Refinement<I::Item, P>, (I::Item, RefinementError)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some(Refinement::refine_or_return(item).map_err(|(item, err)| (item, at(err, index))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator, P: Predicate<I::Item>> ExactSizeIterator for RefineIter<I, P> {}
+
+impl<I: FusedIterator, P: Predicate<I::Item>> FusedIterator for RefineIter<I, P> {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::{string::ToString, vec, vec::Vec};
+
+    use crate::{boundable::unsigned::*, error::ErrorKind, iter::*};
+    #[cfg(feature = "alloc")]
+    use crate::{error::PathSegment, string::Trimmed, Refinement};
+
+    #[test]
+    fn test_refine_each() {
+        let mut iter = [1u8, 12, 3].into_iter().refine_each::<LessThan<10>>();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next().map(|item| item.map(|v| *v)), Some(Ok(1)));
+        let (rejected, err) = iter.next().unwrap().unwrap_err();
+        assert_eq!(rejected, 12);
+        assert!(matches!(err.kind(), ErrorKind::LessThan(_)));
+        #[cfg(feature = "alloc")]
+        assert_eq!(err.path(), &[PathSegment::Index(1)]);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_refine_all() {
+        let values = vec![1u8, 2, 3]
+            .into_iter()
+            .refine_all::<Refinement<u8, LessThan<4>>>()
+            .unwrap();
+        assert_eq!(
+            values.iter().map(|v| **v).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let err = vec![1u8, 4, 5]
+            .into_iter()
+            .refine_all::<Refinement<u8, LessThan<4>>>()
+            .unwrap_err();
+        assert_eq!(err.path(), &[PathSegment::Index(1)]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_partition_refined() {
+        let (valid, invalid) = ["a", " b", "c", "d "]
+            .into_iter()
+            .partition_refined::<Trimmed>();
+        assert_eq!(
+            valid.iter().map(|v| **v).collect::<Vec<_>>(),
+            vec!["a", "c"]
+        );
+        assert_eq!(invalid.len(), 2);
+        assert_eq!(invalid[1].0, "d ");
+        assert_eq!(
+            invalid[1].1.to_string(),
+            "refinement violated: element [3]: must not start or end with whitespace"
+        );
+    }
+}
@@ -55,7 +55,7 @@
 //!
 //! assert!(Frobnicator::new("Good name".to_string(), 99).is_ok());
 //! assert_eq!(Frobnicator::new("Bad name, too long".to_string(), 99).unwrap_err().to_string(),
-//!            "refinement violated: must be greater than or equal to 1 and must be less than or equal to 10");
+//!            "refinement violated: must be less than or equal to 10");
 //! assert_eq!(Frobnicator::new("Good name".to_string(), 123).unwrap_err().to_string(),
 //!            "refinement violated: must be less than or equal to 100");
 //! ```
@@ -98,8 +98,9 @@
 //! Because all [StatefulPredicate] are also [Predicate], you can move seamlessly between stateful and stateless
 //! certification without the underlying refinement type being aware of how it was materialized. This means that
 //! the `serde` feature functions transparently with stateful predicates, but it's important to be aware that the
-//! `Serialize` and `Deserialize` implementations will use the stateless variants (as there's no way to easily
-//! "inject" the predicate state into the serde process).
+//! `Deserialize` implementation will use the stateless variant (as there's no way to "inject" the predicate state
+//! into the derived serde process). To deserialize using the state of a predicate, use the
+//! [WithState](stateful::WithState) seed instead.
 //!
 //! The `regex` feature provides a good motivation for when it could make sense to use [StatefulRefinementOps]; compiling
 //! the regular expression can be an expensive operation, often more expensive than certifying the predicate itself. We
@@ -120,6 +121,25 @@
 //! assert!(OopsAllZs::refine_with_state(&all_zs, "zazzy".to_string()).is_err());
 //! ```
 //!
+//! State is also useful for predicates whose parameters are only known at runtime, such as bounds loaded from
+//! configuration. The runtime-bound predicates in [boundable::unsigned] and [boundable::signed] (e.g.
+//! [DynClosedInterval](boundable::unsigned::DynClosedInterval)) take their bounds at construction:
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::DynClosedInterval};
+//!
+//! type Port = Refinement<u16, DynClosedInterval>;
+//!
+//! // e.g. read from a configuration file
+//! let (min, max) = (1024, 49151);
+//! let range = DynClosedInterval::new(min, max);
+//! assert!(Port::refine_with_state(&range, 8080).is_ok());
+//! assert_eq!(
+//!     Port::refine_with_state(&range, 80).unwrap_err().to_string(),
+//!     "refinement violated: must be greater than or equal to 1024"
+//! );
+//! ```
+//!
 //! ## Named refinement
 //!
 //! As you can see in the error messages in the first example, there are two possible fields that could have led to the error in refinement,
@@ -127,7 +147,9 @@
 //! when using libraries like [serde_path_to_error](https://docs.rs/serde_path_to_error/latest/serde_path_to_error/), this
 //! can be important functionality to have in your own error messages if you're using basic serde functionality or raw types.
 //!
-//! If this is something that you need, consider using [Named], or [NamedSerde] if using `serde`.
+//! If this is something that you need, consider using [Named], or [NamedSerde] if using `serde`. When names are only
+//! known at runtime, [DynNamed] accepts the name at the point of refinement instead. When deserializing, the
+//! [de](crate::de::from_deserializer_named) module can instead name errors after the fields that they occurred in.
 //!
 //! ```
 //! use refined::{prelude::*, boundable::unsigned::{LessThanEqual, ClosedInterval}};
@@ -158,11 +180,14 @@
 //!
 //! assert!(Frobnicator::new("Good name".to_string(), 99).is_ok());
 //! assert_eq!(Frobnicator::new("Bad name, too long".to_string(), 99).unwrap_err().to_string(),
-//!            "refinement violated: name must be greater than or equal to 1 and must be less than or equal to 10");
+//!            "refinement violated: name must be less than or equal to 10");
 //! assert_eq!(Frobnicator::new("Good name".to_string(), 123).unwrap_err().to_string(),
 //!            "refinement violated: size must be less than or equal to 100");
 //! ```
 //!
+//! Similarly, [WithMessage] replaces the description of the predicate in error messages with text of your choosing,
+//! which is useful when errors are shown directly to end users.
+//!
 //! ## Serde support
 //!
 //! Support for serde is about as automatic as you can get when the `serde` feature is enabled.
@@ -185,6 +210,11 @@
 //! assert_eq!(bad.unwrap_err().to_string(), "refinement violated: must be less than 100 at line 1 column 33");
 //! ```
 //!
+//! Refinements are serialized exactly as their base values and deserialize by asking for the base type, so they make no
+//! assumptions about the data format. Compact binary formats that aren't self-describing, such as bincode and postcard,
+//! work just as JSON does, including in `no_std` environments with the `alloc` feature. Note that some formats (postcard
+//! among them) discard the message of custom errors, so only the fact that a refinement failed is reported.
+//!
 //! If using named refinement, only [NamedSerde] will work in serde implementations:
 //!
 //! ```
@@ -301,7 +331,23 @@
 //!   [And](boolean::And) and [Or](boolean::Or)
 //! * [character] contains refinements of [char]. Examples include [IsLowercase](character::IsLowercase) and [IsWhitespace](character::IsWhitespace)
 //! * [string] contains refinements of any type that implements [AsRef\<str\>](AsRef). Examples include [Contains](string::Contains),
-//!   [Trimmed](string::Trimmed), and [Regex](string::Regex)
+//!   [Trimmed](string::Trimmed), [NoWhitespace](string::NoWhitespace), and [Regex](string::Regex)
+//! * [collection] contains element-wise refinements of collections. Examples include [All](collection::All) and
+//!   [AllValues](collection::AllValues), along with [Sorted](collection::Sorted)
+//! * [normalize] contains predicate adapters that canonicalize values before testing them. Examples include
+//!   [Trim](normalize::Trim) and [Lowercase](normalize::Lowercase)
+//! * [project] contains the [On](project::On) adapter, which refines a value by a predicate on a part of it,
+//!   such as a field of a struct or an element of a pair
+//! * [stateful] contains stateful predicates that are built from runtime data, such as an
+//!   [Allowlist](stateful::Allowlist) of values read from a file, and combinators of stateful predicates
+//! * [dynamic] contains object-safe predicates that can be selected and composed at runtime, along with
+//!   [BoxedRefinement](dynamic::BoxedRefinement)
+//! * [iter] contains adapters for refining the items of iterators in bulk, such as
+//!   [refine_all](iter::RefineIterator::refine_all), or lazily with [RefineIter](iter::RefineIter)
+//! * [containers] contains collections that maintain a refinement through mutation, such as
+//!   [BoundedVec](containers::BoundedVec), [BoundedString](containers::BoundedString),
+//!   [NonEmptyVec](containers::NonEmptyVec), [SortedVec](containers::SortedVec) and
+//!   [RefinedMap](containers::RefinedMap)
 //!
 //! # Features
 //!
@@ -321,6 +367,14 @@
 //! This functionality was actually my main motivation for writing the crate in the first place, but technically
 //! the serde dependency is not required for the core functionality of the trait, so it can be disabled.
 //!
+//! Refinements are validated when they are deserialized, but never when they are serialized: a refinement is valid by
+//! construction, so serializing it writes the underlying value directly without testing the predicate again.
+//! With the `std` feature, [de] can deserialize a document while collecting every violated refinement rather than
+//! only the first, or name refinement errors after the fields that they occurred in.
+//!
+//! Refinements are serialized transparently, exactly as their underlying values. With the `alloc` feature, [Tagged]
+//! instead serializes a refinement along with the name of its predicate.
+//!
 //! ## `alloc`
 //!
 //! Enabling alloc allows the use of allocators without requiring `std`. This flag is useful only when `std` is
@@ -331,6 +385,196 @@
 //! Enabling regex allows the use of the [Regex](string::Regex) predicate. This carries a dependency on the [regex] crate
 //! and also requires the `alloc` feature.
 //!
+//! ## `regex-cache`
+//!
+//! Enabling regex-cache compiles the regular expression of each [Regex](string::Regex) predicate only once, caching it
+//! for the lifetime of the program. Stateless refinement (including deserialization with `serde`) then performs as well
+//! as stateful refinement. This requires the `regex` and `std` features.
+//!
+//! ## `memchr`
+//!
+//! Enabling memchr accelerates the scanning of hot string predicates: [Contains](string::Contains) with the vectorized
+//! substring search of [memchr], and [NoWhitespace](string::NoWhitespace) with a single scan that vectorizes. The
+//! predicates behave identically with or without this feature; the `string` benchmark compares them.
+//!
+//! ## `miette`
+//!
+//! Enabling miette implements [miette::Diagnostic] for [RefinementError], so that applications using the [miette] crate
+//! get diagnostic codes, help text, and labeled values for refinement failures. This also requires the `std` feature.
+//!
+//! ## `tracing`
+//!
+//! Enabling tracing emits a [tracing] event every time that a refinement fails, allowing the violated invariants of
+//! a running application to be observed without instrumenting each call site. See [trace] for details of the emitted
+//! events and for how tracing can be disabled for individual types.
+//!
+//! ## `macros`
+//!
+//! Enabling macros provides procedural macros that remove boilerplate from structs of refined fields. For example,
+//! [named_fields] wraps every field of a struct in a [Named] refinement named after the field, and
+//! [`#[derive(Refined)]`](derive@Refined) generates a constructor that refines every field and reports all of the
+//! resulting [errors](error::RefinementErrors). [refined_args] refines annotated function arguments on entry to the
+//! function, and [`#[derive(Invariants)]`](derive@Invariants) declares a predicate for invariants that relate several
+//! fields of a struct, such as `start <= end`. This carries a dependency on the `refined-macros` crate and also requires the `alloc` feature.
+//!
+//! ## `schemars`
+//!
+//! Enabling schemars implements [schemars::JsonSchema] for [Refinement], describing a refined type as the schema of its
+//! base type narrowed by the constraints of its predicate; for example, [boundable::unsigned::LessThan] becomes a
+//! `maximum` for numbers or a `maxLength` for strings. Predicates report their constraints through the
+//! [schema::Describe] trait. This also requires the `alloc` feature.
+//!
+//! ## `utoipa`
+//!
+//! Enabling utoipa implements [utoipa::PartialSchema] and [utoipa::ToSchema] for [Refinement] using the same
+//! [constraints](schema::Constraints) as the `schemars` feature, so that services documented with [utoipa] expose the
+//! bounds, lengths, and patterns of their refined types in generated OpenAPI documents. Refined fields of derived
+//! schemas are best marked `#[schema(inline)]`. This also requires the `std` feature.
+//!
+//! ## `validator`
+//!
+//! Enabling validator eases migration from the [validator] crate. [Refinement] implements [validator::Validate]
+//! (trivially, since refinements are valid by construction), and forwards [validator::ValidateLength] and
+//! [validator::ValidateRange] to the refined value so that both styles of validation can be mixed on the same struct.
+//! [RefinementError] and [error::RefinementErrors] convert into [validator::ValidationError] and
+//! [validator::ValidationErrors] respectively. This also requires the `serde` and `std` features.
+//!
+//! ## `axum`
+//!
+//! Enabling axum provides [extractors](extract::axum) for [axum](https://docs.rs/axum) that deserialize JSON bodies,
+//! query strings, and route parameters while [collecting](de) every violated refinement, rejecting invalid requests
+//! with a `422 Unprocessable Entity` response that lists the errors. This also requires the `serde` and `std` features.
+//!
+//! ## `actix`
+//!
+//! Enabling actix provides [extractors](extract::actix) for [actix-web](https://docs.rs/actix-web) analogous to those
+//! of the `axum` feature. The response to a rejected request can be customized with
+//! [extract::actix::RefinedConfig]. This also requires the `serde` and `std` features.
+//!
+//! ## `rocket`
+//!
+//! Enabling rocket implements [rocket::request::FromParam], [rocket::request::FromSegments], and
+//! [rocket::form::FromFormField] for [Refinement] wherever the base type implements them, so that route parameters and
+//! form fields are refined at the boundary of a [Rocket](https://rocket.rs) application. Route parameters that violate
+//! their refinement fail with a [ParamError], while form fields report the predicate's message as a validation error.
+//! This also requires the `std` feature.
+//!
+//! ## `clap`
+//!
+//! Enabling clap implements [clap::builder::ValueParserFactory] for [Refinement] whenever the base type implements
+//! [FromStr](core::str::FromStr), so that command line arguments are refined by their type and rejected with the
+//! predicate's message. See [RefinedValueParser]. This also requires the `std` feature.
+//!
+//! ## `sqlx`
+//!
+//! Enabling sqlx implements [sqlx::Type], [sqlx::Encode], and [sqlx::Decode] for [Refinement], delegating to the base
+//! type. Decoding refines the loaded value, so that invariants are re-established for data read from the database and
+//! rows that violate them fail to decode with the [RefinementError]. This also requires the `std` feature.
+//!
+//! ## `diesel`
+//!
+//! Enabling diesel implements [diesel::expression::AsExpression], [diesel::serialize::ToSql],
+//! [diesel::deserialize::FromSql], and [diesel::Queryable] for [Refinement], delegating to the base type. Loading
+//! refines the value, so that rows that violate the predicate fail to deserialize. This also requires the `std`
+//! feature.
+//!
+//! ## `postgres-types`
+//!
+//! Enabling postgres-types implements [postgres_types::ToSql] and [postgres_types::FromSql] for [Refinement], delegating
+//! to the base type, so that refined values can be used directly with `tokio-postgres` and `postgres`. Reading a value
+//! refines it, so that rows that violate the predicate fail to convert. This also requires the `std` feature.
+//!
+//! ## `sea-orm`
+//!
+//! Enabling sea-orm implements [sea_orm::TryGetable], [sea_orm::sea_query::ValueType], and [sea_orm::IntoActiveValue]
+//! for [Refinement], delegating to the base type, so that SeaORM entities can use refined column types directly. Loading
+//! refines the value, so that rows that violate the predicate fail to load. This also requires the `std` feature.
+//!
+//! ## `redis`
+//!
+//! Enabling redis implements [redis::ToRedisArgs] and [redis::FromRedisValue] for [Refinement], delegating to the base
+//! type. Reading a value refines it, so that data written by other services that violates the predicate fails to
+//! parse. This also requires the `std` feature.
+//!
+//! ## `bson`
+//!
+//! Enabling bson implements `From<Refinement<T, P>>` for [bson::Bson], so that refined values can be used directly in
+//! `doc!`. Refinements round-trip through both [bson::to_bson]/[bson::from_bson] and the raw [bson::to_vec]/
+//! [bson::from_slice] encoding using the `serde` support. BSON has no unsigned integer types, so unsigned base types are
+//! stored as signed integers. This also requires the `serde` and `std` features.
+//!
+//! ## `async-graphql`
+//!
+//! Enabling async-graphql implements [async_graphql::InputType] and [async_graphql::ScalarType] for [Refinement].
+//! Refinements are custom scalars named after their full type and described by the [constraints](schema::Constraints)
+//! of their predicate. Input values are parsed as the base type and refined, so that GraphQL input objects reject values
+//! that violate the predicate with its message. This also requires the `std` feature.
+//!
+//! ## `proptest`
+//!
+//! Enabling proptest implements [proptest::arbitrary::Arbitrary] for [Refinement], so that `Refinement::<T, P>::arbitrary()`
+//! generates only valid values. The [strategy] module derives strategies from the [constraints](schema::Constraints) of
+//! the predicate, falling back to filtering for predicates that constraints can't express. This also requires the `std`
+//! feature.
+//!
+//! ## `quickcheck`
+//!
+//! Enabling quickcheck implements [quickcheck::Arbitrary] for [Refinement], generating values of the base type within the
+//! [constraints](schema::Constraints) of the predicate (see [ArbitraryWithin]) and shrinking them as the base type does,
+//! skipping values that violate the predicate. This also requires the `std` feature.
+//!
+//! ## `rand`
+//!
+//! Enabling rand implements [rand::distr::Distribution] for refinements of integers by [Convex] predicates, such as the
+//! interval predicates, so that `rng.random::<Refinement<u8, ClosedInterval<1, 10>>>()` samples uniformly from the valid
+//! values. These refinements also implement [rand::distr::uniform::SampleUniform], so that they can be sampled from a range
+//! of refinements with `rng.random_range(low..=high)` without refining the result again.
+//!
+//! ## `rayon`
+//!
+//! Enabling rayon provides [ParRefine], which refines every element of a slice or vector in parallel with [rayon].
+//! Since refinements have the same layout as their base type, the collection is reinterpreted as refinements rather
+//! than copied, which makes validating large batches with expensive predicates such as regular expressions
+//! embarrassingly parallel. This also requires the `std` feature.
+//!
+//! ## `fake`
+//!
+//! Enabling fake implements [fake::Dummy] for [Refinement] with every faker of its base type, keeping the first faked
+//! value that satisfies the predicate. Structs of refinements can derive `Dummy` to seed test databases with valid
+//! values, choosing fakers that mostly produce them, such as `SafeEmail()` for emails or `1..=10` for ranged numbers.
+//! This also requires the `std` feature.
+//!
+//! ## `kani`
+//!
+//! Enabling kani annotates [RefinementOps::refine] and [RefinementOps::modify] with
+//! [Kani](https://model-checking.github.io/kani/) function contracts derived from their predicates, requires the results
+//! of refinement arithmetic and implication to satisfy their predicates, and implements `kani::Arbitrary` for
+//! [Refinement] by assuming the predicate of any value of the base type.
+//! Proof harnesses can then take refinements as inputs and prove that user code maintains their invariants. The
+//! annotations only exist when building with `cargo kani`, which provides the `kani` crate; contracts are checked with
+//! `-Z function-contracts`.
+//!
+//! ## `pyo3`
+//!
+//! Enabling pyo3 implements [pyo3::FromPyObject] and [pyo3::IntoPyObject] for [Refinement], delegating to the base type.
+//! Extracting a value refines it, raising `ValueError` with the message of the predicate if it is violated, so that
+//! Python bindings around Rust cores keep their invariants at the FFI boundary. Refinement errors also convert to
+//! [pyo3::PyErr], so they can be raised from `#[pyfunction]`s with `?`. This also requires the `std` feature.
+//!
+//! ## `wasm`
+//!
+//! Enabling wasm implements `From<Refinement<T, P>>` for [wasm_bindgen::JsValue] and `TryFrom<JsValue>` for [Refinement],
+//! which converts the value to the base type with [serde_wasm_bindgen] and refines it, so that refined types can cross the
+//! wasm-bindgen boundary. Refinement errors convert to JavaScript `Error`s named `RefinementError` whose `errors`
+//! property holds the serialized errors, so that they're thrown as exceptions carrying the structured message. This
+//! also requires the `serde` and `std` features.
+//!
+//! ## `proto`
+//!
+//! Enabling proto provides the [proto] module, which converts the plain fields of protobuf messages (such as those
+//! generated by prost) to and from refinements, and `#[derive(FromProto)]`, which refines every field of a message into
+//! a domain struct and reports each failure named after the path of its field. This also requires the `macros` feature.
+//!
 //! ## `optimized`
 //!
 //! Enabling optimized turns on [unsafe optimizations](https://github.com/jkaye2012/refined/issues/9) that allow the compiler
@@ -368,6 +612,26 @@
 //!
 //! Similarly, the signed variants are [implication::SignedMin], [implication::SignedMax], and [implication::SignedMinMax].
 //!
+//! Unsigned refinements also support [core::ops::Rem] against any type that satisfies [implication::UnsignedMax],
+//! producing a [boundable::unsigned::LessThan] bounded by the greatest value of the divisor regardless of the dividend's
+//! bounds. Similarly, [core::ops::BitAnd] produces a [boundable::unsigned::LessThanEqual] bounded by the greatest value of
+//! the mask, while [core::ops::BitOr], [core::ops::Shl] and [core::ops::Shr] compute the greatest value of their result
+//! from the bounds of both operands.
+//!
+//! Constant operands are provided by [implication::UnsignedConst] and [implication::SignedConst], which shift or scale
+//! the bounds of a refinement as a refinement by `Equals` would: `Refinement<u8, LessThan<100>>` plus
+//! `UnsignedConst::<5>` is a `Refinement<u8, LessThan<105>>`.
+//!
+//! Refinements of integers of different widths, such as `Refinement<u8, LessThan<100>>` and
+//! `Refinement<u16, LessThan<1000>>`, can be operated on together: the narrower operand is widened losslessly and the
+//! result is computed in the wider type.
+//!
+//! The compound assignment operators, such as [core::ops::AddAssign], are implemented wherever the corresponding
+//! operator produces a refinement by the same predicate, so that `total += delta` keeps `total` refined.
+//!
+//! Signed intervals support [core::ops::Neg], which negates and swaps their bounds, so that negating a
+//! [boundable::signed::OpenClosedInterval] produces a [boundable::signed::ClosedOpenInterval].
+//!
 //! See the examples above for more intuition.
 #![cfg_attr(
     feature = "implication",
@@ -380,29 +644,63 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(feature = "alloc")]
-use core::fmt::Display;
-
-#[cfg(feature = "alloc")]
-use thiserror::Error;
-
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use error::ErrorKind;
+
 pub mod boolean;
 pub mod boundable;
 pub mod character;
+pub mod collection;
+#[doc(cfg(feature = "alloc"))]
+#[cfg(feature = "alloc")]
+pub mod containers;
+#[doc(cfg(all(feature = "serde", feature = "std")))]
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod de;
+#[doc(cfg(feature = "alloc"))]
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+pub mod error;
+#[doc(cfg(feature = "alloc"))]
+#[cfg(feature = "alloc")]
+pub mod explain;
+#[doc(cfg(any(feature = "actix", feature = "axum")))]
+#[cfg(any(feature = "actix", feature = "axum"))]
+pub mod extract;
+pub mod info;
+pub mod iter;
+pub mod normalize;
 pub mod prelude;
+pub mod project;
+#[doc(cfg(feature = "proto"))]
+#[cfg(feature = "proto")]
+pub mod proto;
+pub mod schema;
+pub mod stateful;
+#[doc(cfg(feature = "proptest"))]
+#[cfg(feature = "proptest")]
+pub mod strategy;
 #[doc(cfg(feature = "alloc"))]
 #[cfg(feature = "alloc")]
 pub mod string;
+#[doc(cfg(feature = "tracing"))]
+#[cfg(feature = "tracing")]
+pub mod trace;
 
 mod refinement;
 pub use refinement::*;
 
+pub use error::RefinementError;
+
 pub use boundable::signed::SignedBoundable;
 pub use boundable::unsigned::UnsignedBoundable;
 
+#[doc(cfg(feature = "macros"))]
+#[cfg(feature = "macros")]
+pub use refined_macros::*;
+
 #[doc(cfg(feature = "implication"))]
 #[cfg(feature = "implication")]
 pub mod implication;
@@ -444,6 +742,416 @@ macro_rules! type_string {
     };
 }
 
+/// Creates a nominal newtype wrapping a refinement.
+///
+/// Type aliases of [Refinement] are structural: two aliases with the same base type and predicate
+/// are interchangeable. `refinement_newtype!` instead creates a distinct struct wrapping the
+/// refinement, so that values of identically refined types cannot be mixed up.
+///
+/// The generated type implements [RefinementOps], [Deref](core::ops::Deref) to the refined value,
+/// [AsRef] to the wrapped refinement, and conversions to and from the wrapped refinement. It also
+/// provides `new` and `into_inner` inherent functions. Any attributes (including derives) are
+/// applied to the generated struct; because the struct has a single field, deriving `Serialize`
+/// and `Deserialize` with `#[serde(transparent)]` forwards serialization to the refinement.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan, refinement_newtype};
+///
+/// refinement_newtype!(
+///     /// The age of a person, in years.
+///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///     pub Age(Refinement<u8, LessThan<130>>)
+/// );
+///
+/// refinement_newtype!(
+///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///     pub Score(Refinement<u8, LessThan<130>>)
+/// );
+///
+/// fn birthday(age: Age) -> Result<Age, RefinementError> {
+///     age.modify(|age| age + 1)
+/// }
+///
+/// let age = Age::new(41).unwrap();
+/// assert_eq!(*birthday(age).unwrap(), 42);
+/// assert!(Score::new(130).is_err());
+/// ```
+///
+/// Passing a `Score` where an `Age` is expected fails to compile:
+///
+/// ```compile_fail
+/// # use refined::{prelude::*, boundable::unsigned::LessThan, refinement_newtype};
+/// # refinement_newtype!(pub Age(Refinement<u8, LessThan<130>>));
+/// # refinement_newtype!(pub Score(Refinement<u8, LessThan<130>>));
+/// fn birthday(age: Age) -> Result<Age, RefinementError> {
+///     age.modify(|age| age + 1)
+/// }
+///
+/// birthday(Score::new(41).unwrap());
+/// ```
+#[macro_export]
+macro_rules! refinement_newtype {
+    ($(#[$meta:meta])* $vis:vis $name:ident($inner:ty) $(;)?) => {
+        $(#[$meta])*
+        $vis struct $name($inner);
+
+        impl $name {
+            /// Attempts to refine a runtime value.
+            #[allow(dead_code)]
+            $vis fn new(
+                value: <$inner as $crate::RefinementOps>::T,
+            ) -> ::core::result::Result<Self, $crate::RefinementError> {
+                <Self as $crate::RefinementOps>::refine(value)
+            }
+
+            /// Unwraps the underlying refinement.
+            #[allow(dead_code)]
+            $vis fn into_inner(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl ::core::ops::Deref for $name {
+            type Target = <$inner as $crate::RefinementOps>::T;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::AsRef<$inner> for $name {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $inner {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::Refined<<$inner as $crate::RefinementOps>::T>>
+            for $name
+        {
+            type Error = $crate::RefinementError;
+
+            fn try_from(
+                value: $crate::Refined<<$inner as $crate::RefinementOps>::T>,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                <$inner as ::core::convert::TryFrom<_>>::try_from(value).map(Self)
+            }
+        }
+
+        impl $crate::RefinementOps for $name {
+            type T = <$inner as $crate::RefinementOps>::T;
+
+            fn take(self) -> Self::T {
+                $crate::RefinementOps::take(self.0)
+            }
+
+            fn extract(self) -> Self::T {
+                $crate::RefinementOps::take(self.0)
+            }
+        }
+    };
+}
+
+/// Creates a named alias for a (potentially complex) predicate.
+///
+/// Deeply nested combinator types quickly become unwieldy in signatures. `predicate_alias!`
+/// declares a new predicate type `$name` that behaves exactly like `$inner`, optionally replacing
+/// its error message. The alias can be used anywhere that `$inner` could be used as a predicate.
+///
+/// Note that an alias is a distinct type, so [implication](crate::implication) relationships of
+/// the aliased predicate do not carry over to the alias.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boolean::And, boundable::unsigned::ClosedInterval, predicate_alias, string::Trimmed};
+///
+/// predicate_alias!(
+///     /// A valid username.
+///     pub Username, And<Trimmed, ClosedInterval<3, 32>>, "must be a valid username"
+/// );
+///
+/// type User = Refinement<String, Username>;
+///
+/// assert!(User::refine("alice".to_string()).is_ok());
+/// assert_eq!(
+///     User::refine(" al ".to_string()).unwrap_err().to_string(),
+///     "refinement violated: must be a valid username"
+/// );
+/// ```
+#[macro_export]
+macro_rules! predicate_alias {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $inner:ty $(,)?) => {
+        $crate::predicate_alias!(@define $(#[$meta])* $vis $name, $inner);
+
+        impl<T> $crate::Predicate<T> for $name
+        where
+            $inner: $crate::Predicate<T>,
+        {
+            const TRACE: bool = <$inner as $crate::Predicate<T>>::TRACE;
+
+            fn test(value: &T) -> bool {
+                <$inner as $crate::Predicate<T>>::test(value)
+            }
+
+            fn error() -> $crate::ErrorMessage {
+                <$inner as $crate::Predicate<T>>::error()
+            }
+
+            fn normalize(value: T) -> T {
+                <$inner as $crate::Predicate<T>>::normalize(value)
+            }
+
+            fn kind() -> $crate::error::ErrorKind {
+                <$inner as $crate::Predicate<T>>::kind()
+            }
+
+            fn violation(value: &T) -> $crate::RefinementError {
+                <$inner as $crate::Predicate<T>>::violation(value)
+            }
+
+            $crate::__explain_alias!($inner, T);
+
+            unsafe fn optimize(value: &T) {
+                <$inner as $crate::Predicate<T>>::optimize(value)
+            }
+        }
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident, $inner:ty, $message:expr $(,)?) => {
+        $crate::predicate_alias!(@define $(#[$meta])* $vis $name, $inner);
+
+        impl<T> $crate::Predicate<T> for $name
+        where
+            $inner: $crate::Predicate<T>,
+        {
+            const TRACE: bool = <$inner as $crate::Predicate<T>>::TRACE;
+
+            fn test(value: &T) -> bool {
+                <$inner as $crate::Predicate<T>>::test(value)
+            }
+
+            fn error() -> $crate::ErrorMessage {
+                $crate::ErrorMessage::from($message)
+            }
+
+            fn normalize(value: T) -> T {
+                <$inner as $crate::Predicate<T>>::normalize(value)
+            }
+
+            fn kind() -> $crate::error::ErrorKind {
+                <$inner as $crate::Predicate<T>>::kind()
+            }
+
+            fn violation(value: &T) -> $crate::RefinementError {
+                <$inner as $crate::Predicate<T>>::violation(value)
+                    .with_message(<Self as $crate::Predicate<T>>::error())
+            }
+
+            $crate::__explain_alias!($inner, T, <Self as $crate::Predicate<T>>::error());
+
+            unsafe fn optimize(value: &T) {
+                <$inner as $crate::Predicate<T>>::optimize(value)
+            }
+        }
+    };
+    (@define $(#[$meta:meta])* $vis:vis $name:ident, $inner:ty) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        $vis struct $name;
+    };
+}
+
+/// Asserts that a value can be refined as the [Refinement] type `$refinement`.
+///
+/// On failure, the panic message includes the value along with its
+/// [explanation](Refinement::explain), showing which nested predicates it failed. Like
+/// [assert!], a custom message may follow the type. The value must implement [Clone] and
+/// [Debug](core::fmt::Debug).
+///
+/// # Example
+///
+/// ```
+/// use refined::{assert_not_refines, assert_refines, prelude::*, boundable::unsigned::ClosedInterval};
+///
+/// type Percent = Refinement<u8, ClosedInterval<0, 100>>;
+///
+/// assert_refines!(42, Percent);
+/// assert_not_refines!(142, Percent, "percentages are capped at {}", 100);
+/// ```
+///
+/// ```should_panic
+/// # use refined::{assert_refines, prelude::*, boundable::unsigned::ClosedInterval};
+/// # type Percent = Refinement<u8, ClosedInterval<0, 100>>;
+/// // assertion `142` refines `Percent` failed
+/// //   value: 142
+/// // failed: conjunction
+/// //   passed: must be greater than or equal to 0 (measured 142)
+/// //   failed: must be less than or equal to 100 (measured 142)
+/// assert_refines!(142, Percent);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_refines {
+    ($value:expr, $refinement:ty $(,)?) => {
+        match &$value {
+            value => {
+                let explanation = <$refinement>::explain(value);
+                if !explanation.satisfied {
+                    ::core::panic!(
+                        "assertion `{}` refines `{}` failed\n  value: {:?}\n{}",
+                        ::core::stringify!($value),
+                        ::core::stringify!($refinement),
+                        value,
+                        explanation
+                    );
+                }
+            }
+        }
+    };
+    ($value:expr, $refinement:ty, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                let explanation = <$refinement>::explain(value);
+                if !explanation.satisfied {
+                    ::core::panic!(
+                        "assertion `{}` refines `{}` failed: {}\n  value: {:?}\n{}",
+                        ::core::stringify!($value),
+                        ::core::stringify!($refinement),
+                        ::core::format_args!($($arg)+),
+                        value,
+                        explanation
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a value can't be refined as the [Refinement] type `$refinement`.
+///
+/// On failure, the panic message includes the value along with its
+/// [explanation](Refinement::explain), showing that it satisfied every nested predicate. See
+/// [assert_refines!] for an example.
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_not_refines {
+    ($value:expr, $refinement:ty $(,)?) => {
+        match &$value {
+            value => {
+                let explanation = <$refinement>::explain(value);
+                if explanation.satisfied {
+                    ::core::panic!(
+                        "assertion `{}` does not refine `{}` failed\n  value: {:?}\n{}",
+                        ::core::stringify!($value),
+                        ::core::stringify!($refinement),
+                        value,
+                        explanation
+                    );
+                }
+            }
+        }
+    };
+    ($value:expr, $refinement:ty, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                let explanation = <$refinement>::explain(value);
+                if explanation.satisfied {
+                    ::core::panic!(
+                        "assertion `{}` does not refine `{}` failed: {}\n  value: {:?}\n{}",
+                        ::core::stringify!($value),
+                        ::core::stringify!($refinement),
+                        ::core::format_args!($($arg)+),
+                        value,
+                        explanation
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Forwards [Predicate::explain] from an alias to the aliased predicate, optionally replacing
+/// its message. Expands to nothing without the `alloc` feature, where `explain` doesn't exist.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __explain_alias {
+    ($inner:ty, $t:ty) => {
+        fn explain(value: &$t) -> $crate::explain::Explanation {
+            <$inner as $crate::Predicate<$t>>::explain(value)
+        }
+    };
+    ($inner:ty, $t:ty, $message:expr) => {
+        fn explain(value: &$t) -> $crate::explain::Explanation {
+            let mut explanation = <$inner as $crate::Predicate<$t>>::explain(value);
+            explanation.message = $message;
+            explanation
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __explain_alias {
+    ($($tokens:tt)*) => {};
+}
+
+/// Defines a [Predicate] from a pure function and an error message.
+///
+/// `$test` may be any expression that coerces to `fn(&$t) -> bool`, such as a non-capturing
+/// closure or the path of a function. As with any predicate, it **must** be pure; see
+/// [Predicate::test].
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, predicate};
+///
+/// predicate!(
+///     /// An even number.
+///     pub EvenU32, u32, |v| v % 2 == 0, "must be even"
+/// );
+///
+/// assert!(Refinement::<u32, EvenU32>::refine(4).is_ok());
+/// assert_eq!(
+///     Refinement::<u32, EvenU32>::refine(5).unwrap_err().to_string(),
+///     "refinement violated: must be even"
+/// );
+/// ```
+#[macro_export]
+macro_rules! predicate {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $t:ty, $test:expr, $message:expr $(,)?) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        $vis struct $name;
+
+        impl $crate::Predicate<$t> for $name {
+            fn test(value: &$t) -> bool {
+                let test: fn(&$t) -> bool = $test;
+                test(value)
+            }
+
+            fn error() -> $crate::ErrorMessage {
+                $crate::ErrorMessage::from($message)
+            }
+        }
+    };
+}
+
 #[cfg(not(feature = "alloc"))]
 pub type ErrorMessage = &'static str;
 
@@ -452,20 +1160,70 @@ pub type ErrorMessage = alloc::string::String;
 
 /// An assertion that must hold for an instance of a type to be considered refined.
 pub trait Predicate<T> {
+    /// Whether failures of this predicate are reported when the `tracing` feature is enabled.
+    ///
+    /// See [trace] for more information.
+    const TRACE: bool = true;
+
+    /// The inclusive range of bounding values outside of which no value satisfies the predicate,
+    /// if it is statically known.
+    ///
+    /// Refining a value by a predicate whose range is empty, such as `ClosedInterval<10, 5>`,
+    /// fails to compile, since no value could ever be refined by it:
+    ///
+    /// ```compile_fail
+    /// use refined::{prelude::*, boundable::unsigned::OpenInterval};
+    ///
+    /// let _ = Refinement::<u8, OpenInterval<5, 6>>::refine(5);
+    /// ```
+    const BOUNDS: Option<(i128, i128)> = None;
+
     /// Whether a value satisfies the predicate.
     ///
     /// # Correctness
     ///
     /// Implementations of this method **must** be pure functions. They must be infallible and
     /// must always return the same result when provided the same input value. If you have a
-    /// situation that requires impurity to "materialize" a predicate, use the [Default::default]
-    /// implementation of a [StatefulPredicate]. Even then, under no circumstance can the `test`
-    /// function itself be impure.
+    /// situation that requires impurity to "materialize" a predicate, construct a
+    /// [StatefulPredicate] instead. Even then, under no circumstance can the `test` function
+    /// itself be impure.
     fn test(value: &T) -> bool;
 
     /// An error message to display when the predicate doesn't hold.
     fn error() -> ErrorMessage;
 
+    /// Transforms a value into a canonical form before it is tested during refinement.
+    ///
+    /// The default implementation returns the value unchanged. See [normalize] for predicates
+    /// that make use of this.
+    fn normalize(value: T) -> T {
+        value
+    }
+
+    /// The kind of the predicate, used to build structured [errors](RefinementError).
+    fn kind() -> ErrorKind {
+        ErrorKind::Custom
+    }
+
+    /// The error to report when `value` does not satisfy the predicate.
+    ///
+    /// The default implementation combines [kind](Predicate::kind) and [error](Predicate::error);
+    /// the message is not rendered until it is requested.
+    /// Combinators override this to report the errors of their nested predicates.
+    fn violation(_value: &T) -> RefinementError {
+        RefinementError::deferred(Self::kind(), Self::error)
+    }
+
+    /// Explains whether `value` satisfies the predicate and each of its nested predicates.
+    ///
+    /// The default implementation explains the predicate as a whole. Combinators override this to
+    /// explain their nested predicates, and bounds record the bounding value that they measured.
+    #[cfg(feature = "alloc")]
+    #[doc(cfg(feature = "alloc"))]
+    fn explain(value: &T) -> explain::Explanation {
+        explain::Explanation::of::<T, Self>(value)
+    }
+
     /// Applies a potentially unsafe optimization to call sites that can take advantage of
     /// information provided by the predicate. This function is unused by `refined` unless
     /// the `optimized` feature is enabled.
@@ -494,16 +1252,15 @@ pub trait Predicate<T> {
 }
 
 /// A stateful assertion that must hold for an instance of a type to be considered refined.
-pub trait StatefulPredicate<T>: Default + Predicate<T> {
+pub trait StatefulPredicate<T>: Predicate<T> {
     /// Whether a value satisfies the predicate.
     ///
     /// # Correctness
     ///
     /// Implementations of this method **must** be pure functions. They must be infallible and
     /// must always return the same result when provided the same input value. If you have a
-    /// situation that requires impurity to "materialize" a predicate, use the [Default::default]
-    /// implementation to "inject" that logic into the predicate. Even then, under no circumstance
-    /// can the `test` function itself be impure.
+    /// situation that requires impurity to "materialize" a predicate, do so when the predicate is
+    /// constructed. Even then, under no circumstance can the `test` function itself be impure.
     fn test(&self, value: &T) -> bool;
 
     /// An error message to display when the predicate doesn't hold.
@@ -511,6 +1268,15 @@ pub trait StatefulPredicate<T>: Default + Predicate<T> {
         <Self as Predicate<T>>::error()
     }
 
+    /// The error to report when `value` does not satisfy the predicate.
+    ///
+    /// The default implementation combines the stateless [kind](Predicate::kind) with the
+    /// stateful [error](StatefulPredicate::error). Predicates whose kind depends on their state
+    /// should override this.
+    fn violation(&self, _value: &T) -> RefinementError {
+        RefinementError::new(<Self as Predicate<T>>::kind(), self.error())
+    }
+
     /// Applies a potentially unsafe optimization to call sites that can take advantage of
     /// information provided by the predicate. This function is unused by `refined` unless
     /// the `optimized` feature is enabled.
@@ -542,19 +1308,6 @@ pub trait StatefulPredicate<T>: Default + Predicate<T> {
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(transparent))]
 pub struct Refined<T>(T);
 
-/// An [Error] that can result from failed refinement.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "alloc", derive(Error))]
-pub struct RefinementError(ErrorMessage);
-
-#[cfg(feature = "alloc")]
-#[doc(cfg(feature = "alloc"))]
-impl Display for RefinementError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "refinement violated: {}", self.0)
-    }
-}
-
 /// Operations that can be made available on all types of refinement.
 pub trait RefinementOps:
     TryFrom<Refined<Self::T>, Error = RefinementError> + core::ops::Deref<Target = Self::T>
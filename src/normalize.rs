@@ -0,0 +1,191 @@
+//! Normalizing refinement.
+//!
+//! Some predicates are better expressed as "canonicalize the value, then test it" than as a strict
+//! rejection of non-canonical input. [Normalize] pairs a [Transformer] with a [Predicate]: values
+//! are transformed into their canonical form during refinement, and only then tested.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::GreaterThan, normalize::*};
+//!
+//! type Username = Refinement<String, Normalize<Trim, GreaterThan<0>>>;
+//!
+//! let ok = Username::refine("  alice ".to_string()).unwrap();
+//! assert_eq!(*ok, "alice");
+//!
+//! assert!(Username::refine("   ".to_string()).is_err());
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::marker::PhantomData;
+
+use crate::{error::ErrorKind, ErrorMessage, Predicate, RefinementError};
+
+/// A canonicalizing transformation of a value.
+pub trait Transformer<T> {
+    /// Transforms a value into its canonical form.
+    ///
+    /// # Correctness
+    ///
+    /// Implementations must be idempotent, and the result must always satisfy
+    /// [is_canonical](Transformer::is_canonical).
+    fn transform(value: T) -> T;
+
+    /// Whether a value is already in its canonical form.
+    fn is_canonical(value: &T) -> bool;
+}
+
+/// A [Predicate] that canonicalizes values with the [Transformer] `F` before testing them with `P`.
+///
+/// When tested directly, a value only satisfies the predicate if it is already canonical.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Normalize<F, P>(PhantomData<F>, PhantomData<P>);
+
+impl<T, F: Transformer<T>, P: Predicate<T>> Predicate<T> for Normalize<F, P> {
+    const TRACE: bool = P::TRACE;
+
+    fn test(value: &T) -> bool {
+        F::is_canonical(value) && P::test(value)
+    }
+
+    fn error() -> ErrorMessage {
+        P::error()
+    }
+
+    fn normalize(value: T) -> T {
+        P::normalize(F::transform(value))
+    }
+
+    fn kind() -> ErrorKind {
+        P::kind()
+    }
+
+    fn violation(value: &T) -> RefinementError {
+        P::violation(value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> crate::explain::Explanation {
+        let mut explanation = P::explain(value);
+        explanation.satisfied &= F::is_canonical(value);
+        explanation
+    }
+
+    unsafe fn optimize(value: &T) {
+        core::hint::assert_unchecked(Self::test(value));
+    }
+}
+
+/// Removes leading and trailing whitespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Trim;
+
+impl<'a> Transformer<&'a str> for Trim {
+    fn transform(value: &'a str) -> &'a str {
+        value.trim()
+    }
+
+    fn is_canonical(value: &&'a str) -> bool {
+        value.trim() == *value
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Transformer<String> for Trim {
+    fn transform(value: String) -> String {
+        if Self::is_canonical(&value) {
+            value
+        } else {
+            String::from(value.trim())
+        }
+    }
+
+    fn is_canonical(value: &String) -> bool {
+        value.trim() == value
+    }
+}
+
+/// Converts all characters to lowercase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Lowercase;
+
+#[cfg(feature = "alloc")]
+impl Transformer<String> for Lowercase {
+    fn transform(value: String) -> String {
+        value.to_lowercase()
+    }
+
+    fn is_canonical(value: &String) -> bool {
+        value.to_lowercase() == *value
+    }
+}
+
+/// Converts all characters to uppercase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Uppercase;
+
+#[cfg(feature = "alloc")]
+impl Transformer<String> for Uppercase {
+    fn transform(value: String) -> String {
+        value.to_uppercase()
+    }
+
+    fn is_canonical(value: &String) -> bool {
+        value.to_uppercase() == *value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::*, normalize::*, *};
+    #[cfg(feature = "alloc")]
+    use alloc::string::ToString;
+    #[cfg(feature = "alloc")]
+    use boundable::unsigned::*;
+
+    #[test]
+    fn test_trim_str() {
+        type Test<'a> = Refinement<&'a str, Normalize<Trim, True>>;
+        assert_eq!(*Test::refine("  abc  ").unwrap(), "abc");
+    }
+
+    #[test]
+    fn test_normalize_direct_test() {
+        assert!(!<Normalize<Trim, True> as Predicate<&str>>::test(&" abc "));
+        assert!(<Normalize<Trim, True> as Predicate<&str>>::test(&"abc"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_compose_normalizers() {
+        type Test = Refinement<
+            String,
+            And<Normalize<Trim, GreaterThan<0>>, Normalize<Lowercase, LessThan<6>>>,
+        >;
+        assert_eq!(*Test::refine("  HeLLo ".to_string()).unwrap(), "hello");
+        assert!(Test::refine("  ".to_string()).is_err());
+        assert!(Test::refine(" Goodbye ".to_string()).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_disjunction_normalizes() {
+        type Test =
+            Refinement<String, Or<Normalize<Trim, LessThan<3>>, Normalize<Lowercase, True>>>;
+        assert_eq!(*Test::refine(" HeLLo ".to_string()).unwrap(), "hello");
+        type Exclusive =
+            Refinement<String, Xor<Normalize<Trim, LessThan<3>>, Normalize<Lowercase, True>>>;
+        assert_eq!(*Exclusive::refine(" HeLLo ".to_string()).unwrap(), "hello");
+        assert!(Exclusive::refine(" Hi ".to_string()).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_modify_normalizes() {
+        type Test = Refinement<String, Normalize<Uppercase, GreaterThan<0>>>;
+        let value = Test::refine("abc".to_string()).unwrap();
+        let value = value.modify(|s| s + "def").unwrap();
+        assert_eq!(*value, "ABCDEF");
+    }
+}
@@ -1,15 +1,16 @@
 //! Convenience re-exports for the most common `refined` functionality.
 
 pub use crate::{
-    type_string, ErrorMessage, Predicate, Refinement, RefinementError, RefinementOps,
-    StatefulPredicate, StatefulRefinementOps, TypeString,
+    iter::RefineIterator, predicate, predicate_alias, refinement_newtype, type_string,
+    ErrorMessage, Predicate, Refinement, RefinementError, RefinementOps, StatefulPredicate,
+    StatefulRefinementOps, TypeString,
 };
 
 #[cfg(all(feature = "serde", feature = "alloc"))]
 pub use crate::NamedSerde;
 
 #[cfg(feature = "alloc")]
-pub use crate::Named;
+pub use crate::{DynNamed, Named, WithMessage};
 
 #[cfg(feature = "implication")]
 pub use crate::Implies;
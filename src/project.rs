@@ -0,0 +1,197 @@
+//! Projecting refinement.
+//!
+//! Predicates usually describe a value as a whole, but many invariants only concern a part of a
+//! value, such as one field of a struct or one element of a pair. [On] pairs a [Project]ion with a
+//! [Predicate]: values are refined by testing the projected part of the value with the predicate.
+//!
+//! Projections of tuple elements are provided by [First] and [Second], while projections of
+//! struct fields are declared with [projection!](crate::projection).
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::{ClosedInterval, GreaterThan}, project::*, projection};
+//!
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! projection!(
+//!     /// The port of a server.
+//!     pub Port, Server => port: u16
+//! );
+//!
+//! type Unprivileged = Refinement<Server, On<Port, GreaterThan<1023>>>;
+//!
+//! let server = Server { host: "localhost".to_string(), port: 8080 };
+//! assert!(Unprivileged::refine(server).is_ok());
+//!
+//! type Entry = Refinement<(String, u32), On<Second, ClosedInterval<1, 10>>>;
+//!
+//! assert!(Entry::refine(("apples".to_string(), 3)).is_ok());
+//! assert!(Entry::refine(("pears".to_string(), 0)).is_err());
+//! ```
+use core::marker::PhantomData;
+
+use crate::{error::ErrorKind, ErrorMessage, Predicate, RefinementError};
+
+/// A view of a part of a value.
+pub trait Project<T> {
+    /// The type of the projected part.
+    type Output;
+
+    /// Projects the part out of a value.
+    fn project(value: &T) -> &Self::Output;
+}
+
+/// A [Predicate] that tests the part of a value projected by `F` with `P`.
+///
+/// Errors and explanations are those of `P` for the projected part. Since only a part of the value
+/// is available to `P`, any [normalization](Predicate::normalize) performed by `P` is not applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct On<F, P>(PhantomData<F>, PhantomData<P>);
+
+impl<T, F: Project<T>, P: Predicate<F::Output>> Predicate<T> for On<F, P> {
+    const TRACE: bool = P::TRACE;
+
+    fn test(value: &T) -> bool {
+        P::test(F::project(value))
+    }
+
+    fn error() -> ErrorMessage {
+        P::error()
+    }
+
+    fn kind() -> ErrorKind {
+        P::kind()
+    }
+
+    fn violation(value: &T) -> RefinementError {
+        P::violation(F::project(value))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> crate::explain::Explanation {
+        P::explain(F::project(value))
+    }
+
+    unsafe fn optimize(value: &T) {
+        P::optimize(F::project(value))
+    }
+}
+
+/// Projects the first element of a pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct First;
+
+impl<A, B> Project<(A, B)> for First {
+    type Output = A;
+
+    fn project(value: &(A, B)) -> &A {
+        &value.0
+    }
+}
+
+/// Projects the second element of a pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Second;
+
+impl<A, B> Project<(A, B)> for Second {
+    type Output = B;
+
+    fn project(value: &(A, B)) -> &B {
+        &value.1
+    }
+}
+
+/// Declares a [Project]ion of a field of a struct.
+///
+/// `projection!(Name, Struct => field: Type)` declares a new type `Name` projecting the `field` of
+/// type `Type` out of `Struct`, for use with [On].
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, project::On, projection, string::Trimmed};
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// projection!(Name, User => name: String);
+///
+/// type Valid = Refinement<User, On<Name, Trimmed>>;
+///
+/// assert!(Valid::refine(User { name: "alice".to_string() }).is_ok());
+/// assert!(Valid::refine(User { name: " bob".to_string() }).is_err());
+/// ```
+#[macro_export]
+macro_rules! projection {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $t:ty => $field:tt: $output:ty $(,)?) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        $vis struct $name;
+
+        impl $crate::project::Project<$t> for $name {
+            type Output = $output;
+
+            fn project(value: &$t) -> &$output {
+                &value.$field
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::*, boundable::*, project::*, *};
+
+    struct Span {
+        start: u32,
+        end: u32,
+    }
+
+    projection!(Start, Span => start: u32);
+    projection!(End, Span => end: u32);
+
+    #[test]
+    fn test_on_fields() {
+        type Test = Refinement<
+            Span,
+            And<On<Start, unsigned::LessThan<10>>, On<End, unsigned::LessThan<20>>>,
+        >;
+        assert!(Test::refine(Span { start: 5, end: 15 }).is_ok());
+        assert!(Test::refine(Span { start: 10, end: 15 }).is_err());
+        assert!(Test::refine(Span { start: 5, end: 25 }).is_err());
+    }
+
+    #[test]
+    fn test_on_pair() {
+        type Test = Refinement<(i8, u8), And<On<First, signed::Odd>, On<Second, unsigned::Even>>>;
+        assert!(Test::refine((3, 4)).is_ok());
+        assert!(Test::refine((3, 5)).is_err());
+        assert!(Test::refine((2, 4)).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_on_errors() {
+        use alloc::{string::ToString, vec};
+
+        type Test = Refinement<(u8, u8), On<Second, unsigned::ClosedInterval<1, 10>>>;
+        let err = Test::refine((0, 11)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: must be less than or equal to 10"
+        );
+
+        type Nested = Refinement<
+            (u8, alloc::vec::Vec<u8>),
+            On<Second, collection::All<unsigned::LessThan<5>>>,
+        >;
+        let explanation = Nested::why_invalid(&(0, vec![1, 7])).unwrap();
+        assert_eq!(explanation.kind, error::ErrorKind::All);
+        assert!(!explanation.children[1].satisfied);
+    }
+}
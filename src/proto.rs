@@ -0,0 +1,257 @@
+//! Conversions between protobuf messages and refined domain types.
+//!
+//! Protobuf can't express constraints, so messages generated by tools such as
+//! [prost](https://docs.rs/prost) carry plain values that must be refined before they can be
+//! trusted. [FromProto] refines the value of a single field and [IntoProto] converts it back.
+//! Both are implemented for every refinement, for the scalar types that protobuf fields are
+//! generated as, and for the [Option]s and [Vec]s of optional and repeated fields.
+//!
+//! `#[derive(FromProto)]` generates the conversions of a whole domain
+//! struct: a [TryFrom] implementation that refines every field of the message, reporting every
+//! failure named after the path of its field, and a [From] implementation for the message.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::{GreaterThan, LessThan}, string::Trimmed, FromProto};
+//!
+//! // Generated by prost from a .proto file.
+//! #[derive(Clone, PartialEq, Default)]
+//! struct ItemMessage {
+//!     name: String,
+//!     size: u32,
+//! }
+//!
+//! #[derive(Clone, PartialEq, Default)]
+//! struct OrderMessage {
+//!     id: u64,
+//!     item: Option<ItemMessage>,
+//!     extras: Vec<ItemMessage>,
+//! }
+//!
+//! #[derive(Debug, FromProto)]
+//! #[proto(message = ItemMessage)]
+//! struct Item {
+//!     name: Refinement<String, Trimmed>,
+//!     size: Refinement<u32, LessThan<100>>,
+//! }
+//!
+//! #[derive(Debug, FromProto)]
+//! #[proto(message = OrderMessage)]
+//! struct Order {
+//!     id: Refinement<u64, GreaterThan<0>>,
+//!     #[proto(required)]
+//!     item: Item,
+//!     extras: Vec<Item>,
+//! }
+//!
+//! let item = |name: &str, size| ItemMessage { name: name.to_string(), size };
+//! let message = OrderMessage {
+//!     id: 1,
+//!     item: Some(item("widget", 5)),
+//!     extras: vec![item("bolt", 150)],
+//! };
+//! let errors = Order::try_from(message).unwrap_err();
+//! assert_eq!(
+//!     errors.to_string(),
+//!     "refinement violated: extras[0].size must be less than 100"
+//! );
+//!
+//! let message = OrderMessage { id: 0, ..Default::default() };
+//! assert_eq!(
+//!     Order::try_from(message).unwrap_err().to_string(),
+//!     "refinement violated: id must be greater than 0; refinement violated: item must be present"
+//! );
+//! ```
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    error::{ErrorKind, PathSegment, RefinementErrors},
+    RefinementError, RefinementOps,
+};
+
+/// Conversion from the plain value `P` of a protobuf field, refining it.
+///
+/// Errors are unnamed, or named relative to the field; the enclosing message names them with
+/// [RefinementErrors::nest].
+pub trait FromProto<P>: Sized {
+    /// Refines the value of a field.
+    fn from_proto(value: P) -> Result<Self, RefinementErrors>;
+}
+
+/// Conversion into the plain value `P` of a protobuf field.
+pub trait IntoProto<P> {
+    /// Converts a refined value into the value of a field.
+    fn into_proto(self) -> P;
+}
+
+impl<R: RefinementOps> FromProto<R::T> for R {
+    fn from_proto(value: R::T) -> Result<Self, RefinementErrors> {
+        Ok(R::refine(value)?)
+    }
+}
+
+impl<R: RefinementOps> IntoProto<R::T> for R {
+    fn into_proto(self) -> R::T {
+        self.take()
+    }
+}
+
+/// Optional fields are refined if they are present.
+impl<D: FromProto<P>, P> FromProto<Option<P>> for Option<D> {
+    fn from_proto(value: Option<P>) -> Result<Self, RefinementErrors> {
+        value.map(D::from_proto).transpose()
+    }
+}
+
+impl<D: IntoProto<P>, P> IntoProto<Option<P>> for Option<D> {
+    fn into_proto(self) -> Option<P> {
+        self.map(D::into_proto)
+    }
+}
+
+/// Repeated fields are refined element-wise, and every failure is located by its index.
+impl<D: FromProto<P>, P> FromProto<Vec<P>> for Vec<D> {
+    fn from_proto(value: Vec<P>) -> Result<Self, RefinementErrors> {
+        let mut errors = RefinementErrors::new();
+        let mut refined = Vec::with_capacity(value.len());
+        for (index, element) in value.into_iter().enumerate() {
+            match D::from_proto(element) {
+                Ok(element) => refined.push(element),
+                Err(err) => {
+                    for err in err {
+                        errors.push(match err.name() {
+                            Some(_) => err.within(&format!("[{}]", index)),
+                            None => err.at(PathSegment::Index(index)),
+                        });
+                    }
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(refined)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<D: IntoProto<P>, P> IntoProto<Vec<P>> for Vec<D> {
+    fn into_proto(self) -> Vec<P> {
+        self.into_iter().map(D::into_proto).collect()
+    }
+}
+
+macro_rules! identity {
+    ($($ty:ty),*) => {
+        $(
+            /// Fields that aren't refined are passed through as-is.
+            impl FromProto<$ty> for $ty {
+                fn from_proto(value: $ty) -> Result<Self, RefinementErrors> {
+                    Ok(value)
+                }
+            }
+
+            impl IntoProto<$ty> for $ty {
+                fn into_proto(self) -> $ty {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+identity!(bool, i32, i64, u8, u32, u64, f32, f64, String);
+
+/// The value of a message field that the domain type requires, failing if it is absent.
+///
+/// Protobuf message fields are always optional; `#[proto(required)]` fields are converted with
+/// this function.
+pub fn required<P>(value: Option<P>) -> Result<P, RefinementErrors> {
+    value.ok_or_else(|| {
+        RefinementError::new(ErrorKind::Custom, "must be present".to_string()).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, string::Trimmed, Refinement};
+
+    type Size = Refinement<u32, LessThan<100>>;
+
+    #[test]
+    fn test_round_trip() {
+        let sizes = Vec::<Size>::from_proto(vec![1, 2]).unwrap();
+        assert_eq!(sizes.into_proto(), vec![1, 2]);
+
+        let name = Option::<Refinement<String, Trimmed>>::from_proto(None).unwrap();
+        assert_eq!(name, None);
+        assert_eq!(IntoProto::<Option<String>>::into_proto(name), None);
+
+        assert!(bool::from_proto(true).unwrap());
+        assert_eq!(Size::from_proto(5).unwrap().into_proto(), 5);
+    }
+
+    #[test]
+    fn test_field_paths() {
+        let mut errors = RefinementErrors::new();
+        errors.nest("sizes", Vec::<Size>::from_proto(vec![1, 200, 300]));
+        errors.nest("size", required::<u32>(None).and_then(Size::from_proto));
+
+        let mut item = RefinementErrors::new();
+        item.check("size", Size::refine(150));
+        let mut order = RefinementErrors::new();
+        order.nest("item", Err::<(), _>(item));
+        errors.nest("orders[2]", Err::<(), _>(order));
+
+        let names: Vec<_> = errors.errors().iter().map(|err| err.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                Some("sizes"),
+                Some("sizes"),
+                Some("size"),
+                Some("orders[2].item.size"),
+            ]
+        );
+        assert_eq!(
+            errors.errors()[1].to_string(),
+            "refinement violated: sizes element [2]: must be less than 100"
+        );
+        assert_eq!(
+            errors.errors()[2].to_string(),
+            "refinement violated: size must be present"
+        );
+    }
+
+    #[test]
+    fn test_repeated_messages() {
+        struct Item(Size);
+
+        impl FromProto<u32> for Item {
+            fn from_proto(value: u32) -> Result<Self, RefinementErrors> {
+                let mut errors = RefinementErrors::new();
+                match errors.check("size", Size::refine(value)) {
+                    Some(size) => Ok(Item(size)),
+                    None => Err(errors),
+                }
+            }
+        }
+
+        let mut errors = RefinementErrors::new();
+        let items = errors.nest("items", Vec::<Item>::from_proto(vec![1, 150]));
+        assert!(items.is_none());
+        assert_eq!(
+            errors.to_string(),
+            "refinement violated: items[1].size must be less than 100"
+        );
+        assert_eq!(*Vec::<Item>::from_proto(vec![1]).unwrap()[0].0, 1);
+    }
+}
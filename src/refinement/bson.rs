@@ -0,0 +1,92 @@
+use ::bson::Bson;
+
+use crate::{Predicate, Refinement};
+
+/// Refinements are stored as their base type, which allows them to be used directly in `doc!`.
+///
+/// Refinements otherwise rely on the serde support for BSON documents. Note that BSON has no
+/// unsigned integer types, so unsigned base types are stored as signed integers; values that do not
+/// fit (for example, a `u64` above `i64::MAX`) fail to serialize before they are refined.
+#[doc(cfg(feature = "bson"))]
+impl<T: Into<Bson>, P: Predicate<T>> From<Refinement<T, P>> for Bson {
+    fn from(value: Refinement<T, P>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::{String, ToString};
+
+    use ::bson::{doc, from_bson, from_document, from_slice, to_bson, to_document, to_vec};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, prelude::*, string::Trimmed};
+
+    type Size = Refinement<u8, LessThan<100>>;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        name: Refinement<String, Trimmed>,
+        size: Size,
+        limit: Option<Size>,
+    }
+
+    fn item() -> Item {
+        Item {
+            name: Refinement::refine("widget".to_string()).unwrap(),
+            size: Size::refine(5).unwrap(),
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn test_bson_round_trip() {
+        let bson = to_bson(&Size::refine(5).unwrap()).unwrap();
+        assert_eq!(bson, Bson::Int32(5));
+        assert_eq!(*from_bson::<Size>(bson).unwrap(), 5);
+
+        let err = from_bson::<Size>(Bson::Int32(150)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("refinement violated: must be less than 100"));
+        assert!(from_bson::<Size>(Bson::String("5".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_document_round_trip() {
+        let document = to_document(&item()).unwrap();
+        assert_eq!(
+            document,
+            doc! { "name": "widget", "size": 5, "limit": Bson::Null }
+        );
+        assert_eq!(from_document::<Item>(document).unwrap(), item());
+
+        let err = from_document::<Item>(doc! { "name": " widget", "size": 5 }).unwrap_err();
+        assert!(err.to_string().contains("refinement violated"));
+    }
+
+    #[test]
+    fn test_raw_round_trip() {
+        let bytes = to_vec(&item()).unwrap();
+        assert_eq!(from_slice::<Item>(&bytes).unwrap(), item());
+
+        let bytes = to_vec(&doc! { "name": "widget", "size": 150 }).unwrap();
+        let err = from_slice::<Item>(&bytes).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("refinement violated: must be less than 100"));
+    }
+
+    #[test]
+    fn test_into_bson() {
+        let name: Refinement<String, Trimmed> = Refinement::refine("widget".to_string()).unwrap();
+        let size: Refinement<i32, crate::boundable::signed::LessThan<100>> =
+            Refinement::refine(5).unwrap();
+        assert_eq!(
+            doc! { "name": name, "size": size },
+            doc! { "name": "widget", "size": 5 }
+        );
+    }
+}
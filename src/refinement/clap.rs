@@ -0,0 +1,145 @@
+use std::{
+    boxed::Box, error, ffi::OsStr, format, marker::PhantomData, str::FromStr, string::ToString,
+};
+
+use ::clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command, Error,
+};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// A [TypedValueParser] that parses a value with `V` and then refines it with `P`.
+///
+/// Every [Refinement] whose base type implements [FromStr] has a [value parser](ValueParserFactory)
+/// of this type, so refined arguments are supported by `value_parser!` and `#[derive(Parser)]`
+/// without further configuration. Other parsers of the base type can be refined with
+/// [RefinedValueParser::new]. Values that violate the refinement are rejected with the message of
+/// the predicate:
+///
+/// ```
+/// use clap::{value_parser, Arg, Command};
+/// use refined::{prelude::*, boundable::unsigned::ClosedInterval};
+///
+/// type Threads = Refinement<u8, ClosedInterval<1, 64>>;
+///
+/// let command = Command::new("app")
+///     .arg(Arg::new("threads").long("threads").value_parser(value_parser!(Threads)));
+///
+/// let matches = command.clone().try_get_matches_from(["app", "--threads", "8"]).unwrap();
+/// assert_eq!(**matches.get_one::<Threads>("threads").unwrap(), 8);
+///
+/// let err = command.try_get_matches_from(["app", "--threads", "128"]).unwrap_err();
+/// assert!(err.to_string().contains("must be less than or equal to 64"));
+/// ```
+#[doc(cfg(feature = "clap"))]
+pub struct RefinedValueParser<V, P>(V, PhantomData<fn() -> P>);
+
+impl<V, P> RefinedValueParser<V, P> {
+    pub fn new(parser: V) -> Self {
+        Self(parser, PhantomData)
+    }
+}
+
+impl<V: Clone, P> Clone for RefinedValueParser<V, P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<V, P> TypedValueParser for RefinedValueParser<V, P>
+where
+    V: TypedValueParser,
+    P: Predicate<V::Value> + Clone + Send + Sync + 'static,
+{
+    type Value = Refinement<V::Value, P>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let parsed = self.0.parse_ref(cmd, arg, value)?;
+        Refinement::refine(parsed).map_err(|err| {
+            let arg = arg.map_or_else(|| "...".into(), ToString::to_string);
+            cmd.clone().error(
+                ErrorKind::ValueValidation,
+                format!(
+                    "invalid value '{}' for '{}': {}",
+                    value.to_string_lossy(),
+                    arg,
+                    err.message()
+                ),
+            )
+        })
+    }
+}
+
+#[doc(cfg(feature = "clap"))]
+impl<T, P> ValueParserFactory for Refinement<T, P>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: Into<Box<dyn error::Error + Send + Sync + 'static>>,
+    P: Predicate<T> + Clone + Send + Sync + 'static,
+{
+    type Parser = RefinedValueParser<fn(&str) -> Result<T, T::Err>, P>;
+
+    fn value_parser() -> Self::Parser {
+        RefinedValueParser::new(T::from_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::clap::value_parser;
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, string::Trimmed};
+
+    fn command() -> Command {
+        Command::new("test")
+            .arg(
+                Arg::new("size")
+                    .long("size")
+                    .value_parser(value_parser!(Refinement<u8, LessThan<100>>)),
+            )
+            .arg(
+                Arg::new("name")
+                    .long("name")
+                    .value_parser(value_parser!(Refinement<std::string::String, Trimmed>)),
+            )
+    }
+
+    #[test]
+    fn test_value_parser() {
+        let matches = command()
+            .try_get_matches_from(["test", "--size", "5", "--name", "foo"])
+            .unwrap();
+        assert_eq!(
+            **matches
+                .get_one::<Refinement<u8, LessThan<100>>>("size")
+                .unwrap(),
+            5
+        );
+
+        let err = command()
+            .try_get_matches_from(["test", "--size", "150"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        assert!(err
+            .to_string()
+            .contains("invalid value '150' for '--size <size>': must be less than 100"));
+
+        let err = command()
+            .try_get_matches_from(["test", "--size", "x"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+
+        let err = command()
+            .try_get_matches_from(["test", "--name", " foo"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    }
+}
@@ -0,0 +1,283 @@
+//! Conversions between [Refinement] and other types.
+//!
+//! A blanket `impl<T, P> TryFrom<T> for Refinement<T, P>` conflicts with the reflexive `TryFrom`
+//! implementation provided by `core`, so direct conversions are instead provided for each of the
+//! base types that `refined` knows how to refine.
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    rc::Rc,
+    string::String,
+    vec::Vec,
+};
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use core::num::{
+    NonZeroI16, NonZeroI32, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32, NonZeroU8,
+    NonZeroUsize,
+};
+#[cfg(target_pointer_width = "64")]
+use core::num::{NonZeroI64, NonZeroU64};
+
+#[cfg(feature = "implication")]
+use crate::implication::Implies;
+use crate::{
+    boundable::{signed, unsigned, BoundingPredicate},
+    Predicate, Refined, Refinement, RefinementError,
+};
+
+/// Implements `TryFrom<$t>` for `Refinement<$t, P>` by delegating to the [Refined] conversion.
+macro_rules! refinement_try_from {
+    ($([$($gen:ident),*] $t:ty),* $(,)?) => {
+        $(
+            impl<P: Predicate<$t>, $($gen),*> TryFrom<$t> for Refinement<$t, P> {
+                type Error = RefinementError;
+
+                fn try_from(value: $t) -> Result<Self, Self::Error> {
+                    Self::try_from(Refined(value))
+                }
+            }
+        )*
+    };
+}
+
+refinement_try_from!(
+    [] u8,
+    [] u16,
+    [] u32,
+    [] u64,
+    [] u128,
+    [] usize,
+    [] i8,
+    [] i16,
+    [] i32,
+    [] i64,
+    [] i128,
+    [] isize,
+    [] f32,
+    [] f64,
+    [] bool,
+    [] char,
+);
+
+#[cfg(feature = "alloc")]
+refinement_try_from!(
+    [] String,
+    [X] Vec<X>,
+    [X] VecDeque<X>,
+    [X] LinkedList<X>,
+    [X] BinaryHeap<X>,
+    [X] BTreeSet<X>,
+    [K, V] BTreeMap<K, V>,
+    [] Box<str>,
+    [] Rc<str>,
+    [X] Box<[X]>,
+    [X] Rc<[X]>,
+);
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+refinement_try_from!([] alloc::sync::Arc<str>, [X] alloc::sync::Arc<[X]>);
+
+#[cfg(feature = "std")]
+refinement_try_from!([X, S] HashSet<X, S>, [K, V, S] HashMap<K, V, S>);
+
+impl<'a, P: Predicate<&'a str>> TryFrom<&'a str> for Refinement<&'a str, P> {
+    type Error = RefinementError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(Refined(value))
+    }
+}
+
+impl<'a, X, P: Predicate<&'a [X]>> TryFrom<&'a [X]> for Refinement<&'a [X], P> {
+    type Error = RefinementError;
+
+    fn try_from(value: &'a [X]) -> Result<Self, Self::Error> {
+        Self::try_from(Refined(value))
+    }
+}
+
+/// Implements infallible conversions between `Refinement<$t, NonZero>` and the corresponding
+/// `core::num` non-zero type.
+///
+/// When the `implication` feature is enabled, any refinement whose predicate implies `NonZero`
+/// converts into the non-zero type, and a non-zero value converts into any refinement whose
+/// predicate is implied by `NonZero`.
+macro_rules! refinement_non_zero {
+    ($nonzero:ident, $($t:ty => $nz:ty),* $(,)?) => {
+        $(
+            #[cfg(not(feature = "implication"))]
+            impl From<Refinement<$t, $nonzero::NonZero>> for $nz {
+                fn from(value: Refinement<$t, $nonzero::NonZero>) -> Self {
+                    <$nz>::new(value.0).expect("refinement guarantees a non-zero value")
+                }
+            }
+
+            #[cfg(not(feature = "implication"))]
+            impl From<$nz> for Refinement<$t, $nonzero::NonZero> {
+                fn from(value: $nz) -> Self {
+                    Refinement(value.get(), PhantomData)
+                }
+            }
+
+            #[cfg(feature = "implication")]
+            impl<P: Predicate<$t> + Implies<$nonzero::NonZero>> From<Refinement<$t, P>> for $nz {
+                fn from(value: Refinement<$t, P>) -> Self {
+                    <$nz>::new(value.0).expect("refinement guarantees a non-zero value")
+                }
+            }
+
+            #[cfg(feature = "implication")]
+            impl<P: Predicate<$t>> From<$nz> for Refinement<$t, P>
+            where
+                $nonzero::NonZero: Implies<P>,
+            {
+                fn from(value: $nz) -> Self {
+                    Refinement(value.get(), PhantomData)
+                }
+            }
+        )*
+    };
+}
+
+refinement_non_zero!(
+    unsigned,
+    u8 => NonZeroU8,
+    u16 => NonZeroU16,
+    u32 => NonZeroU32,
+    usize => NonZeroUsize,
+);
+
+#[cfg(target_pointer_width = "64")]
+refinement_non_zero!(unsigned, u64 => NonZeroU64);
+
+refinement_non_zero!(
+    signed,
+    i8 => NonZeroI8,
+    i16 => NonZeroI16,
+    i32 => NonZeroI32,
+    isize => NonZeroIsize,
+);
+
+#[cfg(target_pointer_width = "64")]
+refinement_non_zero!(signed, i64 => NonZeroI64);
+
+/// Implements lossless widening conversions between refinements of numeric types, preserving the
+/// predicate without re-checking it.
+macro_rules! refinement_widen {
+    ($($from:ty => $($to:ty),+);* $(;)?) => {
+        $($(
+            impl<P> From<Refinement<$from, P>> for Refinement<$to, P>
+            where
+                P: BoundingPredicate + Predicate<$from> + Predicate<$to>,
+            {
+                fn from(value: Refinement<$from, P>) -> Self {
+                    Refinement(<$to>::from(value.0), PhantomData)
+                }
+            }
+        )+)*
+    };
+}
+
+refinement_widen!(
+    u8 => u16, u32, usize;
+    u16 => u32, usize;
+    i8 => i16, i32, isize;
+    i16 => i32, isize;
+);
+
+#[cfg(target_pointer_width = "64")]
+refinement_widen!(
+    u8 => u64;
+    u16 => u64;
+    u32 => u64;
+    i8 => i64;
+    i16 => i64;
+    i32 => i64;
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::LessThan, *};
+    #[cfg(feature = "alloc")]
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn test_try_from_primitive() {
+        let ok: Result<Refinement<u8, LessThan<5>>, _> = 4.try_into();
+        assert_eq!(*ok.unwrap(), 4);
+
+        let err: Result<Refinement<u8, LessThan<5>>, _> = 5.try_into();
+        assert!(err.is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_from_str() {
+        let ok: Result<Refinement<&str, string::Trimmed>, _> = "foo".try_into();
+        assert_eq!(*ok.unwrap(), "foo");
+
+        let err: Result<Refinement<&str, string::Trimmed>, _> = " foo ".try_into();
+        assert!(err.is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_from_string() {
+        let err = Refinement::<String, LessThan<3>>::try_from("abc".to_string()).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be less than 3"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_from_vec() {
+        let ok: Result<Refinement<Vec<u8>, LessThan<3>>, _> = vec![1, 2].try_into();
+        assert_eq!(ok.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_non_zero() {
+        let r = Refinement::<u16, boundable::unsigned::NonZero>::refine(7).unwrap();
+        let n: core::num::NonZeroU16 = r.into();
+        assert_eq!(n.get(), 7);
+        let r: Refinement<u16, boundable::unsigned::NonZero> = n.into();
+        assert_eq!(*r, 7);
+
+        let n = core::num::NonZeroI32::new(-3).unwrap();
+        let r: Refinement<i32, boundable::signed::NonZero> = n.into();
+        assert_eq!(*r, -3);
+    }
+
+    #[cfg(feature = "implication")]
+    #[test]
+    fn test_non_zero_implication() {
+        let r = Refinement::<u8, boundable::unsigned::ClosedInterval<1, 10>>::refine(3).unwrap();
+        let n: core::num::NonZeroU8 = r.into();
+        assert_eq!(n.get(), 3);
+        let r: Refinement<u8, boundable::unsigned::GreaterThanEqual<1>> = n.into();
+        assert_eq!(*r, 3);
+    }
+
+    #[test]
+    fn test_widen() {
+        let r = Refinement::<u8, boundable::unsigned::LessThan<100>>::refine(42).unwrap();
+        let w: Refinement<u16, boundable::unsigned::LessThan<100>> = r.into();
+        assert_eq!(*w, 42);
+
+        let r = Refinement::<i8, boundable::signed::ClosedInterval<-5, 5>>::refine(-3).unwrap();
+        let w: Refinement<i32, boundable::signed::ClosedInterval<-5, 5>> = r.into();
+        assert_eq!(*w, -3);
+    }
+}
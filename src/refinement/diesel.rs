@@ -0,0 +1,85 @@
+use core::fmt::Debug;
+
+use ::diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    expression::{AsExpression, TypedExpressionType},
+    serialize::{self, Output, ToSql},
+    sql_types::{SingleValue, SqlType},
+    Queryable,
+};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// Refinements are used in queries as their base type.
+#[doc(cfg(feature = "diesel"))]
+impl<ST, T, P> AsExpression<ST> for Refinement<T, P>
+where
+    ST: SqlType + TypedExpressionType,
+    T: AsExpression<ST>,
+    P: Predicate<T>,
+{
+    type Expression = T::Expression;
+
+    fn as_expression(self) -> Self::Expression {
+        self.0.as_expression()
+    }
+}
+
+#[doc(cfg(feature = "diesel"))]
+impl<'a, ST, T, P> AsExpression<ST> for &'a Refinement<T, P>
+where
+    ST: SqlType + TypedExpressionType,
+    &'a T: AsExpression<ST>,
+    P: Predicate<T>,
+{
+    type Expression = <&'a T as AsExpression<ST>>::Expression;
+
+    fn as_expression(self) -> Self::Expression {
+        (&self.0).as_expression()
+    }
+}
+
+/// Refinements are stored as their base type.
+#[doc(cfg(feature = "diesel"))]
+impl<ST, DB, T, P> ToSql<ST, DB> for Refinement<T, P>
+where
+    DB: Backend,
+    T: ToSql<ST, DB>,
+    P: Predicate<T> + Debug,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+/// Loads a value of the base type and refines it, so that values loaded from the database
+/// re-establish the predicate. Values that violate the predicate fail to load with the
+/// [RefinementError](crate::RefinementError).
+#[doc(cfg(feature = "diesel"))]
+impl<ST, DB, T, P> FromSql<ST, DB> for Refinement<T, P>
+where
+    DB: Backend,
+    T: FromSql<ST, DB>,
+    P: Predicate<T>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::refine(T::from_sql(bytes)?)?)
+    }
+}
+
+/// Refinements can be loaded as a single column.
+#[doc(cfg(feature = "diesel"))]
+impl<ST, DB, T, P> Queryable<ST, DB> for Refinement<T, P>
+where
+    ST: SingleValue,
+    DB: Backend,
+    T: FromSql<ST, DB>,
+    P: Predicate<T>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
@@ -0,0 +1,102 @@
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{Predicate, Refinement};
+
+/// A rendering of a [Refinement] along with the type and predicate that it is refined by, such as
+/// `49: u8 where LessThan<50>`.
+///
+/// Created with [Refinement::display_refined]. The value is rendered with [Display] when the
+/// rendering is displayed, and with [Debug] when it is debugged. Type names are rendered without
+/// their module paths.
+///
+/// ```
+/// use refined::{Refinement, RefinementOps, boundable::unsigned::LessThan, string::Trimmed};
+///
+/// let value = Refinement::<u8, LessThan<50>>::refine(49).unwrap();
+/// assert_eq!(value.display_refined().to_string(), "49: u8 where LessThan<50>");
+///
+/// let name = Refinement::<String, Trimmed>::refine("ferris".to_string()).unwrap();
+/// assert_eq!(
+///     format!("{:?}", name.display_refined()),
+///     "\"ferris\": String where Trimmed"
+/// );
+/// ```
+pub struct DisplayRefined<'a, T, P: Predicate<T>>(&'a T, PhantomData<P>);
+
+impl<T, P: Predicate<T>> DisplayRefined<'_, T, P> {
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(": ")?;
+        short_type_name(f, core::any::type_name::<T>())?;
+        f.write_str(" where ")?;
+        short_type_name(f, core::any::type_name::<P>())
+    }
+}
+
+impl<T: Display, P: Predicate<T>> Display for DisplayRefined<'_, T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.0, f)?;
+        self.describe(f)
+    }
+}
+
+impl<T: Debug, P: Predicate<T>> Debug for DisplayRefined<'_, T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.0, f)?;
+        self.describe(f)
+    }
+}
+
+/// Writes the type name `name` with the module paths of each of its segments stripped, such as
+/// `And<GreaterThan<5>, LessThan<10>>`.
+pub(crate) fn short_type_name(f: &mut Formatter<'_>, name: &str) -> fmt::Result {
+    let mut rest = name;
+    while !rest.is_empty() {
+        let end = rest
+            .find(['<', '>', ',', ' ', '(', ')', '[', ']', ';', '&'])
+            .unwrap_or(rest.len());
+        let (segment, tail) = rest.split_at(end);
+        f.write_str(segment.rsplit("::").next().unwrap_or(segment))?;
+        let delimiter = tail.chars().next().map_or(0, char::len_utf8);
+        f.write_str(&tail[..delimiter])?;
+        rest = &tail[delimiter..];
+    }
+    Ok(())
+}
+
+impl<T, P: Predicate<T>> Refinement<T, P> {
+    /// Renders the refined value along with the type and predicate that it is refined by.
+    ///
+    /// See [DisplayRefined] for more information.
+    pub fn display_refined(&self) -> DisplayRefined<'_, T, P> {
+        DisplayRefined(&self.0, PhantomData)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{format, string::ToString, vec};
+
+    use crate::{boolean::*, boundable::*, collection::All, *};
+
+    #[test]
+    fn test_display_refined() {
+        let value =
+            Refinement::<i16, And<signed::GreaterThan<-5>, Not<signed::Equals<0>>>>::refine(-3)
+                .unwrap();
+        assert_eq!(
+            value.display_refined().to_string(),
+            "-3: i16 where And<GreaterThan<-5>, Not<Equals<0>>>"
+        );
+
+        let values =
+            Refinement::<alloc::vec::Vec<u8>, All<unsigned::LessThan<10>>>::refine(vec![1, 2])
+                .unwrap();
+        assert_eq!(
+            format!("{:?}", values.display_refined()),
+            "[1, 2]: Vec<u8> where All<LessThan<10>>"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use alloc::borrow::Cow;
+use core::ops::Deref;
+
+use crate::{RefinementError, RefinementOps, StatefulPredicate, StatefulRefinementOps};
+
+/// A named refinement over a refinement type `R` whose name is only known at runtime.
+///
+/// [Named] requires the name of a refinement to be fixed in its type. `DynNamed` instead accepts
+/// the name when the value is refined, which is useful when names are generated dynamically (for
+/// example, positional columns of a CSV file or repeated entries of an array).
+///
+/// Because the name is part of the value rather than the type, `DynNamed` cannot implement
+/// [RefinementOps]; it provides equivalent inherent methods instead.
+///
+/// [Named]: crate::Named
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan};
+///
+/// type Cell = Refinement<u8, LessThan<10>>;
+///
+/// let row = [3, 12, 5];
+/// let err = row
+///     .iter()
+///     .enumerate()
+///     .map(|(i, v)| DynNamed::<Cell>::refine(format!("column {}", i), *v))
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap_err();
+///
+/// assert_eq!(err.to_string(), "refinement violated: column 1 must be less than 10");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynNamed<R: RefinementOps> {
+    name: Cow<'static, str>,
+    refinement: R,
+}
+
+impl<R: RefinementOps> DynNamed<R> {
+    /// Attempts to refine a runtime value with the type's imbued predicate, naming the refinement
+    /// `name`.
+    pub fn refine<N: Into<Cow<'static, str>>>(
+        name: N,
+        value: R::T,
+    ) -> Result<Self, RefinementError> {
+        Self::new(name.into(), || R::refine(value))
+    }
+
+    /// Attempts a modification of a refined value, re-certifying that the predicate still holds
+    /// after the modification is complete.
+    pub fn modify<F>(self, fun: F) -> Result<Self, RefinementError>
+    where
+        F: FnOnce(R::T) -> R::T,
+    {
+        let value = self.refinement.take();
+        Self::new(self.name, || R::refine(fun(value)))
+    }
+
+    /// Attempts a replacement of a refined value, re-certifying that the predicate holds for the
+    /// new value.
+    pub fn replace(self, value: R::T) -> Result<Self, RefinementError> {
+        Self::new(self.name, || R::refine(value))
+    }
+
+    /// The name of the refinement.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Destructively removes the refined value from the `DynNamed` wrapper.
+    pub fn take(self) -> R::T {
+        self.refinement.take()
+    }
+
+    /// Destructively removes the underlying refinement from the `DynNamed` wrapper.
+    pub fn into_inner(self) -> R {
+        self.refinement
+    }
+
+    /// Attempts to refine a runtime value with the type's imbued predicate, statefully, naming the
+    /// refinement `name`.
+    pub fn refine_with_state<T, P, N>(
+        name: N,
+        predicate: &P,
+        value: T,
+    ) -> Result<Self, RefinementError>
+    where
+        P: StatefulPredicate<T>,
+        R: StatefulRefinementOps<T, P>,
+        N: Into<Cow<'static, str>>,
+    {
+        Self::new(name.into(), || R::refine_with_state(predicate, value))
+    }
+
+    fn new<F>(name: Cow<'static, str>, refine: F) -> Result<Self, RefinementError>
+    where
+        F: FnOnce() -> Result<R, RefinementError>,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(target: "refined", "named", name = &*name).entered();
+        match refine() {
+            Ok(refinement) => Ok(Self { name, refinement }),
+            Err(err) => Err(err.named(&name)),
+        }
+    }
+}
+
+impl<R: RefinementOps> Deref for DynNamed<R> {
+    type Target = R::T;
+
+    fn deref(&self) -> &Self::Target {
+        self.refinement.deref()
+    }
+}
+
+impl<R: RefinementOps> AsRef<R> for DynNamed<R> {
+    fn as_ref(&self) -> &R {
+        &self.refinement
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: RefinementOps> serde::Serialize for DynNamed<R>
+where
+    R::T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.refinement.deref().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, *};
+    use alloc::{format, string::ToString};
+
+    type Test = Refinement<u8, LessThan<5>>;
+
+    #[test]
+    fn test_dyn_named_success() {
+        let value = DynNamed::<Test>::refine("test", 4).unwrap();
+        assert_eq!(*value, 4);
+        assert_eq!(value.name(), "test");
+    }
+
+    #[test]
+    fn test_dyn_named_failure() {
+        let err = DynNamed::<Test>::refine(format!("entry {}", 3), 5).unwrap_err();
+        assert_eq!(err.name(), Some("entry 3"));
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: entry 3 must be less than 5"
+        );
+    }
+
+    #[test]
+    fn test_dyn_named_modify() {
+        let value = DynNamed::<Test>::refine("test", 3).unwrap();
+        let value = value.modify(|x| x + 1).unwrap();
+        assert_eq!(*value, 4);
+        assert_eq!(
+            value.modify(|x| x + 1).unwrap_err().to_string(),
+            "refinement violated: test must be less than 5"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_dyn_named_serialize() {
+        let value = DynNamed::<Test>::refine("test", 4).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap(), "4");
+    }
+}
@@ -0,0 +1,105 @@
+use ::fake::{Dummy, Rng};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// The number of values that are faked for a refinement before giving up on its predicate.
+const ATTEMPTS: usize = 1000;
+
+/// Fakes values of the base type with any of its fakers, keeping the first that satisfies the
+/// predicate. Faking panics if no value satisfies the predicate after many attempts, so fakers
+/// should mostly produce valid values: a ranged number is faked from a range such as `1..=10`
+/// rather than [Faker](::fake::Faker), and a name with a length bound from
+/// [Name](::fake::faker::name::en::Name).
+///
+/// ```
+/// use fake::{faker::internet::en::SafeEmail, Fake};
+/// use refined::{prelude::*, boundable::unsigned::ClosedInterval, string::Contains};
+///
+/// type_string!(At, "@");
+/// type Email = Refinement<String, Contains<At>>;
+/// type Level = Refinement<u8, ClosedInterval<1, 10>>;
+///
+/// let email: Email = SafeEmail().fake();
+/// assert!(email.contains('@'));
+/// let level: Level = (1..=10).fake();
+/// assert!((1..=10).contains(&*level));
+/// ```
+#[doc(cfg(feature = "fake"))]
+impl<C, T: Dummy<C>, P: Predicate<T>> Dummy<C> for Refinement<T, P> {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &C, rng: &mut R) -> Self {
+        (0..ATTEMPTS)
+            .find_map(|_| Self::refine(T::dummy_with_rng(config, rng)).ok())
+            .unwrap_or_else(|| {
+                panic!(
+                    "no value of {} that {} was faked",
+                    core::any::type_name::<T>(),
+                    P::error()
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+
+    use ::fake::{
+        faker::{internet::en::SafeEmail, name::en::Name},
+        Fake, Faker,
+    };
+
+    use super::*;
+    use crate::{
+        boundable::{signed, unsigned},
+        string::{Contains, Trimmed},
+        type_string, TypeString,
+    };
+
+    type_string!(At, "@");
+
+    type Email = Refinement<String, Contains<At>>;
+    type UserName = Refinement<String, unsigned::ClosedInterval<3, 32>>;
+    type Level = Refinement<u8, unsigned::ClosedInterval<1, 10>>;
+
+    #[derive(Debug, Dummy)]
+    struct User {
+        #[dummy(faker = "SafeEmail()")]
+        email: Email,
+        #[dummy(faker = "Name()")]
+        name: UserName,
+        #[dummy(faker = "1..=10")]
+        level: Level,
+        nickname: Refinement<String, Trimmed>,
+    }
+
+    #[test]
+    fn test_fakers() {
+        for _ in 0..100 {
+            let email: Email = SafeEmail().fake();
+            assert!(email.contains('@'));
+            let name: UserName = Name().fake();
+            assert!((3..=32).contains(&name.len()));
+            let offset: Refinement<i32, signed::OpenInterval<-5, 5>> = (-4..5).fake();
+            assert!((-4..=4).contains(&*offset));
+            let even: Refinement<u8, unsigned::Even> = Faker.fake();
+            assert_eq!(*even % 2, 0);
+        }
+    }
+
+    #[test]
+    fn test_derive() {
+        for _ in 0..100 {
+            let user: User = Faker.fake();
+            assert!(user.email.contains('@'));
+            assert!((3..=32).contains(&user.name.len()));
+            assert!((1..=10).contains(&*user.level));
+            assert_eq!(user.nickname.trim(), user.nickname.as_str());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no value of u8 that")]
+    fn test_unsatisfiable() {
+        let _: Refinement<u8, unsigned::GreaterThan<10>> = (0..=10).fake();
+    }
+}
@@ -0,0 +1,136 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{Predicate, Refinement, RefinementError};
+
+/// A scoped mutable borrow of a [Refinement] that re-certifies the predicate when it is released.
+///
+/// The guard is created with [Refinement::guard_mut] and dereferences to a copy of the underlying
+/// value, allowing any number of mutations. The predicate is checked once, either explicitly with
+/// [commit](RefinementGuard::commit) or implicitly when the guard is dropped, and the copy is only
+/// written back to the refinement if the predicate still holds. Otherwise, and if the guard is
+/// never released (e.g. with [core::mem::forget]), the refinement keeps its original value.
+///
+/// Dropping the guard discards any failure, other than [tracing](crate::trace) it; use
+/// [commit](RefinementGuard::commit) to observe it.
+///
+/// ```
+/// use refined::{Refinement, RefinementOps, boundable::unsigned::LessThan};
+///
+/// let mut value = Refinement::<Vec<u8>, LessThan<3>>::refine(vec![1]).unwrap();
+///
+/// let mut guard = value.guard_mut();
+/// guard.push(2);
+/// assert!(guard.commit().is_ok());
+/// assert_eq!(*value, vec![1, 2]);
+///
+/// let mut guard = value.guard_mut();
+/// guard.push(3);
+/// guard.push(4);
+/// assert!(guard.commit().is_err());
+/// assert_eq!(*value, vec![1, 2]);
+/// ```
+#[must_use = "dropping the guard discards the outcome of the mutation; use `commit` to observe it"]
+pub struct RefinementGuard<'a, T: Clone, P: Predicate<T>> {
+    refinement: &'a mut Refinement<T, P>,
+    value: Option<T>,
+}
+
+impl<'a, T: Clone, P: Predicate<T>> RefinementGuard<'a, T, P> {
+    pub(crate) fn new(refinement: &'a mut Refinement<T, P>) -> Self {
+        let value = Some(refinement.0.clone());
+        Self { refinement, value }
+    }
+
+    /// Releases the guard, re-certifying that the predicate still holds.
+    ///
+    /// On failure the refinement keeps its original value.
+    pub fn commit(mut self) -> Result<(), RefinementError> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<(), RefinementError> {
+        match self.value.take() {
+            Some(value) if P::test(&value) => {
+                self.refinement.0 = value;
+                Ok(())
+            }
+            Some(value) => Err(super::rejected::<T, P>(P::violation(&value))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Clone, P: Predicate<T>> Deref for RefinementGuard<'_, T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+            .as_ref()
+            .expect("the guard has not been released")
+    }
+}
+
+impl<T: Clone, P: Predicate<T>> DerefMut for RefinementGuard<'_, T, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+            .as_mut()
+            .expect("the guard has not been released")
+    }
+}
+
+impl<T: Clone, P: Predicate<T>> Drop for RefinementGuard<'_, T, P> {
+    fn drop(&mut self) {
+        // Failures are traced by `rejected`; there is no caller left to report them to.
+        let _ = self.finish();
+    }
+}
+
+impl<T: Clone, P: Predicate<T>> Refinement<T, P> {
+    /// Borrows the refined value mutably through a [RefinementGuard].
+    pub fn guard_mut(&mut self) -> RefinementGuard<'_, T, P> {
+        RefinementGuard::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::LessThan, *};
+
+    #[test]
+    fn test_guard_commit() {
+        let mut value = Refinement::<u8, LessThan<10>>::refine(3).unwrap();
+        let mut guard = value.guard_mut();
+        *guard += 4;
+        *guard -= 1;
+        assert!(guard.commit().is_ok());
+        assert_eq!(*value, 6);
+    }
+
+    #[test]
+    fn test_guard_rollback_on_drop() {
+        let mut value = Refinement::<u8, LessThan<10>>::refine(3).unwrap();
+        {
+            let mut guard = value.guard_mut();
+            *guard = 42;
+        }
+        assert_eq!(*value, 3);
+    }
+
+    #[test]
+    fn test_guard_forgotten() {
+        let mut value = Refinement::<u8, LessThan<10>>::refine(3).unwrap();
+        let mut guard = value.guard_mut();
+        *guard = 42;
+        core::mem::forget(guard);
+        assert_eq!(*value, 3);
+    }
+
+    #[test]
+    fn test_guard_commit_failure() {
+        let mut value = Refinement::<u8, LessThan<10>>::refine(3).unwrap();
+        let mut guard = value.guard_mut();
+        *guard = 42;
+        assert!(guard.commit().is_err());
+        assert_eq!(*value, 3);
+    }
+}
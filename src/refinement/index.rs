@@ -0,0 +1,130 @@
+//! Indexing with refined indices, which are known to be in bounds.
+#[cfg(all(feature = "alloc", feature = "implication"))]
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+use crate::{boundable::unsigned::LessThan, Refinement};
+#[cfg(all(feature = "alloc", feature = "implication"))]
+use crate::{
+    boundable::UnsignedMin,
+    implication::{Assert, IsTrue},
+};
+
+/// Returns a reference to the element at `index` of `slice`, which the caller guarantees to be in
+/// bounds. The bounds check is only elided with the `optimized` feature.
+fn get<T>(slice: &[T], index: usize) -> &T {
+    debug_assert!(index < slice.len());
+    #[cfg(feature = "optimized")]
+    unsafe {
+        slice.get_unchecked(index)
+    }
+    #[cfg(not(feature = "optimized"))]
+    &slice[index]
+}
+
+/// Returns a mutable reference to the element at `index` of `slice`, which the caller guarantees to
+/// be in bounds. The bounds check is only elided with the `optimized` feature.
+fn get_mut<T>(slice: &mut [T], index: usize) -> &mut T {
+    debug_assert!(index < slice.len());
+    #[cfg(feature = "optimized")]
+    unsafe {
+        slice.get_unchecked_mut(index)
+    }
+    #[cfg(not(feature = "optimized"))]
+    &mut slice[index]
+}
+
+/// Arrays can be indexed by refinements that are less than their length, which never panic.
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan};
+///
+/// let weekdays = ["Mon", "Tue", "Wed", "Thu", "Fri"];
+/// let index = Refinement::<usize, LessThan<5>>::refine(3).unwrap();
+/// assert_eq!(weekdays[index], "Thu");
+/// ```
+impl<T, const N: usize> Index<Refinement<usize, LessThan<N>>> for [T; N] {
+    type Output = T;
+
+    fn index(&self, index: Refinement<usize, LessThan<N>>) -> &T {
+        get(self, index.0)
+    }
+}
+
+impl<T, const N: usize> IndexMut<Refinement<usize, LessThan<N>>> for [T; N] {
+    fn index_mut(&mut self, index: Refinement<usize, LessThan<N>>) -> &mut T {
+        get_mut(self, index.0)
+    }
+}
+
+/// Vectors whose length is refined to be at least `N` can be indexed by refinements that are less
+/// than `N`.
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use refined::{prelude::*, boundable::unsigned::{GreaterThanEqual, LessThan}};
+///
+/// let scores = Refinement::<Vec<u8>, GreaterThanEqual<3>>::refine(vec![7, 8, 9, 10]).unwrap();
+/// let podium = Refinement::<usize, LessThan<3>>::refine(2).unwrap();
+/// assert_eq!(scores[podium], 9);
+/// ```
+#[cfg(all(feature = "alloc", feature = "implication"))]
+#[doc(cfg(all(feature = "alloc", feature = "implication")))]
+impl<T, P: UnsignedMin<Vec<T>>, const N: usize> Index<Refinement<usize, LessThan<N>>>
+    for Refinement<Vec<T>, P>
+where
+    Assert<{ P::UMIN >= N }>: IsTrue,
+{
+    type Output = T;
+
+    fn index(&self, index: Refinement<usize, LessThan<N>>) -> &T {
+        get(&self.0, index.0)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "implication"))]
+#[doc(cfg(all(feature = "alloc", feature = "implication")))]
+impl<T, P: UnsignedMin<Vec<T>>, const N: usize> IndexMut<Refinement<usize, LessThan<N>>>
+    for Refinement<Vec<T>, P>
+where
+    Assert<{ P::UMIN >= N }>: IsTrue,
+{
+    fn index_mut(&mut self, index: Refinement<usize, LessThan<N>>) -> &mut T {
+        get_mut(&mut self.0, index.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::LessThan, *};
+
+    #[test]
+    fn test_array_index() {
+        let mut values = [1, 2, 3, 4];
+        let index = Refinement::<usize, LessThan<4>>::refine(3).unwrap();
+        assert_eq!(values[index], 4);
+        values[index] = 5;
+        assert_eq!(values, [1, 2, 3, 5]);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "implication"))]
+    #[test]
+    fn test_vec_index() {
+        use alloc::{vec, vec::Vec};
+
+        use crate::boundable::unsigned::{ClosedInterval, GreaterThan};
+
+        let values = Refinement::<Vec<char>, ClosedInterval<2, 5>>::refine(vec!['a', 'b']).unwrap();
+        let index = Refinement::<usize, LessThan<2>>::refine(1).unwrap();
+        assert_eq!(values[index], 'b');
+
+        let mut values = Refinement::<Vec<char>, GreaterThan<0>>::refine(vec!['z']).unwrap();
+        values[Refinement::<usize, LessThan<1>>::refine(0).unwrap()] = 'y';
+        assert_eq!(
+            values[Refinement::<usize, LessThan<1>>::refine(0).unwrap()],
+            'y'
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use core::marker::PhantomData;
+
+use crate::{Predicate, Refinement};
+
+/// Produces any value of the base type that satisfies the predicate, so that proof harnesses can
+/// take refinements as inputs with `kani::any()` and rely on their invariants.
+#[doc(cfg(feature = "kani"))]
+impl<T: kani::Arbitrary, P: Predicate<T>> kani::Arbitrary for Refinement<T, P> {
+    fn any() -> Self {
+        let value = T::any();
+        kani::assume(P::test(&value));
+        Self(value, PhantomData)
+    }
+}
+
+mod proofs {
+    use crate::{boundable::unsigned, Predicate, Refinement, RefinementOps};
+
+    type Size = Refinement<u8, unsigned::LessThan<100>>;
+
+    #[kani::proof]
+    fn refine_upholds_predicate() {
+        if let Ok(size) = Size::refine(kani::any()) {
+            assert!(*size < 100);
+        }
+    }
+
+    #[kani::proof]
+    fn modify_upholds_predicate() {
+        let size: Size = kani::any();
+        let amount: u8 = kani::any();
+        if let Ok(size) = size.modify(|size| size.wrapping_add(amount)) {
+            assert!(unsigned::LessThan::<100>::test(&*size));
+        }
+    }
+
+    #[cfg(feature = "arithmetic")]
+    #[kani::proof]
+    fn arithmetic_upholds_predicates() {
+        use crate::boundable::signed;
+
+        // Each result is checked against its predicate as it is constructed.
+        let a: Refinement<u8, unsigned::LessThan<10>> = kani::any();
+        let b: Refinement<u8, unsigned::ClosedInterval<1, 20>> = kani::any();
+        let _ = a + b;
+        let _ = a * b;
+        let _ = a / b;
+
+        let c: Refinement<i8, signed::ClosedInterval<-10, 10>> = kani::any();
+        let d: Refinement<i8, signed::ClosedInterval<-5, 5>> = kani::any();
+        let _ = c + d;
+        let _ = c - d;
+    }
+}
@@ -0,0 +1,155 @@
+use core::{marker::PhantomData, ops::Deref};
+
+use crate::{
+    Refined, RefinementError, RefinementOps, StatefulPredicate, StatefulRefinementOps, TypeString,
+};
+
+/// A refinement type `R` whose errors carry the message `M` in place of the predicate's own
+/// description.
+///
+/// Predicate descriptions are written for developers; `WithMessage` allows a refinement to carry
+/// copy that is suitable to show to end users instead. The structure of the error, including its
+/// [kind](RefinementError::kind) and [violations](RefinementError::violations), is preserved.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::ClosedInterval};
+///
+/// type_string!(PasswordLength, "Password must be 8-64 characters");
+///
+/// type Password = WithMessage<PasswordLength, Refinement<String, ClosedInterval<8, 64>>>;
+///
+/// let err = Password::refine("hunter2".to_string()).unwrap_err();
+/// assert_eq!(err.message(), "Password must be 8-64 characters");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct WithMessage<M: TypeString, R: RefinementOps>(R, PhantomData<M>);
+
+impl<M: TypeString, R: RefinementOps> Deref for WithMessage<M, R> {
+    type Target = R::T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+impl<M: TypeString, R: RefinementOps> AsRef<R> for WithMessage<M, R> {
+    fn as_ref(&self) -> &R {
+        &self.0
+    }
+}
+
+impl<M: TypeString, R: RefinementOps> TryFrom<Refined<R::T>> for WithMessage<M, R> {
+    type Error = RefinementError;
+
+    fn try_from(value: Refined<R::T>) -> Result<Self, Self::Error> {
+        match R::refine(value.0) {
+            Ok(value) => Ok(Self(value, PhantomData)),
+            Err(err) => Err(err.with_message(M::VALUE.into())),
+        }
+    }
+}
+
+impl<M: TypeString, R: RefinementOps> From<WithMessage<M, R>> for Refined<R::T> {
+    fn from(value: WithMessage<M, R>) -> Self {
+        Refined(value.take())
+    }
+}
+
+impl<M: TypeString, R: RefinementOps> RefinementOps for WithMessage<M, R> {
+    type T = R::T;
+
+    fn take(self) -> Self::T {
+        self.0.take()
+    }
+
+    fn extract(self) -> Self::T {
+        self.0.take()
+    }
+}
+
+impl<M: TypeString, T, P: StatefulPredicate<T>, R: StatefulRefinementOps<T, P>>
+    StatefulRefinementOps<T, P> for WithMessage<M, R>
+{
+    fn refine_with_state(predicate: &P, value: T) -> Result<Self, RefinementError> {
+        match R::refine_with_state(predicate, value) {
+            Ok(value) => Ok(Self(value, PhantomData)),
+            Err(err) => Err(err.with_message(M::VALUE.into())),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<M: TypeString, R: RefinementOps> serde::Serialize for WithMessage<M, R>
+where
+    R::T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.deref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, M: TypeString, R: RefinementOps> serde::Deserialize<'de> for WithMessage<M, R>
+where
+    R::T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let refined = Refined::<R::T>::deserialize(deserializer)?;
+        Self::try_from(refined).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boolean::And, boundable::unsigned::*, *};
+    use alloc::format;
+
+    type_string!(Percent, "please enter a percentage");
+
+    type Test = WithMessage<Percent, Refinement<u8, And<GreaterThan<0>, LessThanEqual<100>>>>;
+
+    #[test]
+    fn test_with_message_success() {
+        assert_eq!(*Test::refine(50).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_with_message_failure() {
+        let err = Test::refine(101).unwrap_err();
+        assert_eq!(err.kind(), error::ErrorKind::And);
+        assert_eq!(err.violations().len(), 1);
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: please enter a percentage"
+        );
+    }
+
+    #[test]
+    fn test_with_message_named() {
+        type_string!(Score, "score");
+        let err = Named::<Score, Test>::refine(0).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: score please enter a percentage"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_with_message_deserialize_failure() {
+        let err = serde_json::from_str::<Test>("200").unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: please enter a percentage"
+        );
+    }
+}
@@ -1,11 +1,79 @@
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "clap")]
+mod clap;
+mod convert;
+#[cfg(feature = "diesel")]
+mod diesel;
+mod display;
+#[cfg(feature = "alloc")]
+mod dyn_named;
+#[cfg(feature = "fake")]
+mod fake;
+mod guard;
+mod index;
+#[cfg(all(kani, feature = "kani"))]
+mod kani;
+#[cfg(feature = "alloc")]
+mod message;
 #[cfg(feature = "alloc")]
 mod named;
+mod nested;
+mod numeric;
+#[cfg(feature = "postgres-types")]
+mod postgres;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "rocket")]
+mod rocket;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+mod tagged;
+#[cfg(feature = "wasm")]
+mod wasm;
 
 use core::{fmt::Display, marker::PhantomData};
 
+#[doc(cfg(feature = "clap"))]
+#[cfg(feature = "clap")]
+pub use self::clap::RefinedValueParser;
+#[doc(cfg(feature = "quickcheck"))]
+#[cfg(feature = "quickcheck")]
+pub use self::quickcheck::ArbitraryWithin;
+#[doc(cfg(feature = "rand"))]
+#[cfg(feature = "rand")]
+pub use self::rand::{Convex, UniformRefinement};
+#[doc(cfg(feature = "rayon"))]
+#[cfg(feature = "rayon")]
+pub use self::rayon::ParRefine;
+pub use display::DisplayRefined;
+#[doc(cfg(feature = "alloc"))]
+#[cfg(feature = "alloc")]
+pub use dyn_named::*;
+pub use guard::*;
+#[doc(cfg(feature = "alloc"))]
+#[cfg(feature = "alloc")]
+pub use message::*;
 #[doc(cfg(feature = "alloc"))]
 #[cfg(feature = "alloc")]
 pub use named::*;
+#[doc(cfg(feature = "rocket"))]
+#[cfg(feature = "rocket")]
+pub use rocket::ParamError;
+#[doc(cfg(all(feature = "serde", feature = "alloc")))]
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub use tagged::Tagged;
 
 #[cfg(all(feature = "serde", feature = "alloc"))]
 use serde::Deserialize;
@@ -13,14 +81,55 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::{
+    boundable::{Clamp, Saturate},
     Predicate, Refined, RefinementError, RefinementOps, StatefulPredicate, StatefulRefinementOps,
 };
 
 #[cfg(feature = "implication")]
 use crate::Implies;
 
+/// Reports a failed refinement of `T` by `P`, returning the error unchanged.
+#[cfg_attr(not(feature = "tracing"), allow(clippy::extra_unused_type_parameters))]
+fn rejected<T, P: Predicate<T>>(err: RefinementError) -> RefinementError {
+    #[cfg(feature = "tracing")]
+    crate::trace::report::<T, P>(&err);
+    err
+}
+
+/// Fails to compile when the [bounds](Predicate::BOUNDS) of `P` are empty, so that refinements that
+/// could never be constructed are rejected where they're used.
+struct Satisfiable<T, P>(PhantomData<T>, PhantomData<P>);
+
+impl<T, P: Predicate<T>> Satisfiable<T, P> {
+    const CHECK: () = if let Some((min, max)) = P::BOUNDS {
+        assert!(
+            min <= max,
+            "no value satisfies the bounds of this predicate"
+        );
+    };
+}
+
 /// A refinement of a type `T` certifying that the [Predicate] `P` holds.
+///
+/// Refinements of most common base types can be created directly from raw values using the
+/// standard conversion traits, without importing [RefinementOps]:
+///
+/// ```
+/// use refined::{Refinement, RefinementError, boundable::unsigned::LessThan};
+///
+/// fn parse_size(raw: u8) -> Result<Refinement<u8, LessThan<100>>, RefinementError> {
+///     let size = raw.try_into()?;
+///     Ok(size)
+/// }
+///
+/// assert_eq!(*parse_size(99).unwrap(), 99);
+/// assert!(parse_size(100).is_err());
+/// ```
+///
+/// Refinements have the same layout as their base type, so that collections of values that are
+/// known to satisfy `P` can be reinterpreted as collections of refinements.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
 pub struct Refinement<T, P: Predicate<T>>(pub(crate) T, pub(crate) PhantomData<P>);
 
 #[doc(cfg(feature = "serde"))]
@@ -41,14 +150,381 @@ impl<'de, T: Deserialize<'de>, P: Predicate<T>> Deserialize<'de> for Refinement<
     where
         D: serde::Deserializer<'de>,
     {
-        let refined = Refined::<T>::deserialize(deserializer)?;
-        Ok(Self::try_from(refined).map_err(serde::de::Error::custom)?)
+        let result = Self::try_from(Refined::<T>::deserialize(deserializer)?);
+        #[cfg(feature = "std")]
+        let result = result.map_err(|err| {
+            crate::de::collect(err.clone());
+            crate::de::named(err)
+        });
+        result.map_err(serde::de::Error::custom)
+    }
+}
+
+/// Refinements are valid by construction, so validation always succeeds. This allows refined fields
+/// to be marked `#[validate(nested)]` in structs that derive [validator::Validate].
+#[doc(cfg(feature = "validator"))]
+#[cfg(feature = "validator")]
+impl<T, P: Predicate<T>> validator::Validate for Refinement<T, P> {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        Ok(())
+    }
+}
+
+#[doc(cfg(feature = "validator"))]
+#[cfg(feature = "validator")]
+impl<L: PartialEq + PartialOrd, T: validator::ValidateLength<L>, P: Predicate<T>>
+    validator::ValidateLength<L> for Refinement<T, P>
+{
+    fn length(&self) -> Option<L> {
+        self.0.length()
+    }
+}
+
+#[doc(cfg(feature = "validator"))]
+#[cfg(feature = "validator")]
+impl<R, T: validator::ValidateRange<R>, P: Predicate<T>> validator::ValidateRange<R>
+    for Refinement<T, P>
+{
+    fn greater_than(&self, max: R) -> Option<bool> {
+        self.0.greater_than(max)
+    }
+
+    fn less_than(&self, min: R) -> Option<bool> {
+        self.0.less_than(min)
+    }
+}
+
+impl<T, P: Clamp<T>> Refinement<T, P> {
+    /// Refines a runtime value, first clamping it into the bounds of the predicate.
+    ///
+    /// An error is only returned if the clamped value still fails the predicate, which can happen
+    /// when a bound is not representable by `T` or the interval is empty.
+    ///
+    /// ```
+    /// use refined::{Refinement, boundable::unsigned::ClosedInterval};
+    ///
+    /// type Threads = Refinement<u8, ClosedInterval<1, 64>>;
+    /// assert_eq!(*Threads::refine_clamped(0).unwrap(), 1);
+    /// assert_eq!(*Threads::refine_clamped(128).unwrap(), 64);
+    /// ```
+    pub fn refine_clamped(value: T) -> Result<Self, RefinementError> {
+        Self::try_from(Refined(P::clamp(value)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+impl<T: Display, P: Predicate<T>> Refinement<T, P> {
+    /// Attempts to refine a runtime value, capturing a rendering of the value in the error if
+    /// the predicate does not hold.
+    ///
+    /// [RefinementOps::refine] never captures the rejected value, so that sensitive data does not
+    /// leak into logs by default.
+    ///
+    /// ```
+    /// use refined::{Refinement, boundable::unsigned::LessThan};
+    ///
+    /// let err = Refinement::<u8, LessThan<100>>::refine_verbose(123).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "refinement violated: must be less than 100 (got 123)"
+    /// );
+    /// ```
+    pub fn refine_verbose(value: T) -> Result<Self, RefinementError> {
+        let () = Satisfiable::<T, P>::CHECK;
+        let value = P::normalize(value);
+        if P::test(&value) {
+            Ok(Self(value, PhantomData))
+        } else {
+            Err(rejected::<T, P>(P::violation(&value).with_value(&value)))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+impl<P> Refinement<alloc::string::String, P>
+where
+    P: Predicate<alloc::string::String> + for<'a> Predicate<&'a str>,
+{
+    /// Tests a borrowed string against the predicate without allocating.
+    ///
+    /// The string is [normalized](Predicate::normalize) as a `&str` before it is tested, so
+    /// predicates that can only normalize owned strings, such as `Normalize<Lowercase, _>`, are
+    /// not supported.
+    ///
+    /// ```
+    /// use refined::{Refinement, boundable::unsigned::GreaterThan};
+    ///
+    /// type NonEmptyString = Refinement<String, GreaterThan<0>>;
+    /// assert!(NonEmptyString::check("foo").is_ok());
+    /// assert_eq!(
+    ///     NonEmptyString::check("").unwrap_err().to_string(),
+    ///     "refinement violated: must be greater than 0"
+    /// );
+    /// ```
+    pub fn check(value: &str) -> Result<(), RefinementError> {
+        Self::checked(value).map(|_| ())
+    }
+
+    /// Attempts to refine a borrowed string, only allocating an owned copy once the predicate is
+    /// known to hold.
+    ///
+    /// ```
+    /// use refined::{Refinement, boundable::unsigned::GreaterThan, normalize::{Normalize, Trim}};
+    ///
+    /// type Username = Refinement<String, Normalize<Trim, GreaterThan<0>>>;
+    /// assert_eq!(*Username::refine_from("  alice ").unwrap(), "alice");
+    /// assert!(Username::refine_from("   ").is_err());
+    /// ```
+    pub fn refine_from(value: &str) -> Result<Self, RefinementError> {
+        let value = alloc::string::String::from(Self::checked(value)?);
+        debug_assert!(<P as Predicate<alloc::string::String>>::test(&value));
+        Ok(Self(value, PhantomData))
+    }
+
+    /// Normalizes and tests a borrowed string, returning the normalized string if it satisfies
+    /// the predicate.
+    fn checked(value: &str) -> Result<&str, RefinementError> {
+        let () = Satisfiable::<alloc::string::String, P>::CHECK;
+        let value = <P as Predicate<&str>>::normalize(value);
+        if <P as Predicate<&str>>::test(&value) {
+            Ok(value)
+        } else {
+            Err(rejected::<&str, P>(<P as Predicate<&str>>::violation(
+                &value,
+            )))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+impl<T, P: Predicate<T>> Refinement<T, P> {
+    /// Attempts to refine a runtime value directly into a shared, thread-safe pointer, so that
+    /// large immutable values can be shared after being checked once.
+    ///
+    /// To refine a value that is itself behind a shared pointer, such as an `Arc<str>`, refine
+    /// the pointer instead, as in `Refinement<Arc<str>, P>`.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use refined::{Refinement, boundable::unsigned::LessThan, string::Trimmed};
+    ///
+    /// let template = Refinement::<String, Trimmed>::refine_arc("Hello, {name}!".to_string()).unwrap();
+    /// let shared = Arc::clone(&template);
+    /// assert_eq!(shared.as_str(), "Hello, {name}!");
+    ///
+    /// assert!(Refinement::<u8, LessThan<5>>::refine_arc(7).is_err());
+    /// ```
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn refine_arc(value: T) -> Result<alloc::sync::Arc<Self>, RefinementError> {
+        Self::try_from(Refined(value)).map(alloc::sync::Arc::new)
+    }
+
+    /// Attempts to refine a runtime value directly into a shared, single-threaded pointer.
+    ///
+    /// See [refine_arc](Self::refine_arc) for more information.
+    pub fn refine_rc(value: T) -> Result<alloc::rc::Rc<Self>, RefinementError> {
+        Self::try_from(Refined(value)).map(alloc::rc::Rc::new)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+impl<T: Clone, P: Predicate<T>> Refinement<T, P> {
+    /// Explains whether a runtime value can be refined, whether or not it satisfies the predicate.
+    ///
+    /// The value is [normalized](Predicate::normalize) before it is explained, as it would be
+    /// when refined.
+    pub fn explain(value: &T) -> crate::explain::Explanation {
+        P::explain(&P::normalize(value.clone()))
+    }
+
+    /// Explains why a runtime value can't be refined, or returns `None` if it can.
+    ///
+    /// The value is [normalized](Predicate::normalize) before it is explained, as it would be
+    /// when refined.
+    ///
+    /// ```
+    /// use refined::{Refinement, boolean::And, boundable::unsigned::{GreaterThan, LessThan}};
+    ///
+    /// type Test = Refinement<u8, And<GreaterThan<5>, LessThan<10>>>;
+    /// let explanation = Test::why_invalid(&12).unwrap();
+    /// assert!(explanation.children[0].satisfied);
+    /// assert!(!explanation.children[1].satisfied);
+    /// assert!(Test::why_invalid(&7).is_none());
+    /// ```
+    pub fn why_invalid(value: &T) -> Option<crate::explain::Explanation> {
+        let value = P::normalize(value.clone());
+        (!P::test(&value)).then(|| P::explain(&value))
+    }
+}
+
+impl<T, P: Saturate<T>> Refinement<T, P> {
+    /// Refines a runtime value, first saturating it to the bound of the predicate.
+    ///
+    /// An error is only returned if the saturated value still fails the predicate, which can
+    /// happen when the bound is not representable by `T`.
+    ///
+    /// ```
+    /// use refined::{Refinement, boundable::unsigned::LessThan};
+    ///
+    /// type Percent = Refinement<u8, LessThan<101>>;
+    /// assert_eq!(*Percent::refine_saturating(250).unwrap(), 100);
+    /// ```
+    pub fn refine_saturating(value: T) -> Result<Self, RefinementError> {
+        Self::try_from(Refined(P::saturate(value)))
+    }
+}
+
+impl<T, P: Predicate<T>> Refinement<T, P> {
+    /// Attempts to refine a runtime value, returning the value, after any
+    /// [normalization](Predicate::normalize), along with the error if it is rejected, so that it
+    /// can be reported or recovered.
+    ///
+    /// ```
+    /// use refined::{Refinement, boundable::unsigned::LessThan};
+    ///
+    /// let (value, err) = Refinement::<u8, LessThan<5>>::refine_or_return(7).unwrap_err();
+    /// assert_eq!(value, 7);
+    /// assert_eq!(err.to_string(), "refinement violated: must be less than 5");
+    /// ```
+    pub fn refine_or_return(value: T) -> Result<Self, (T, RefinementError)> {
+        let () = Satisfiable::<T, P>::CHECK;
+        let value = P::normalize(value);
+        if P::test(&value) {
+            Ok(Self(value, PhantomData))
+        } else {
+            let err = rejected::<T, P>(P::violation(&value));
+            Err((value, err))
+        }
+    }
+
+    /// Attempts to convert this refinement into a refinement of the same value under a different
+    /// predicate, checking the target predicate at runtime.
+    ///
+    /// This is the runtime-checked counterpart of `Implies` for conversions that cannot be proven
+    /// statically.
+    ///
+    /// ```
+    /// use refined::{Refinement, RefinementOps, boundable::unsigned::{LessThan, GreaterThan}};
+    ///
+    /// let value = Refinement::<u8, LessThan<100>>::refine(50).unwrap();
+    /// let converted = value.try_imply::<GreaterThan<10>>().unwrap();
+    /// assert_eq!(*converted, 50);
+    ///
+    /// let value = Refinement::<u8, LessThan<100>>::refine(5).unwrap();
+    /// assert!(value.try_imply::<GreaterThan<10>>().is_err());
+    /// ```
+    pub fn try_imply<Q: Predicate<T>>(self) -> Result<Refinement<T, Q>, RefinementError> {
+        Refinement::try_from(Refined(self.0))
+    }
+
+    /// Mutates the refined value in place, re-certifying that the predicate still holds after the
+    /// mutation is complete.
+    ///
+    /// The mutation is applied to a copy of the value, which is [normalized](Predicate::normalize)
+    /// and replaces the value only if the predicate still holds. Otherwise, or if `f` panics, the original value is kept and, in the
+    /// former case, an error is returned.
+    ///
+    /// ```
+    /// use refined::{Refinement, RefinementOps, boundable::unsigned::LessThan};
+    ///
+    /// let mut value = Refinement::<u8, LessThan<5>>::refine(3).unwrap();
+    /// assert!(value.try_update(|v| *v += 1).is_ok());
+    /// assert_eq!(*value, 4);
+    /// assert!(value.try_update(|v| *v += 1).is_err());
+    /// assert_eq!(*value, 4);
+    /// ```
+    pub fn try_update<F: FnOnce(&mut T)>(&mut self, f: F) -> Result<(), RefinementError>
+    where
+        T: Clone,
+    {
+        let mut value = self.0.clone();
+        f(&mut value);
+        let value = P::normalize(value);
+        if P::test(&value) {
+            self.0 = value;
+            Ok(())
+        } else {
+            Err(rejected::<T, P>(P::violation(&value)))
+        }
+    }
+
+    /// Mutates the refined value in place with `apply`, returning its result, or reverting the
+    /// mutation with `undo` if the predicate no longer holds. Unlike
+    /// [try_update](Self::try_update), the original value is not cloned, so containers can
+    /// maintain their refinement through cheap, reversible operations.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn try_mutate<R>(
+        &mut self,
+        apply: impl FnOnce(&mut T) -> R,
+        undo: impl FnOnce(&mut T, R),
+    ) -> Result<R, RefinementError> {
+        let applied = apply(&mut self.0);
+        if P::test(&self.0) {
+            Ok(applied)
+        } else {
+            let err = rejected::<T, P>(P::violation(&self.0));
+            undo(&mut self.0, applied);
+            Err(err)
+        }
+    }
+
+    /// Transforms the refined value without re-checking the predicate.
+    ///
+    /// The predicate is still checked in debug builds.
+    ///
+    /// # Safety
+    ///
+    /// `f` must produce a value satisfying `P`. Violating this is undefined behavior when the
+    /// `optimized` feature is enabled.
+    pub unsafe fn map_unchecked<F: FnOnce(T) -> T>(self, f: F) -> Self {
+        let value = f(self.0);
+        debug_assert!(P::test(&value));
+        Self(value, PhantomData)
+    }
+
+    /// Wraps a value that is known to satisfy `P`, such as the result of arithmetic on refinements
+    /// whose bounds imply it.
+    ///
+    /// The predicate is still checked in debug builds, and is a precondition for Kani.
+    #[cfg(feature = "implication")]
+    #[cfg_attr(all(kani, feature = "kani"), ::kani::requires(P::test(&value)))]
+    pub(crate) fn proven(value: T) -> Self {
+        debug_assert!(P::test(&value));
+        Self(value, PhantomData)
     }
 }
 
 impl<T, P: Predicate<T>> RefinementOps for Refinement<T, P> {
     type T = T;
 
+    #[cfg_attr(
+        all(kani, feature = "kani"),
+        ::kani::ensures(|result: &Result<Self, RefinementError>| {
+            result.as_ref().map_or(true, |refined| P::test(&refined.0))
+        })
+    )]
+    fn refine(value: T) -> Result<Self, RefinementError> {
+        Self::try_from(Refined(value))
+    }
+
+    #[cfg_attr(all(kani, feature = "kani"), ::kani::requires(P::test(&self.0)))]
+    #[cfg_attr(
+        all(kani, feature = "kani"),
+        ::kani::ensures(|result: &Result<Self, RefinementError>| {
+            result.as_ref().map_or(true, |refined| P::test(&refined.0))
+        })
+    )]
+    fn modify<F>(self, fun: F) -> Result<Self, RefinementError>
+    where
+        F: FnOnce(T) -> T,
+    {
+        Self::refine(fun(self.take()))
+    }
+
     fn take(self) -> T {
         #[cfg(feature = "optimized")]
         unsafe {
@@ -68,7 +544,7 @@ impl<T, P: Predicate<T>> RefinementOps for Refinement<T, P> {
 
 impl<T: Display, P: Predicate<T>> Display for Refinement<T, P> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", &self.0)
+        write!(f, "{}", self.0)
     }
 }
 
@@ -94,32 +570,30 @@ impl<T, P: Predicate<T>> TryFrom<Refined<T>> for Refinement<T, P> {
     type Error = RefinementError;
 
     fn try_from(value: Refined<T>) -> Result<Self, Self::Error> {
-        if P::test(&value.0) {
-            Ok(Self(value.0, PhantomData))
-        } else {
-            Err(RefinementError(P::error()))
-        }
+        Self::refine_or_return(value.0).map_err(|(_, err)| err)
     }
 }
 
 #[doc(cfg(feature = "implication"))]
 #[cfg(feature = "implication")]
-impl<F, T, Type> Implies<Refinement<Type, T>> for Refinement<Type, F>
+impl<F, T, Type, Proof> Implies<Refinement<Type, T>, Proof> for Refinement<Type, F>
 where
-    F: Predicate<Type> + Implies<T>,
+    F: Predicate<Type> + Implies<T, Proof>,
     T: Predicate<Type>,
 {
     fn imply(self) -> Refinement<Type, T> {
-        Refinement(self.0, PhantomData)
+        Refinement::proven(self.0)
     }
 }
 
 impl<T, P: StatefulPredicate<T>> StatefulRefinementOps<T, P> for Refinement<T, P> {
     fn refine_with_state(predicate: &P, value: T) -> Result<Self, RefinementError> {
+        let () = Satisfiable::<T, P>::CHECK;
+        let value = <P as Predicate<T>>::normalize(value);
         if predicate.test(&value) {
             Ok(Self(value, PhantomData))
         } else {
-            Err(RefinementError(predicate.error()))
+            Err(rejected::<T, P>(predicate.violation(&value)))
         }
     }
 }
@@ -154,6 +628,148 @@ mod tests {
         assert_eq!(serialized, "4");
     }
 
+    #[test]
+    fn test_refinement_serialize_does_not_test() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static TESTS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted;
+
+        impl Predicate<u8> for Counted {
+            fn test(_: &u8) -> bool {
+                TESTS.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+
+            fn error() -> ErrorMessage {
+                ErrorMessage::from("counted")
+            }
+        }
+
+        let value = serde_json::from_str::<Refinement<u8, Counted>>("4").unwrap();
+        assert_eq!(TESTS.load(Ordering::Relaxed), 1);
+        assert_eq!(serde_json::to_string(&value).unwrap(), "4");
+        assert_eq!(TESTS.load(Ordering::Relaxed), 1);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        name: Refinement<alloc::string::String, string::Trimmed>,
+        size: Refinement<u8, boundable::unsigned::LessThan<100>>,
+        offset: Refinement<i64, boundable::signed::GreaterThan<-10>>,
+        note: Option<Refinement<alloc::string::String, string::Trimmed>>,
+        sizes: alloc::vec::Vec<Refinement<u16, boundable::unsigned::LessThan<1000>>>,
+    }
+
+    /// The same shape as [Order] without any refinements, as another service might encode it.
+    #[derive(Serialize)]
+    struct RawOrder<'a> {
+        name: &'a str,
+        size: u8,
+        offset: i64,
+        note: Option<&'a str>,
+        sizes: &'a [u16],
+    }
+
+    fn raw_order(size: u8) -> RawOrder<'static> {
+        RawOrder {
+            name: "widget",
+            size,
+            offset: -5,
+            note: Some("fragile"),
+            sizes: &[1, 999],
+        }
+    }
+
+    #[test]
+    fn test_refinement_postcard_round_trip() {
+        let bytes = postcard::to_allocvec(&raw_order(99)).unwrap();
+        let order = postcard::from_bytes::<Order>(&bytes).unwrap();
+        assert_eq!(*order.name, "widget");
+        assert_eq!(*order.size, 99);
+        assert_eq!(*order.offset, -5);
+        assert_eq!(
+            order.note.as_deref().map(|note| note.as_str()),
+            Some("fragile")
+        );
+        assert_eq!(
+            order
+                .sizes
+                .iter()
+                .map(|size| **size)
+                .collect::<alloc::vec::Vec<_>>(),
+            [1, 999]
+        );
+
+        // Refinements are encoded exactly as their base values.
+        assert_eq!(postcard::to_allocvec(&order).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_refinement_postcard_failure() {
+        let bytes = postcard::to_allocvec(&raw_order(100)).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<Order>(&bytes).unwrap_err(),
+            postcard::Error::SerdeDeCustom
+        );
+
+        let mut raw = raw_order(1);
+        raw.sizes = &[1000];
+        let bytes = postcard::to_allocvec(&raw).unwrap();
+        assert!(postcard::from_bytes::<Order>(&bytes).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_refinement_bincode() {
+        let bytes = bincode::serialize(&raw_order(99)).unwrap();
+        let order = bincode::deserialize::<Order>(&bytes).unwrap();
+        assert_eq!(bincode::serialize(&order).unwrap(), bytes);
+
+        let bytes = bincode::serialize(&raw_order(100)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Order>(&bytes)
+                .unwrap_err()
+                .to_string(),
+            "refinement violated: must be less than 100"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_refinement_cbor() {
+        let mut bytes = std::vec::Vec::new();
+        ciborium::into_writer(&raw_order(99), &mut bytes).unwrap();
+        let order = ciborium::from_reader::<Order, _>(bytes.as_slice()).unwrap();
+        let mut encoded = std::vec::Vec::new();
+        ciborium::into_writer(&order, &mut encoded).unwrap();
+        assert_eq!(encoded, bytes);
+
+        let mut bytes = std::vec::Vec::new();
+        ciborium::into_writer(&raw_order(100), &mut bytes).unwrap();
+        let err = ciborium::from_reader::<Order, _>(bytes.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Semantic(None, \"refinement violated: must be less than 100\")"
+        );
+    }
+
+    #[cfg(feature = "validator")]
+    #[test]
+    fn test_refinement_validator() {
+        use validator::{Validate, ValidateLength, ValidateRange};
+
+        let value = Refinement::<String, crate::string::Trimmed>::refine("foo".into()).unwrap();
+        assert!(value.validate().is_ok());
+        assert!(value.validate_length(Some(1u64), Some(3), None));
+        assert!(!value.validate_length(Some(4u64), None, None));
+
+        let value = Refinement::<u8, boundable::unsigned::LessThan<10>>::refine(5).unwrap();
+        assert!(value.validate_range(Some(1), Some(5), None, None));
+        assert!(!value.validate_range(None, None, None, Some(5)));
+    }
+
     #[test]
     fn test_refinement_modify_success() {
         let value = Refinement::<u8, boundable::unsigned::LessThan<5>>(3, PhantomData);
@@ -194,4 +810,125 @@ mod tests {
         let extracted = value.take();
         assert_eq!(extracted, 4);
     }
+
+    #[test]
+    fn test_refinement_try_imply() {
+        let value = Refinement::<u8, boundable::unsigned::LessThan<5>>(4, PhantomData);
+        let converted = value
+            .try_imply::<boundable::unsigned::GreaterThan<2>>()
+            .unwrap();
+        assert_eq!(*converted, 4);
+
+        let value = Refinement::<u8, boundable::unsigned::LessThan<5>>(1, PhantomData);
+        let err = value
+            .try_imply::<boundable::unsigned::GreaterThan<2>>()
+            .unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be greater than 2"
+        );
+    }
+
+    #[test]
+    fn test_refinement_map_unchecked() {
+        let value = Refinement::<u8, boundable::unsigned::LessThan<5>>(4, PhantomData);
+        let mapped = unsafe { value.map_unchecked(|x| x - 1) };
+        assert_eq!(*mapped, 3);
+    }
+
+    #[test]
+    fn test_refinement_try_update() {
+        let mut value = Refinement::<u8, boundable::unsigned::LessThan<5>>(3, PhantomData);
+        value.try_update(|x| *x += 1).unwrap();
+        assert_eq!(*value, 4);
+
+        let err = value.try_update(|x| *x += 1).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be less than 5"
+        );
+        assert_eq!(*value, 4);
+    }
+
+    #[test]
+    fn test_refinement_try_update_normalizes() {
+        use normalize::{Normalize, Trim};
+
+        type Test =
+            Refinement<alloc::string::String, Normalize<Trim, boundable::unsigned::LessThan<5>>>;
+        let mut value = Test::refine(alloc::string::String::from(" ab ")).unwrap();
+        value.try_update(|v| v.push_str("c ")).unwrap();
+        assert_eq!(*value, "abc");
+        assert!(value.try_update(|v| v.push_str("def")).is_err());
+        assert_eq!(*value, "abc");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_refinement_try_update_panic() {
+        let mut value = Refinement::<u8, boundable::unsigned::LessThan<5>>(3, PhantomData);
+        let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            value.try_update(|x| {
+                *x = 42;
+                panic!("update panicked");
+            })
+        }));
+        assert!(panic.is_err());
+        assert_eq!(*value, 3);
+    }
+
+    #[test]
+    fn test_refinement_refine_from() {
+        type Test = Refinement<
+            alloc::string::String,
+            boolean::And<string::Trimmed, boundable::unsigned::LessThan<5>>,
+        >;
+        assert!(Test::check("abc").is_ok());
+        assert_eq!(
+            format!("{}", Test::check(" abc").unwrap_err()),
+            "refinement violated: must not start or end with whitespace"
+        );
+        assert_eq!(*Test::refine_from("abcd").unwrap(), "abcd");
+        assert_eq!(
+            format!("{}", Test::refine_from("abcde").unwrap_err()),
+            "refinement violated: must be less than 5"
+        );
+    }
+
+    #[test]
+    fn test_refinement_shared() {
+        use alloc::{rc::Rc, sync::Arc};
+
+        type Test =
+            Refinement<Arc<str>, boolean::And<string::Trimmed, boundable::unsigned::LessThan<5>>>;
+        let value = Test::refine(Arc::from("abcd")).unwrap();
+        assert_eq!(&**value, "abcd");
+        assert!(Test::refine(Arc::from("abcde")).is_err());
+        assert!(Test::try_from(Arc::<str>::from(" abc")).is_err());
+
+        type Shared = Refinement<Rc<[u8]>, collection::All<boundable::unsigned::LessThan<5>>>;
+        assert!(Shared::refine(Rc::from([1, 2, 3])).is_ok());
+        assert!(Shared::refine(Rc::from([1, 2, 7])).is_err());
+
+        let value = Refinement::<alloc::string::String, string::Trimmed>::refine_arc(
+            alloc::string::String::from("abc"),
+        )
+        .unwrap();
+        assert_eq!(Arc::clone(&value).as_str(), "abc");
+        assert!(Refinement::<u8, boundable::unsigned::LessThan<5>>::refine_rc(7).is_err());
+    }
+
+    #[test]
+    fn test_refinement_refine_verbose() {
+        let err =
+            Refinement::<u8, boundable::unsigned::LessThan<5>>::refine_verbose(7).unwrap_err();
+        assert_eq!(err.value(), Some("7"));
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be less than 5 (got 7)"
+        );
+
+        let err = Refinement::<u8, boundable::unsigned::LessThan<5>>::refine(7).unwrap_err();
+        assert_eq!(err.value(), None);
+    }
 }
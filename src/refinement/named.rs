@@ -1,4 +1,3 @@
-use alloc::format;
 use core::{marker::PhantomData, ops::Deref};
 
 use crate::{
@@ -45,9 +44,11 @@ impl<N: TypeString, R: RefinementOps> TryFrom<Refined<R::T>> for Named<N, R> {
     type Error = RefinementError;
 
     fn try_from(value: Refined<R::T>) -> Result<Self, Self::Error> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(target: "refined", "named", name = N::VALUE).entered();
         match R::refine(value.0) {
             Ok(value) => Ok(Self(value, PhantomData)),
-            Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
+            Err(err) => Err(err.named(N::VALUE)),
         }
     }
 }
@@ -74,9 +75,11 @@ impl<N: TypeString, T, P: StatefulPredicate<T>, R: StatefulRefinementOps<T, P>>
     StatefulRefinementOps<T, P> for Named<N, R>
 {
     fn refine_with_state(predicate: &P, value: T) -> Result<Self, RefinementError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(target: "refined", "named", name = N::VALUE).entered();
         match R::refine_with_state(predicate, value) {
             Ok(value) => Ok(Self(value, PhantomData)),
-            Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
+            Err(err) => Err(err.named(N::VALUE)),
         }
     }
 }
@@ -117,7 +120,7 @@ mod named_serde {
             D: serde::Deserializer<'de>,
         {
             let refined = Refined::<R::T>::deserialize(deserializer)?;
-            Ok(Self::try_from(refined).map_err(serde::de::Error::custom)?)
+            Self::try_from(refined).map_err(serde::de::Error::custom)
         }
     }
 
@@ -148,9 +151,11 @@ mod named_serde {
         type Error = RefinementError;
 
         fn try_from(value: Refined<R::T>) -> Result<Self, Self::Error> {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(target: "refined", "named", name = N::VALUE).entered();
             match R::refine(value.0) {
                 Ok(value) => Ok(Self(value, PhantomData)),
-                Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
+                Err(err) => Err(err.named(N::VALUE)),
             }
         }
     }
@@ -187,9 +192,11 @@ mod named_serde {
         > StatefulRefinementOps<T, P> for NamedSerde<N, R>
     {
         fn refine_with_state(predicate: &P, value: T) -> Result<Self, RefinementError> {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(target: "refined", "named", name = N::VALUE).entered();
             match R::refine_with_state(predicate, value) {
                 Ok(value) => Ok(Self(value, PhantomData)),
-                Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
+                Err(err) => Err(err.named(N::VALUE)),
             }
         }
     }
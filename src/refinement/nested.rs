@@ -0,0 +1,132 @@
+//! Support for nested refinements.
+//!
+//! A `Refinement<Refinement<T, P>, Q>` certifies the same property as a `Refinement<T, And<P, Q>>`
+//! whenever `Q` judges the inner refinement exactly as it would judge the underlying value. The
+//! boundable and string predicates are made to behave this way by delegating [UnsignedBoundable],
+//! [SignedBoundable] and [AsRef] through [Refinement].
+
+use core::marker::PhantomData;
+
+use crate::{
+    boolean::And, Predicate, Refinement, RefinementError, SignedBoundable, UnsignedBoundable,
+};
+
+impl<T: UnsignedBoundable, P: Predicate<T>> UnsignedBoundable for Refinement<T, P> {
+    fn bounding_value(&self) -> usize {
+        self.0.bounding_value()
+    }
+}
+
+impl<T: SignedBoundable, P: Predicate<T>> SignedBoundable for Refinement<T, P> {
+    fn bounding_value(&self) -> isize {
+        self.0.bounding_value()
+    }
+}
+
+impl<T: AsRef<U>, U: ?Sized, P: Predicate<T>> AsRef<U> for Refinement<T, P> {
+    fn as_ref(&self) -> &U {
+        self.0.as_ref()
+    }
+}
+
+impl<T, P, Q> Refinement<Refinement<T, P>, Q>
+where
+    P: Predicate<T>,
+    Q: Predicate<Refinement<T, P>> + Predicate<T>,
+{
+    /// Collapses a nested refinement into a single refinement over the conjunction of both
+    /// predicates.
+    ///
+    /// `Q` is tested again against the underlying value, so that predicates that judge the inner
+    /// refinement differently from the underlying value never produce an invalid refinement.
+    ///
+    /// ```
+    /// use refined::{prelude::*, boolean::And, boundable::unsigned::{GreaterThan, LessThan}};
+    ///
+    /// type Inner = Refinement<u8, GreaterThan<10>>;
+    /// let nested = Refinement::<Inner, LessThan<20>>::refine(Inner::refine(15).unwrap()).unwrap();
+    /// let flat: Refinement<u8, And<GreaterThan<10>, LessThan<20>>> = nested.flatten().unwrap();
+    /// assert_eq!(*flat, 15);
+    /// ```
+    pub fn flatten(self) -> Result<Refinement<T, And<P, Q>>, RefinementError> {
+        let inner = self.0 .0;
+        if <Q as Predicate<T>>::test(&inner) {
+            Ok(Refinement(inner, PhantomData))
+        } else {
+            Err(super::rejected::<T, Q>(<Q as Predicate<T>>::violation(
+                &inner,
+            )))
+        }
+    }
+}
+
+impl<T, P, Q> Refinement<T, And<P, Q>>
+where
+    P: Predicate<T>,
+    Q: Predicate<Refinement<T, P>> + Predicate<T>,
+{
+    /// Splits a conjunctive refinement into a nested refinement; the inverse of
+    /// [flatten](Refinement::flatten).
+    ///
+    /// `Q` is tested again against the inner refinement, as for [flatten](Refinement::flatten).
+    pub fn unflatten(self) -> Result<Refinement<Refinement<T, P>, Q>, RefinementError> {
+        let inner = Refinement(self.0, PhantomData);
+        Refinement::try_from(crate::Refined(inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::And, boundable::unsigned::*, *};
+
+    #[test]
+    fn test_flatten_unflatten() {
+        type Inner = Refinement<u8, GreaterThan<10>>;
+        let nested = Refinement::<Inner, LessThan<20>>::refine(Inner::refine(15).unwrap()).unwrap();
+        let flat: Refinement<u8, And<GreaterThan<10>, LessThan<20>>> = nested.flatten().unwrap();
+        assert_eq!(*flat, 15);
+
+        let nested = flat.unflatten().unwrap();
+        assert_eq!(**nested, 15);
+    }
+
+    /// Judges values by their parity, but refinements of them by their magnitude.
+    struct Inconsistent;
+
+    impl Predicate<u8> for Inconsistent {
+        fn test(value: &u8) -> bool {
+            value.is_multiple_of(2)
+        }
+
+        fn error() -> ErrorMessage {
+            ErrorMessage::from("must be even")
+        }
+    }
+
+    impl Predicate<Refinement<u8, GreaterThan<10>>> for Inconsistent {
+        fn test(value: &Refinement<u8, GreaterThan<10>>) -> bool {
+            **value < 20
+        }
+
+        fn error() -> ErrorMessage {
+            ErrorMessage::from("must be less than 20")
+        }
+    }
+
+    #[test]
+    fn test_flatten_inconsistent() {
+        type Inner = Refinement<u8, GreaterThan<10>>;
+        let nested = Refinement::<Inner, Inconsistent>::refine(Inner::refine(15).unwrap()).unwrap();
+        assert!(nested.flatten().is_err());
+
+        let flat = Refinement::<u8, And<GreaterThan<10>, Inconsistent>>::refine(24).unwrap();
+        assert!(flat.unflatten().is_err());
+    }
+
+    #[test]
+    fn test_nested_refinement_checks_inner_value() {
+        type Inner = Refinement<u8, GreaterThan<10>>;
+        let nested = Refinement::<Inner, LessThan<20>>::refine(Inner::refine(25).unwrap());
+        assert!(nested.is_err());
+    }
+}
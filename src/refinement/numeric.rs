@@ -0,0 +1,266 @@
+use core::{
+    iter::{Product, Sum},
+    marker::PhantomData,
+};
+
+use crate::{
+    boundable::Saturate, error::ErrorKind, ErrorMessage, Predicate, Refinement, RefinementError,
+    RefinementOps,
+};
+
+use super::rejected;
+
+/// The error for arithmetic on a refinement whose result is not representable by its base type.
+fn overflowed<T, P: Predicate<T>>() -> RefinementError {
+    rejected::<T, P>(RefinementError::deferred(ErrorKind::Overflow, || {
+        ErrorMessage::from("must not overflow")
+    }))
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl<P: Predicate<$ty>> Refinement<$ty, P> {
+                /// Adds an unrefined value, checking the predicate against the sum at runtime.
+                ///
+                /// An error is returned if the addition overflows or the sum violates the
+                /// predicate.
+                pub fn checked_add_raw(self, rhs: $ty) -> Result<Self, RefinementError> {
+                    self.0
+                        .checked_add(rhs)
+                        .map_or_else(|| Err(overflowed::<$ty, P>()), Self::refine)
+                }
+
+                /// Subtracts an unrefined value, checking the predicate against the difference at
+                /// runtime.
+                ///
+                /// An error is returned if the subtraction overflows or the difference violates
+                /// the predicate.
+                pub fn checked_sub_raw(self, rhs: $ty) -> Result<Self, RefinementError> {
+                    self.0
+                        .checked_sub(rhs)
+                        .map_or_else(|| Err(overflowed::<$ty, P>()), Self::refine)
+                }
+
+                /// Multiplies by an unrefined value, checking the predicate against the product at
+                /// runtime.
+                ///
+                /// An error is returned if the multiplication overflows or the product violates
+                /// the predicate.
+                pub fn checked_mul_raw(self, rhs: $ty) -> Result<Self, RefinementError> {
+                    self.0
+                        .checked_mul(rhs)
+                        .map_or_else(|| Err(overflowed::<$ty, P>()), Self::refine)
+                }
+
+                /// Checked addition, returning `None` if the addition overflows or the sum
+                /// violates the predicate.
+                pub fn checked_add<Q: Predicate<$ty>>(self, rhs: Refinement<$ty, Q>) -> Option<Self> {
+                    self.checked_add_raw(rhs.0).ok()
+                }
+
+                /// Checked subtraction, returning `None` if the subtraction overflows or the
+                /// difference violates the predicate.
+                pub fn checked_sub<Q: Predicate<$ty>>(self, rhs: Refinement<$ty, Q>) -> Option<Self> {
+                    self.checked_sub_raw(rhs.0).ok()
+                }
+
+                /// Checked multiplication, returning `None` if the multiplication overflows or the
+                /// product violates the predicate.
+                pub fn checked_mul<Q: Predicate<$ty>>(self, rhs: Refinement<$ty, Q>) -> Option<Self> {
+                    self.checked_mul_raw(rhs.0).ok()
+                }
+
+                /// Checked division, returning `None` if the divisor is zero, the division
+                /// overflows or the quotient violates the predicate.
+                pub fn checked_div<Q: Predicate<$ty>>(self, rhs: Refinement<$ty, Q>) -> Option<Self> {
+                    self.0.checked_div(rhs.0).and_then(|value| Self::refine(value).ok())
+                }
+            }
+
+            impl<P: Saturate<$ty>> Refinement<$ty, P> {
+                /// Saturating addition, clamping the sum to the bounds of the predicate rather
+                /// than overflowing them.
+                pub fn saturating_add<Q: Predicate<$ty>>(self, rhs: Refinement<$ty, Q>) -> Self {
+                    Self::saturated(self.0.saturating_add(rhs.0))
+                }
+
+                /// Saturating subtraction, clamping the difference to the bounds of the predicate
+                /// rather than overflowing them.
+                pub fn saturating_sub<Q: Predicate<$ty>>(self, rhs: Refinement<$ty, Q>) -> Self {
+                    Self::saturated(self.0.saturating_sub(rhs.0))
+                }
+
+                /// Saturating multiplication, clamping the product to the bounds of the predicate
+                /// rather than overflowing them.
+                pub fn saturating_mul<Q: Predicate<$ty>>(self, rhs: Refinement<$ty, Q>) -> Self {
+                    Self::saturated(self.0.saturating_mul(rhs.0))
+                }
+
+                /// Wraps a value that has been saturated to the limits of the base type, and
+                /// is saturated to the bounds of the predicate here. A refinement can only exist
+                /// if those bounds overlap the base type, so the result satisfies the predicate.
+                fn saturated(value: $ty) -> Self {
+                    let value = P::saturate(value);
+                    debug_assert!(P::test(&value));
+                    Self(value, PhantomData)
+                }
+            }
+
+            /// Sums refinements into a refinement by any predicate, checking it against the total
+            /// at runtime. An error is returned if the sum overflows or violates the predicate.
+            impl<P: Predicate<$ty>, Q: Predicate<$ty>> Sum<Refinement<$ty, Q>>
+                for Result<Refinement<$ty, P>, RefinementError>
+            {
+                fn sum<I: Iterator<Item = Refinement<$ty, Q>>>(mut iter: I) -> Self {
+                    iter.try_fold(0 as $ty, |total, value| total.checked_add(value.0))
+                        .map_or_else(|| Err(overflowed::<$ty, P>()), Refinement::refine)
+                }
+            }
+
+            impl<'a, P: Predicate<$ty>, Q: Predicate<$ty>> Sum<&'a Refinement<$ty, Q>>
+                for Result<Refinement<$ty, P>, RefinementError>
+            {
+                fn sum<I: Iterator<Item = &'a Refinement<$ty, Q>>>(iter: I) -> Self {
+                    iter.map(|value| Refinement::<$ty, Q>(value.0, PhantomData)).sum()
+                }
+            }
+
+            /// Multiplies refinements into a refinement by any predicate, checking it against the
+            /// product at runtime. An error is returned if the product overflows or violates the
+            /// predicate.
+            impl<P: Predicate<$ty>, Q: Predicate<$ty>> Product<Refinement<$ty, Q>>
+                for Result<Refinement<$ty, P>, RefinementError>
+            {
+                fn product<I: Iterator<Item = Refinement<$ty, Q>>>(mut iter: I) -> Self {
+                    iter.try_fold(1 as $ty, |total, value| total.checked_mul(value.0))
+                        .map_or_else(|| Err(overflowed::<$ty, P>()), Refinement::refine)
+                }
+            }
+
+            impl<'a, P: Predicate<$ty>, Q: Predicate<$ty>> Product<&'a Refinement<$ty, Q>>
+                for Result<Refinement<$ty, P>, RefinementError>
+            {
+                fn product<I: Iterator<Item = &'a Refinement<$ty, Q>>>(iter: I) -> Self {
+                    iter.map(|value| Refinement::<$ty, Q>(value.0, PhantomData)).product()
+                }
+            }
+
+            /// Sums refinements into their unrefined base type.
+            impl<Q: Predicate<$ty>> Sum<Refinement<$ty, Q>> for $ty {
+                fn sum<I: Iterator<Item = Refinement<$ty, Q>>>(iter: I) -> Self {
+                    iter.map(|value| value.0).sum()
+                }
+            }
+
+            /// Multiplies refinements into their unrefined base type.
+            impl<Q: Predicate<$ty>> Product<Refinement<$ty, Q>> for $ty {
+                fn product<I: Iterator<Item = Refinement<$ty, Q>>>(iter: I) -> Self {
+                    iter.map(|value| value.0).product()
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        boundable::{signed, unsigned},
+        error::ErrorKind,
+        *,
+    };
+
+    #[test]
+    fn test_checked_raw() {
+        let value = Refinement::<u8, unsigned::LessThan<100>>::refine(40).unwrap();
+        assert_eq!(*value.checked_add_raw(59).unwrap(), 99);
+        assert_eq!(*value.checked_sub_raw(40).unwrap(), 0);
+        assert_eq!(*value.checked_mul_raw(2).unwrap(), 80);
+
+        let err = value.checked_add_raw(60).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LessThan(error::Bound::Unsigned(100)));
+        assert_eq!(value.checked_mul_raw(3).unwrap_err().kind(), err.kind());
+    }
+
+    #[test]
+    fn test_checked_raw_overflow() {
+        let value = Refinement::<i8, signed::GreaterThan<-100>>::refine(100).unwrap();
+        let err = value.checked_add_raw(28).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overflow);
+        assert_eq!(err.message(), "must not overflow");
+        assert_eq!(
+            value.checked_mul_raw(-2).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+        assert_eq!(*value.checked_sub_raw(127).unwrap(), -27);
+    }
+
+    #[test]
+    fn test_checked() {
+        let value = Refinement::<u8, unsigned::ClosedInterval<10, 200>>::refine(100).unwrap();
+        let two = Refinement::<u8, unsigned::NonZero>::refine(2).unwrap();
+        assert_eq!(*value.checked_add(two).unwrap(), 102);
+        assert_eq!(*value.checked_sub(two).unwrap(), 98);
+        assert_eq!(*value.checked_mul(two).unwrap(), 200);
+        assert_eq!(*value.checked_div(two).unwrap(), 50);
+        assert_eq!(value.checked_mul(value), None);
+        assert_eq!(value.checked_div(value.checked_div(two).unwrap()), None);
+
+        let zero = Refinement::<u8, unsigned::LessThan<10>>::refine(0).unwrap();
+        assert_eq!(value.checked_div(zero), None);
+    }
+
+    #[test]
+    fn test_saturating() {
+        let value = Refinement::<u8, unsigned::ClosedInterval<10, 200>>::refine(100).unwrap();
+        let large = Refinement::<u8, unsigned::GreaterThan<150>>::refine(160).unwrap();
+        assert_eq!(*value.saturating_add(large), 200);
+        assert_eq!(*value.saturating_sub(large), 10);
+        assert_eq!(*value.saturating_mul(large), 200);
+
+        let value = Refinement::<i16, signed::GreaterThanEqual<-100>>::refine(-90).unwrap();
+        let offset = Refinement::<i16, signed::LessThan<0>>::refine(-20).unwrap();
+        assert_eq!(*value.saturating_add(offset), -100);
+        assert_eq!(*value.saturating_sub(offset), -70);
+        assert_eq!(*value.saturating_mul(offset), 1800);
+        let factor = Refinement::<i16, signed::GreaterThan<1000>>::refine(1001).unwrap();
+        assert_eq!(*value.saturating_mul(factor), -100);
+    }
+
+    #[test]
+    fn test_sum() {
+        let items: [Refinement<u8, unsigned::LessThan<100>>; 3] =
+            [40, 50, 60].map(|value| Refinement::refine(value).unwrap());
+
+        let total: Result<Refinement<u8, unsigned::LessThanEqual<150>>, _> = items.iter().sum();
+        assert_eq!(*total.unwrap(), 150);
+        let total: Result<Refinement<u8, unsigned::LessThan<150>>, _> = items.iter().sum();
+        assert!(total.is_err());
+        let total: Result<Refinement<u8, unsigned::GreaterThan<0>>, RefinementError> =
+            items.iter().chain(&items).sum();
+        assert_eq!(total.unwrap_err().kind(), ErrorKind::Overflow);
+
+        let total: u8 = items.into_iter().sum();
+        assert_eq!(total, 150);
+
+        let empty: Result<Refinement<u8, unsigned::GreaterThan<0>>, _> =
+            core::iter::empty::<Refinement<u8, unsigned::LessThan<100>>>().sum();
+        assert!(empty.is_err());
+    }
+
+    #[test]
+    fn test_product() {
+        let items: [Refinement<i32, signed::ClosedInterval<-10, 10>>; 3] =
+            [-2, 5, 3].map(|value| Refinement::refine(value).unwrap());
+
+        let product: Result<Refinement<i32, signed::Negative>, _> = items.iter().product();
+        assert_eq!(*product.unwrap(), -30);
+        let product: Result<Refinement<i32, signed::Positive>, _> = items.iter().product();
+        assert!(product.is_err());
+        let product: i32 = items.into_iter().product();
+        assert_eq!(product, -30);
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt::Debug;
+use std::{boxed::Box, error::Error};
+
+use ::bytes::BytesMut;
+use ::postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// Refinements are stored as their base type.
+#[doc(cfg(feature = "postgres-types"))]
+impl<T: ToSql, P: Predicate<T> + Debug> ToSql for Refinement<T, P> {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.0.to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        T::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+/// Reads a value of the base type and refines it, so that row data re-establishes the predicate.
+/// Values that violate the predicate fail with the [RefinementError](crate::RefinementError).
+#[doc(cfg(feature = "postgres-types"))]
+impl<'a, T: FromSql<'a>, P: Predicate<T>> FromSql<'a> for Refinement<T, P> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Self::refine(T::from_sql(ty, raw)?)?)
+    }
+
+    fn from_sql_null(ty: &Type) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Self::refine(T::from_sql_null(ty)?)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        T::accepts(ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boundable::signed::LessThan, RefinementError};
+
+    type Size = Refinement<i64, LessThan<100>>;
+
+    #[test]
+    fn test_round_trip() {
+        assert!(<Size as ToSql>::accepts(&Type::INT8));
+        assert!(!<Size as ToSql>::accepts(&Type::TEXT));
+
+        let mut buf = BytesMut::new();
+        Size::refine(5)
+            .unwrap()
+            .to_sql_checked(&Type::INT8, &mut buf)
+            .unwrap();
+        assert_eq!(*Size::from_sql(&Type::INT8, &buf).unwrap(), 5);
+
+        let mut buf = BytesMut::new();
+        150i64.to_sql(&Type::INT8, &mut buf).unwrap();
+        let err = Size::from_sql(&Type::INT8, &buf).unwrap_err();
+        assert!(err.downcast_ref::<RefinementError>().is_some());
+    }
+}
@@ -0,0 +1,99 @@
+use ::pyo3::{prelude::*, Borrowed, PyErr};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// Extracts a value of the base type and refines it. Values that violate the predicate raise
+/// `ValueError` with the message of the [RefinementError](crate::RefinementError), so that Python
+/// callers can't break the invariants of a Rust core.
+#[doc(cfg(feature = "pyo3"))]
+impl<'a, 'py, T, P> FromPyObject<'a, 'py> for Refinement<T, P>
+where
+    T: FromPyObject<'a, 'py>,
+    P: Predicate<T>,
+{
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let value = T::extract(obj).map_err(Into::into)?;
+        Ok(Self::refine(value)?)
+    }
+}
+
+/// Refinements are passed to Python as their base type.
+#[doc(cfg(feature = "pyo3"))]
+impl<'py, T: IntoPyObject<'py>, P: Predicate<T>> IntoPyObject<'py> for Refinement<T, P> {
+    type Target = T::Target;
+    type Output = T::Output;
+    type Error = T::Error;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.0.into_pyobject(py)
+    }
+}
+
+#[doc(cfg(feature = "pyo3"))]
+impl<'a, 'py, T, P: Predicate<T>> IntoPyObject<'py> for &'a Refinement<T, P>
+where
+    &'a T: IntoPyObject<'py>,
+{
+    type Target = <&'a T as IntoPyObject<'py>>::Target;
+    type Output = <&'a T as IntoPyObject<'py>>::Output;
+    type Error = <&'a T as IntoPyObject<'py>>::Error;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.0.into_pyobject(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::{String, ToString};
+
+    use ::pyo3::{exceptions::PyValueError, types::PyString};
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, string::Trimmed};
+
+    type Size = Refinement<u8, LessThan<100>>;
+
+    #[test]
+    fn test_round_trip() {
+        Python::initialize();
+        Python::attach(|py| {
+            let size = Size::refine(5).unwrap();
+            let object = (&size).into_pyobject(py).unwrap();
+            assert_eq!(object.extract::<u8>().unwrap(), 5);
+            assert_eq!(*object.extract::<Size>().unwrap(), 5);
+
+            let name = Refinement::<String, Trimmed>::refine("name".to_string()).unwrap();
+            let object = name.into_pyobject(py).unwrap();
+            assert_eq!(object.to_str().unwrap(), "name");
+        });
+    }
+
+    #[test]
+    fn test_value_error() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = 150u8
+                .into_pyobject(py)
+                .unwrap()
+                .extract::<Size>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "refinement violated: must be less than 100"
+            );
+
+            let err = PyString::new(py, " name")
+                .extract::<Refinement<String, Trimmed>>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+
+            // Values of the wrong type fail to extract the base type before they are refined.
+            let err = PyString::new(py, "five").extract::<Size>().unwrap_err();
+            assert!(!err.is_instance_of::<PyValueError>(py));
+        });
+    }
+}
@@ -0,0 +1,205 @@
+use std::{boxed::Box, string::String, vec::Vec};
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use crate::{
+    schema::{Bound, Constraints, Describe},
+    Predicate, Refinement, RefinementOps,
+};
+
+/// The number of values that are generated for a refinement before giving up on its predicate.
+const ATTEMPTS: usize = 1000;
+
+/// Base types that can generate values within a set of [Constraints], so that refinements of them
+/// implement [Arbitrary] without discarding most of the generated values.
+///
+/// The constraints are a hint: the generated values are filtered by the predicate, so
+/// implementations may ignore constraints that they can't satisfy directly.
+#[doc(cfg(feature = "quickcheck"))]
+pub trait ArbitraryWithin: Arbitrary {
+    /// Generates a value within `constraints`.
+    fn arbitrary_within(g: &mut Gen, constraints: &Constraints) -> Self;
+}
+
+/// Generates values of the base type within the [Constraints] of `P`, keeping the first that
+/// satisfies it. Predicates that the constraints can't express, such as
+/// [Or](crate::boolean::Or) and [Not](crate::boolean::Not), are enforced by filtering; generation
+/// panics if no value satisfies the predicate after many attempts.
+///
+/// Values shrink as their base type does, skipping those that violate the predicate.
+#[doc(cfg(feature = "quickcheck"))]
+impl<T, P> Arbitrary for Refinement<T, P>
+where
+    T: ArbitraryWithin,
+    P: Predicate<T> + Describe + Clone + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        let constraints = Constraints::of::<P>();
+        (0..ATTEMPTS)
+            .find_map(|_| Self::refine(T::arbitrary_within(g, &constraints)).ok())
+            .unwrap_or_else(|| {
+                panic!(
+                    "no value of {} that {} was generated",
+                    core::any::type_name::<T>(),
+                    P::error()
+                )
+            })
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().filter_map(|value| Self::refine(value).ok()))
+    }
+}
+
+fn value(bound: Bound) -> i128 {
+    match bound {
+        Bound::Unsigned(value) => value as i128,
+        Bound::Signed(value) => value as i128,
+    }
+}
+
+/// A value within `min..=max`, which must not be empty. The limits themselves are chosen more
+/// often than the values between them, since they're the most likely to expose bugs.
+fn within(g: &mut Gen, min: i128, max: i128) -> i128 {
+    if *g.choose(&[true, false, false, false, false]).unwrap() {
+        return *g.choose(&[min, max]).unwrap();
+    }
+    let span = max.abs_diff(min);
+    let random = (u128::from(u64::arbitrary(g)) << 64) | u128::from(u64::arbitrary(g));
+    match span.checked_add(1) {
+        Some(count) => min.wrapping_add((random % count) as i128),
+        None => random as i128,
+    }
+}
+
+/// An integer within `min..=max` that satisfies `constraints`.
+fn integer(g: &mut Gen, constraints: &Constraints, min: i128, max: i128) -> i128 {
+    let (min, max) = constraints.range(min, max);
+    let step = match constraints.multiple_of.map(value) {
+        Some(step) if step != 0 => step.abs(),
+        _ => 1,
+    };
+    let first = min.div_euclid(step) + i128::from(min.rem_euclid(step) != 0);
+    let last = max.div_euclid(step);
+    if first > last {
+        // Nothing satisfies the constraints; the predicate will reject this.
+        return min;
+    }
+    within(g, first, last) * step
+}
+
+/// A length of a string or collection that satisfies `constraints`, if any does.
+fn length(g: &mut Gen, constraints: &Constraints) -> Option<usize> {
+    let (min, max) = constraints.range(0, usize::MAX as i128);
+    let max = match constraints.maximum {
+        Some(_) => max,
+        None => max.min(min + g.size() as i128),
+    };
+    (min <= max).then(|| within(g, min, max) as usize)
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl ArbitraryWithin for $ty {
+                fn arbitrary_within(g: &mut Gen, constraints: &Constraints) -> Self {
+                    integer(g, constraints, <$ty>::MIN as i128, <$ty>::MAX as i128) as $ty
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! unconstrained {
+    ($($ty:ty),*) => {
+        $(
+            /// No predicates constrain this type, so values are generated by its [Arbitrary]
+            /// implementation.
+            impl ArbitraryWithin for $ty {
+                fn arbitrary_within(g: &mut Gen, _: &Constraints) -> Self {
+                    <$ty>::arbitrary(g)
+                }
+            }
+        )*
+    };
+}
+
+unconstrained!(bool, char, f32, f64);
+
+/// Strings with a length constraint are generated from printable ASCII characters, so that their
+/// length in bytes is their number of characters. Patterns aren't supported, so refinements by
+/// [Regex](crate::string::Regex) generally need a dedicated [Arbitrary] implementation.
+impl ArbitraryWithin for String {
+    fn arbitrary_within(g: &mut Gen, constraints: &Constraints) -> Self {
+        if constraints.minimum.is_none() && constraints.maximum.is_none() {
+            return String::arbitrary(g);
+        }
+        let printable: Vec<char> = (' '..='~').collect();
+        let length = length(g, constraints).unwrap_or_default();
+        (0..length)
+            .map(|_| *g.choose(&printable).unwrap())
+            .collect()
+    }
+}
+
+/// Vectors whose number of elements satisfies the constraints.
+impl<T: Arbitrary> ArbitraryWithin for Vec<T> {
+    fn arbitrary_within(g: &mut Gen, constraints: &Constraints) -> Self {
+        let length = length(g, constraints).unwrap_or_default();
+        (0..length).map(|_| T::arbitrary(g)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::quickcheck::QuickCheck;
+
+    use super::*;
+    use crate::{
+        boolean::{And, Or},
+        boundable::{signed, unsigned},
+        string::Trimmed,
+    };
+
+    type Level = Refinement<u8, unsigned::ClosedInterval<5, 10>>;
+
+    #[test]
+    fn test_bounds() {
+        fn prop(
+            level: Level,
+            large: Refinement<u64, unsigned::GreaterThan<{ usize::MAX - 2 }>>,
+            negative: Refinement<i16, And<signed::Divisible<7>, signed::Negative>>,
+        ) -> bool {
+            (5..=10).contains(&*level)
+                && *large > u64::MAX - 2
+                && *negative % 7 == 0
+                && *negative < 0
+        }
+        QuickCheck::new().quickcheck(prop as fn(_, _, _) -> bool);
+    }
+
+    #[test]
+    fn test_filtered() {
+        fn prop(
+            either: Refinement<u16, Or<unsigned::LessThan<10>, unsigned::GreaterThan<60000>>>,
+            name: Refinement<String, Trimmed>,
+            sizes: Refinement<Vec<Level>, unsigned::LessThanEqual<3>>,
+        ) -> bool {
+            (*either < 10 || *either > 60000)
+                && name.trim() == name.as_str()
+                && sizes.len() <= 3
+                && sizes.iter().all(|level| (5..=10).contains(&**level))
+        }
+        QuickCheck::new().quickcheck(prop as fn(_, _, _) -> bool);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let level = Level::refine(9).unwrap();
+        let shrunk: Vec<u8> = level.shrink().map(|level| *level).collect();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|value| (5..9).contains(value)));
+    }
+}
@@ -0,0 +1,163 @@
+use core::marker::PhantomData;
+
+use ::rand::{
+    distr::{
+        uniform::{Error, SampleBorrow, SampleUniform, UniformSampler},
+        Distribution, StandardUniform,
+    },
+    Rng,
+};
+
+use crate::{
+    boolean::{And, True},
+    boundable::{signed, unsigned},
+    schema::{Constraints, Describe},
+    Predicate, Refinement,
+};
+
+/// Predicates whose satisfying values form an interval: every value between two values that
+/// satisfy the predicate also satisfies it.
+///
+/// Refinements by these predicates can be sampled from a range of refinements, since every value
+/// of the range is valid. Samples are still tested, so an incorrect implementation panics rather
+/// than producing an invalid refinement.
+#[doc(cfg(feature = "rand"))]
+pub trait Convex {}
+
+impl Convex for True {}
+
+impl<A: Convex, B: Convex> Convex for And<A, B> {}
+
+impl<const MIN: usize> Convex for unsigned::GreaterThan<MIN> {}
+
+impl<const MIN: usize> Convex for unsigned::GreaterThanEqual<MIN> {}
+
+impl<const MAX: usize> Convex for unsigned::LessThan<MAX> {}
+
+impl<const MAX: usize> Convex for unsigned::LessThanEqual<MAX> {}
+
+impl<const VAL: usize> Convex for unsigned::Equals<VAL> {}
+
+impl<const MIN: isize> Convex for signed::GreaterThan<MIN> {}
+
+impl<const MIN: isize> Convex for signed::GreaterThanEqual<MIN> {}
+
+impl<const MAX: isize> Convex for signed::LessThan<MAX> {}
+
+impl<const MAX: isize> Convex for signed::LessThanEqual<MAX> {}
+
+impl<const VAL: isize> Convex for signed::Equals<VAL> {}
+
+/// Wraps a sampled value that is known to satisfy `P`.
+fn sampled<T, P: Predicate<T>>(value: T) -> Refinement<T, P> {
+    assert!(P::test(&value), "sampled value violates {}", P::error());
+    Refinement(value, PhantomData)
+}
+
+/// Samples refinements uniformly from a range of refinements, as with
+/// `rng.random_range(low..=high)`.
+#[doc(cfg(feature = "rand"))]
+pub struct UniformRefinement<T: SampleUniform, P>(T::Sampler, PhantomData<P>);
+
+impl<T: SampleUniform, P: Predicate<T> + Convex> UniformSampler for UniformRefinement<T, P> {
+    type X = Refinement<T, P>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let sampler = T::Sampler::new(&low.borrow().0, &high.borrow().0)?;
+        Ok(Self(sampler, PhantomData))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let sampler = T::Sampler::new_inclusive(&low.borrow().0, &high.borrow().0)?;
+        Ok(Self(sampler, PhantomData))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        sampled(self.0.sample(rng))
+    }
+}
+
+/// Refinements by [Convex] predicates can be sampled from ranges of refinements, since every value
+/// between two valid refinements is valid.
+#[doc(cfg(feature = "rand"))]
+impl<T: SampleUniform, P: Predicate<T> + Convex> SampleUniform for Refinement<T, P> {
+    type Sampler = UniformRefinement<T, P>;
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            /// Samples uniformly from the values that satisfy the predicate, which are described
+            /// by its [Constraints]. Panics if no value does.
+            #[doc(cfg(feature = "rand"))]
+            impl<P: Predicate<$ty> + Describe + Convex> Distribution<Refinement<$ty, P>>
+                for StandardUniform
+            {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Refinement<$ty, P> {
+                    let (min, max) = Constraints::of::<P>()
+                        .range(<$ty>::MIN as i128, <$ty>::MAX as i128);
+                    assert!(min <= max, "no {} satisfies {}", stringify!($ty), P::error());
+                    sampled(rng.random_range(min as $ty..=max as $ty))
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+#[cfg(test)]
+mod tests {
+    use ::rand::{rngs::SmallRng, SeedableRng};
+
+    use super::*;
+    use crate::RefinementOps;
+
+    type SkillLevel = Refinement<u8, unsigned::ClosedInterval<1, 10>>;
+    type Offset = Refinement<i32, signed::OpenInterval<-5, 5>>;
+
+    #[test]
+    fn test_standard() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut seen = [false; 11];
+        for _ in 0..1000 {
+            let level: SkillLevel = rng.random();
+            seen[*level as usize] = true;
+            let offset: Offset = rng.random();
+            assert!((-4..=4).contains(&*offset));
+            let exact: Refinement<u64, unsigned::Equals<42>> = rng.random();
+            assert_eq!(*exact, 42);
+        }
+        assert_eq!(
+            seen,
+            [false, true, true, true, true, true, true, true, true, true, true]
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let min = SkillLevel::refine(3).unwrap();
+        let max = SkillLevel::refine(6).unwrap();
+        for _ in 0..100 {
+            assert!((3..=6).contains(&*rng.random_range(min..=max)));
+            assert!((3..6).contains(&*rng.random_range(min..max)));
+        }
+        assert!(UniformRefinement::new(max, min).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "no u8 satisfies")]
+    fn test_unsatisfiable() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let _: Refinement<u8, unsigned::GreaterThan<300>> = rng.random();
+    }
+}
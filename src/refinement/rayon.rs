@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+
+use ::rayon::prelude::*;
+
+use super::rejected;
+use crate::{error::PathSegment, Predicate, Refinement, RefinementError};
+
+/// Refines every element of a slice or vector by `P` in parallel.
+///
+/// Elements are tested in place, without [normalization](Predicate::normalize), and the buffer is
+/// reinterpreted as refinements when every element satisfies `P`, so that no copies are made.
+/// Otherwise, the violation of the first element that does not satisfy `P` is reported at its
+/// [index](PathSegment::Index).
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan, ParRefine};
+///
+/// let readings: Vec<u16> = (0..60_000).map(|i| i % 1000).collect();
+/// let refined = readings.par_refine_all::<LessThan<1000>>().unwrap();
+/// assert_eq!(*refined[999], 999);
+///
+/// let err = [1u16, 2000, 3000][..].par_refine_all::<LessThan<1000>>().unwrap_err();
+/// assert_eq!(err.to_string(), "refinement violated: element [1]: must be less than 1000");
+/// ```
+#[doc(cfg(feature = "rayon"))]
+pub trait ParRefine<T>: Sized {
+    /// The collection of refinements that the collection is reinterpreted as.
+    type Refined<P: Predicate<T> + 'static>;
+
+    /// Refines every element by `P` in parallel, reinterpreting the collection as refinements.
+    fn par_refine_all<P: Predicate<T> + 'static>(self)
+        -> Result<Self::Refined<P>, RefinementError>;
+}
+
+/// Tests every element of `values` by `P` in parallel, reporting the first violation.
+fn check<T: Sync, P: Predicate<T>>(values: &[T]) -> Result<(), RefinementError> {
+    match values.par_iter().position_first(|value| !P::test(value)) {
+        Some(index) => {
+            Err(rejected::<T, P>(P::violation(&values[index])).at(PathSegment::Index(index)))
+        }
+        None => Ok(()),
+    }
+}
+
+impl<'a, T: Sync> ParRefine<T> for &'a [T] {
+    type Refined<P: Predicate<T> + 'static> = &'a [Refinement<T, P>];
+
+    fn par_refine_all<P: Predicate<T> + 'static>(
+        self,
+    ) -> Result<Self::Refined<P>, RefinementError> {
+        check::<T, P>(self)?;
+        // SAFETY: refinements are transparent over their base type, and every element satisfies P.
+        Ok(unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), self.len()) })
+    }
+}
+
+impl<T: Sync> ParRefine<T> for Vec<T> {
+    type Refined<P: Predicate<T> + 'static> = Vec<Refinement<T, P>>;
+
+    fn par_refine_all<P: Predicate<T> + 'static>(
+        self,
+    ) -> Result<Self::Refined<P>, RefinementError> {
+        check::<T, P>(&self)?;
+        let mut values = ManuallyDrop::new(self);
+        // SAFETY: refinements are transparent over their base type, so they share its layout and
+        // allocation, and every element satisfies P.
+        Ok(unsafe {
+            Vec::from_raw_parts(values.as_mut_ptr().cast(), values.len(), values.capacity())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::*, error::PathSegment, *};
+
+    #[test]
+    fn test_par_refine_vec() {
+        let values: Vec<u32> = (0..10_000).collect();
+        let refined = values.par_refine_all::<LessThan<10_000>>().unwrap();
+        assert_eq!(refined.len(), 10_000);
+        assert_eq!(*refined[1234], 1234);
+
+        let values: Vec<u32> = (0..10_000).collect();
+        let err = values.par_refine_all::<LessThan<5_000>>().unwrap_err();
+        assert_eq!(err.path(), &[PathSegment::Index(5_000)]);
+    }
+
+    #[test]
+    fn test_par_refine_slice() {
+        let values = ["a".to_string(), "bc".to_string()];
+        let refined = values[..].par_refine_all::<GreaterThan<0>>().unwrap();
+        assert_eq!(refined[1].as_str(), "bc");
+        assert!(values[..].par_refine_all::<GreaterThan<1>>().is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use std::string::ToString;
+
+use ::redis::{FromRedisValue, NumericBehavior, ParsingError, RedisWrite, ToRedisArgs, Value};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// Refinements are written as their base type.
+#[doc(cfg(feature = "redis"))]
+impl<T: ToRedisArgs, P: Predicate<T>> ToRedisArgs for Refinement<T, P> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.0.write_redis_args(out)
+    }
+
+    fn describe_numeric_behavior(&self) -> NumericBehavior {
+        self.0.describe_numeric_behavior()
+    }
+
+    fn num_of_args(&self) -> usize {
+        self.0.num_of_args()
+    }
+}
+
+/// Reads a value of the base type and refines it. Data in Redis may have been written by other
+/// services, so values that violate the predicate fail to parse with the message of the
+/// [RefinementError](crate::RefinementError).
+#[doc(cfg(feature = "redis"))]
+impl<T: FromRedisValue, P: Predicate<T>> FromRedisValue for Refinement<T, P> {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        Self::refine(T::from_redis_value_ref(v)?).map_err(|err| err.to_string().into())
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::refine(T::from_redis_value(v)?).map_err(|err| err.to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use ::redis::from_redis_value;
+
+    use super::*;
+    use crate::boundable::unsigned::LessThan;
+
+    type Size = Refinement<u8, LessThan<100>>;
+
+    #[test]
+    fn test_round_trip() {
+        let args = Size::refine(5).unwrap().to_redis_args();
+        assert_eq!(args, vec![b"5".to_vec()]);
+
+        let size: Size = from_redis_value(Value::BulkString(args[0].clone())).unwrap();
+        assert_eq!(*size, 5);
+        assert_eq!(*Size::from_redis_value_ref(&Value::Int(5)).unwrap(), 5);
+
+        let err = from_redis_value::<Size>(Value::Int(150)).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("refinement violated: must be less than 100"));
+        assert!(Size::from_redis_value_ref(&Value::Nil).is_err());
+    }
+}
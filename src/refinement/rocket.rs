@@ -0,0 +1,102 @@
+use ::rocket::{
+    form::{self, DataField, FromFormField, ValueField},
+    http::uri::{fmt::Path, Segments},
+    request::{FromParam, FromSegments},
+};
+use thiserror::Error;
+
+use crate::{Predicate, Refinement, RefinementError, RefinementOps};
+
+/// The reason that a route parameter could not be refined.
+#[doc(cfg(feature = "rocket"))]
+#[derive(Debug, Error)]
+pub enum ParamError<E> {
+    /// The parameter could not be parsed as the base type.
+    #[error("{0:?}")]
+    Parse(E),
+    /// The parameter was parsed, but the refinement was violated.
+    #[error(transparent)]
+    Refinement(RefinementError),
+}
+
+/// Refines a route parameter that `T` can be parsed from.
+#[doc(cfg(feature = "rocket"))]
+impl<'a, T: FromParam<'a>, P: Predicate<T>> FromParam<'a> for Refinement<T, P> {
+    type Error = ParamError<T::Error>;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        let value = T::from_param(param).map_err(ParamError::Parse)?;
+        Self::refine(value).map_err(ParamError::Refinement)
+    }
+}
+
+/// Refines the trailing segments of a route that `T` can be parsed from.
+#[doc(cfg(feature = "rocket"))]
+impl<'r, T: FromSegments<'r>, P: Predicate<T>> FromSegments<'r> for Refinement<T, P> {
+    type Error = ParamError<T::Error>;
+
+    fn from_segments(segments: Segments<'r, Path>) -> Result<Self, Self::Error> {
+        let value = T::from_segments(segments).map_err(ParamError::Parse)?;
+        Self::refine(value).map_err(ParamError::Refinement)
+    }
+}
+
+/// Refines a form field that `T` can be parsed from, reporting violations as validation errors.
+#[doc(cfg(feature = "rocket"))]
+#[::rocket::async_trait]
+impl<'v, T: FromFormField<'v>, P: Predicate<T> + Send> FromFormField<'v> for Refinement<T, P> {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        let value = T::from_value(field)?;
+        Ok(Self::refine(value).map_err(validation)?)
+    }
+
+    async fn from_data(field: DataField<'v, '_>) -> form::Result<'v, Self> {
+        let value = T::from_data(field).await?;
+        Ok(Self::refine(value).map_err(validation)?)
+    }
+
+    fn default() -> Option<Self> {
+        Self::refine(T::default()?).ok()
+    }
+}
+
+fn validation<'v>(err: RefinementError) -> form::Error<'v> {
+    form::Error::validation(err.message())
+}
+
+#[cfg(test)]
+mod tests {
+    use ::rocket::form::Form;
+
+    use super::*;
+    use crate::boundable::unsigned::LessThan;
+
+    type Size = Refinement<u8, LessThan<100>>;
+
+    #[test]
+    fn test_from_param() {
+        assert_eq!(*Size::from_param("5").unwrap(), 5);
+        assert!(matches!(
+            Size::from_param("150"),
+            Err(ParamError::Refinement(_))
+        ));
+        assert!(matches!(Size::from_param("x"), Err(ParamError::Parse(_))));
+    }
+
+    #[test]
+    fn test_from_form_field() {
+        #[derive(Debug, ::rocket::FromForm)]
+        struct Params {
+            size: Size,
+        }
+
+        let params = Form::<Params>::parse("size=5").unwrap();
+        assert_eq!(*params.size, 5);
+
+        let errors = Form::<Params>::parse("size=150").unwrap_err();
+        assert_eq!(
+            errors.iter().next().unwrap().to_string(),
+            "must be less than 100"
+        );
+    }
+}
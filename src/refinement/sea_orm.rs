@@ -0,0 +1,66 @@
+use std::{any::type_name, boxed::Box, string::String};
+
+use ::sea_orm::{
+    sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr},
+    ActiveValue, ColIdx, DbErr, IntoActiveValue, QueryResult, TryGetError, TryGetable,
+};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// Refinements are stored as their base type.
+#[doc(cfg(feature = "sea-orm"))]
+impl<T: Into<Value>, P: Predicate<T>> From<Refinement<T, P>> for Value {
+    fn from(value: Refinement<T, P>) -> Self {
+        value.0.into()
+    }
+}
+
+#[doc(cfg(feature = "sea-orm"))]
+impl<T: Nullable, P: Predicate<T>> Nullable for Refinement<T, P> {
+    fn null() -> Value {
+        T::null()
+    }
+}
+
+/// Converts a value of the base type and refines it. Values that violate the predicate fail to
+/// convert.
+#[doc(cfg(feature = "sea-orm"))]
+impl<T: ValueType, P: Predicate<T>> ValueType for Refinement<T, P> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        Self::refine(T::try_from(v)?).map_err(|_| ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        T::type_name()
+    }
+
+    fn array_type() -> ArrayType {
+        T::array_type()
+    }
+
+    fn column_type() -> ColumnType {
+        T::column_type()
+    }
+}
+
+/// Reads a column of the base type and refines it, so that rows that violate the predicate fail
+/// to load with a [DbErr::TryIntoErr] whose source is the [RefinementError](crate::RefinementError).
+#[doc(cfg(feature = "sea-orm"))]
+impl<T: TryGetable, P: Predicate<T>> TryGetable for Refinement<T, P> {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        Self::refine(T::try_get_by(res, index)?).map_err(|err| {
+            TryGetError::DbErr(DbErr::TryIntoErr {
+                from: type_name::<T>(),
+                into: type_name::<Self>(),
+                source: Box::new(err),
+            })
+        })
+    }
+}
+
+#[doc(cfg(feature = "sea-orm"))]
+impl<T: Into<Value>, P: Predicate<T>> IntoActiveValue<Self> for Refinement<T, P> {
+    fn into_active_value(self) -> ActiveValue<Self> {
+        ActiveValue::Set(self)
+    }
+}
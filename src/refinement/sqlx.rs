@@ -0,0 +1,46 @@
+use ::sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// Refinements are stored as their base type.
+#[doc(cfg(feature = "sqlx"))]
+impl<DB: Database, T: Type<DB>, P: Predicate<T>> Type<DB> for Refinement<T, P> {
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+/// Encodes the refined value as its base type. The stored value is guaranteed to satisfy the
+/// predicate.
+#[doc(cfg(feature = "sqlx"))]
+impl<'q, DB: Database, T: Encode<'q, DB>, P: Predicate<T>> Encode<'q, DB> for Refinement<T, P> {
+    fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.0.encode(buf)
+    }
+
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        self.0.produces()
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+/// Decodes a value of the base type and refines it, so that values loaded from the database
+/// re-establish the predicate. Values that violate the predicate fail to decode with the
+/// [RefinementError](crate::RefinementError).
+#[doc(cfg(feature = "sqlx"))]
+impl<'r, DB: Database, T: Decode<'r, DB>, P: Predicate<T>> Decode<'r, DB> for Refinement<T, P> {
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(Self::refine(T::decode(value)?)?)
+    }
+}
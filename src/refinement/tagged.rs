@@ -0,0 +1,193 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use serde::{
+    de::DeserializeOwned,
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+
+use alloc::string::String;
+
+use super::display::short_type_name;
+use crate::{Predicate, Refined, Refinement, RefinementError, RefinementOps};
+
+/// A [Refinement] that is serialized along with the name of its predicate.
+///
+/// Refinements are serialized transparently, exactly as their base values. `Tagged` instead
+/// serializes a refinement as a struct with a `value` field holding the base value and a
+/// `refined` field naming the predicate without its module paths, which is useful for audit logs
+/// and for data that is read without knowing its schema up front.
+///
+/// When deserializing, the `refined` field is ignored and the predicate is checked again, so
+/// data remains readable if a predicate is renamed. Only the `value` field is required.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan, Tagged};
+///
+/// let value = Tagged::<u8, LessThan<50>>::refine(49).unwrap();
+/// assert_eq!(
+///     serde_json::to_string(&value).unwrap(),
+///     r#"{"value":49,"refined":"LessThan<50>"}"#
+/// );
+///
+/// let value: Tagged<u8, LessThan<50>> = serde_json::from_str(r#"{"value":42}"#).unwrap();
+/// assert_eq!(*value, 42);
+/// assert!(serde_json::from_str::<Tagged<u8, LessThan<50>>>(r#"{"value":50}"#).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Tagged<T, P: Predicate<T>>(Refinement<T, P>);
+
+impl<T, P: Predicate<T>> Tagged<T, P> {
+    /// Tags a refinement.
+    pub fn new(refinement: Refinement<T, P>) -> Self {
+        Self(refinement)
+    }
+
+    /// Unwraps the underlying refinement.
+    pub fn into_inner(self) -> Refinement<T, P> {
+        self.0
+    }
+}
+
+/// The name of the predicate `P`, serialized as a string.
+struct PredicateName<P>(PhantomData<P>);
+
+impl<P> Display for PredicateName<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        short_type_name(f, core::any::type_name::<P>())
+    }
+}
+
+impl<P> Serialize for PredicateName<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<T: Serialize, P: Predicate<T>> Serialize for Tagged<T, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tagged = serializer.serialize_struct("Tagged", 2)?;
+        tagged.serialize_field("value", &*self.0)?;
+        tagged.serialize_field("refined", &PredicateName::<P>(PhantomData))?;
+        tagged.end()
+    }
+}
+
+/// The serialized form of [Tagged]. The `refined` field is read so that formats that are not
+/// self-describing can skip over it, but is otherwise ignored.
+#[derive(Deserialize)]
+#[serde(rename = "Tagged")]
+struct TaggedValue<T> {
+    value: T,
+    #[serde(default)]
+    #[allow(dead_code)]
+    refined: String,
+}
+
+impl<'de, T: DeserializeOwned, P: Predicate<T>> Deserialize<'de> for Tagged<T, P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let tagged = TaggedValue::<T>::deserialize(deserializer)?;
+        Self::try_from(Refined(tagged.value)).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<T, P: Predicate<T>> Deref for Tagged<T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+impl<T, P: Predicate<T>> AsRef<Refinement<T, P>> for Tagged<T, P> {
+    fn as_ref(&self) -> &Refinement<T, P> {
+        &self.0
+    }
+}
+
+impl<T, P: Predicate<T>> From<Refinement<T, P>> for Tagged<T, P> {
+    fn from(value: Refinement<T, P>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T, P: Predicate<T>> From<Tagged<T, P>> for Refinement<T, P> {
+    fn from(value: Tagged<T, P>) -> Self {
+        value.0
+    }
+}
+
+impl<T, P: Predicate<T>> TryFrom<Refined<T>> for Tagged<T, P> {
+    type Error = RefinementError;
+
+    fn try_from(value: Refined<T>) -> Result<Self, Self::Error> {
+        Refinement::try_from(value).map(Self)
+    }
+}
+
+impl<T, P: Predicate<T>> From<Tagged<T, P>> for Refined<T> {
+    fn from(value: Tagged<T, P>) -> Self {
+        Refined(value.take())
+    }
+}
+
+impl<T, P: Predicate<T>> RefinementOps for Tagged<T, P> {
+    type T = T;
+
+    fn take(self) -> T {
+        self.0.take()
+    }
+
+    fn extract(self) -> T {
+        self.0.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boolean::And, boundable::unsigned, string::Trimmed};
+    use alloc::format;
+
+    #[test]
+    fn test_tagged_round_trip() {
+        type Test = Tagged<String, And<Trimmed, unsigned::LessThan<5>>>;
+        let value = Test::refine(String::from("abc")).unwrap();
+        let serialized = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"value":"abc","refined":"And<Trimmed, LessThan<5>>"}"#
+        );
+        assert_eq!(serde_json::from_str::<Test>(&serialized).unwrap(), value);
+
+        let err =
+            serde_json::from_str::<Test>(r#"{"refined":"Other","value":" abc"}"#).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must not start or end with whitespace"
+        );
+        assert!(serde_json::from_str::<Test>(r#""abc""#).is_err());
+    }
+
+    #[test]
+    fn test_tagged_postcard() {
+        let value = Tagged::<u8, unsigned::LessThan<50>>::refine(49).unwrap();
+        let bytes = postcard::to_allocvec(&value).unwrap();
+        let mut expected = postcard::to_allocvec(&49u8).unwrap();
+        expected.extend(postcard::to_allocvec("LessThan<50>").unwrap());
+        assert_eq!(bytes, expected);
+        assert_eq!(
+            postcard::from_bytes::<Tagged<u8, unsigned::LessThan<50>>>(&bytes).unwrap(),
+            value
+        );
+    }
+}
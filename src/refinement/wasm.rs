@@ -0,0 +1,82 @@
+use ::wasm_bindgen::JsValue;
+use serde::de::DeserializeOwned;
+
+use crate::{Predicate, Refinement, RefinementOps};
+
+/// Refinements are passed to JavaScript as their base type.
+#[doc(cfg(feature = "wasm"))]
+impl<T: Into<JsValue>, P: Predicate<T>> From<Refinement<T, P>> for JsValue {
+    fn from(value: Refinement<T, P>) -> Self {
+        value.0.into()
+    }
+}
+
+/// Converts a JavaScript value to the base type and refines it.
+///
+/// The value is converted with [serde_wasm_bindgen], so any base type that can be deserialized is
+/// supported. Values that violate the predicate fail with a JavaScript `Error` built from the
+/// [RefinementError](crate::RefinementError); returning it from a `#[wasm_bindgen]` function throws
+/// it as an exception.
+///
+/// ```no_run
+/// use refined::{prelude::*, boundable::unsigned::LessThan};
+/// use wasm_bindgen::prelude::*;
+///
+/// type Size = Refinement<u8, LessThan<100>>;
+///
+/// #[wasm_bindgen]
+/// pub fn grow(size: JsValue) -> Result<JsValue, JsValue> {
+///     let size = Size::try_from(size)?;
+///     let grown = size.modify(|size| size + 1)?;
+///     Ok(grown.into())
+/// }
+/// ```
+#[doc(cfg(feature = "wasm"))]
+impl<T: DeserializeOwned, P: Predicate<T>> TryFrom<JsValue> for Refinement<T, P> {
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        let value = serde_wasm_bindgen::from_value(value)?;
+        Ok(Self::refine(value)?)
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use js_sys::{Array, Reflect};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, string::Trimmed};
+
+    type Size = Refinement<u8, LessThan<100>>;
+
+    fn property(value: &JsValue, key: &str) -> JsValue {
+        Reflect::get(value, &JsValue::from_str(key)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_round_trip() {
+        let size = Size::try_from(JsValue::from(5)).unwrap();
+        assert_eq!(*size, 5);
+        assert_eq!(JsValue::from(size), JsValue::from(5));
+
+        let name = Refinement::<String, Trimmed>::try_from(JsValue::from_str("name")).unwrap();
+        assert_eq!(JsValue::from(name), JsValue::from_str("name"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_error() {
+        let err = Size::try_from(JsValue::from(150)).unwrap_err();
+        assert_eq!(property(&err, "name"), "RefinementError");
+        assert_eq!(
+            property(&err, "message"),
+            "refinement violated: must be less than 100"
+        );
+        let errors = Array::from(&property(&err, "errors"));
+        assert_eq!(errors.length(), 1);
+        assert_eq!(property(&errors.get(0), "code"), "less_than");
+
+        assert!(Size::try_from(JsValue::from_str("five")).is_err());
+    }
+}
@@ -0,0 +1,924 @@
+//! Schema constraints implied by predicates.
+//!
+//! Schema generators (such as [schemars](https://docs.rs/schemars) with the `schemars` feature,
+//! [utoipa](https://docs.rs/utoipa) with the `utoipa` feature and
+//! [async-graphql](https://docs.rs/async-graphql) with the `async-graphql` feature)
+//! describe a [Refinement](crate::Refinement) as the schema of its base type, narrowed by the
+//! [Constraints] of its predicate. Predicates report their constraints by implementing
+//! [Describe].
+//!
+//! Bounds are expressed in terms of the bounding value of the refined type, so they are applied to
+//! the value itself for numbers, to the length of strings and to the number of items or entries of
+//! collections. Note that the [UnsignedBoundable](crate::UnsignedBoundable) length of a string is
+//! its length in bytes, while schemas count characters; the two only agree for ASCII strings.
+//!
+//! Constraints that can't be expressed by a schema, such as those of [Or](crate::boolean::Or) and
+//! [Not](crate::boolean::Not), are omitted. The resulting schema may therefore accept values that
+//! the predicate rejects, but never the other way around.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{boolean::And, boundable::unsigned::{GreaterThan, LessThanEqual}, schema::*};
+//!
+//! let constraints = Constraints::of::<And<GreaterThan<5>, LessThanEqual<10>>>();
+//! assert_eq!(constraints.minimum, Some(Limit::exclusive(Bound::Unsigned(5))));
+//! assert_eq!(constraints.maximum, Some(Limit::inclusive(Bound::Unsigned(10))));
+//! ```
+use core::fmt::{self, Display, Formatter};
+
+use crate::{
+    boolean::{And, False, Not, Or, True, Xor},
+    boundable::{signed, unsigned},
+    character, collection, normalize,
+};
+#[cfg(feature = "alloc")]
+use crate::{string, TypeString};
+
+pub use crate::error::Bound;
+
+/// A lower or upper limit of a bounding value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Limit {
+    pub value: Bound,
+    pub exclusive: bool,
+}
+
+impl Limit {
+    pub const fn inclusive(value: Bound) -> Self {
+        Self {
+            value,
+            exclusive: false,
+        }
+    }
+
+    pub const fn exclusive(value: Bound) -> Self {
+        Self {
+            value,
+            exclusive: true,
+        }
+    }
+
+    /// The limit as a signed value, so that limits of either signedness can be compared.
+    fn key(&self) -> i128 {
+        match self.value {
+            Bound::Unsigned(value) => value as i128,
+            Bound::Signed(value) => value as i128,
+        }
+    }
+}
+
+/// The constraints that a predicate places on the values that satisfy it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Constraints {
+    /// The lower limit of the bounding value.
+    pub minimum: Option<Limit>,
+    /// The upper limit of the bounding value.
+    pub maximum: Option<Limit>,
+    /// A value that the bounding value must be a multiple of.
+    pub multiple_of: Option<Bound>,
+    /// A regular expression that strings must match. If several apply, only the first is
+    /// recorded.
+    pub pattern: Option<&'static str>,
+}
+
+impl Constraints {
+    /// The constraints described by the predicate `P`.
+    pub fn of<P: Describe>() -> Self {
+        let mut constraints = Self::default();
+        P::describe(&mut constraints);
+        constraints
+    }
+
+    /// Narrows the lower limit, if `limit` is tighter than the current one.
+    pub fn at_least(&mut self, limit: Limit) {
+        match self.minimum {
+            Some(current)
+                if current.key() > limit.key()
+                    || (current.key() == limit.key() && current.exclusive) => {}
+            _ => self.minimum = Some(limit),
+        }
+    }
+
+    /// Narrows the upper limit, if `limit` is tighter than the current one.
+    pub fn at_most(&mut self, limit: Limit) {
+        match self.maximum {
+            Some(current)
+                if current.key() < limit.key()
+                    || (current.key() == limit.key() && current.exclusive) => {}
+            _ => self.maximum = Some(limit),
+        }
+    }
+
+    /// Requires strings to match `pattern`, unless a pattern is already required.
+    pub fn matching(&mut self, pattern: &'static str) {
+        self.pattern.get_or_insert(pattern);
+    }
+
+    /// The inclusive range of bounding values allowed by the limits, within `min..=max`. The start
+    /// of the range exceeds its end if no value is allowed.
+    #[cfg(any(feature = "proptest", feature = "quickcheck", feature = "rand"))]
+    pub(crate) fn range(&self, min: i128, max: i128) -> (i128, i128) {
+        let lower = self.minimum.map_or(min, |limit| {
+            limit.key().saturating_add(i128::from(limit.exclusive))
+        });
+        let upper = self.maximum.map_or(max, |limit| {
+            limit.key().saturating_sub(i128::from(limit.exclusive))
+        });
+        (lower.max(min), upper.min(max))
+    }
+}
+
+/// Renders the constraints as a phrase such as `greater than 0, less than or equal to 10`, for use in
+/// schema descriptions.
+impl Display for Constraints {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        let mut clause = |f: &mut Formatter<'_>, args: fmt::Arguments<'_>| {
+            let result = write!(f, "{separator}{args}");
+            separator = ", ";
+            result
+        };
+        match self.minimum {
+            Some(limit) if limit.exclusive => {
+                clause(f, format_args!("greater than {}", limit.value))?
+            }
+            Some(limit) => clause(f, format_args!("greater than or equal to {}", limit.value))?,
+            None => {}
+        }
+        match self.maximum {
+            Some(limit) if limit.exclusive => clause(f, format_args!("less than {}", limit.value))?,
+            Some(limit) => clause(f, format_args!("less than or equal to {}", limit.value))?,
+            None => {}
+        }
+        if let Some(multiple) = self.multiple_of {
+            clause(f, format_args!("a multiple of {multiple}"))?;
+        }
+        if let Some(pattern) = self.pattern {
+            clause(f, format_args!("matching `{pattern}`"))?;
+        }
+        if separator.is_empty() {
+            f.write_str("unconstrained")?;
+        }
+        Ok(())
+    }
+}
+
+/// Predicates that can describe their [Constraints].
+///
+/// The default implementation describes no constraints, so a predicate that can't be expressed by
+/// a schema only needs an empty implementation:
+///
+/// ```
+/// use refined::{schema::Describe, Predicate, ErrorMessage};
+///
+/// struct IsEven;
+///
+/// impl Predicate<u32> for IsEven {
+///     fn test(value: &u32) -> bool {
+///         value % 2 == 0
+///     }
+///
+///     fn error() -> ErrorMessage {
+///         ErrorMessage::from("must be even")
+///     }
+/// }
+///
+/// impl Describe for IsEven {}
+/// ```
+pub trait Describe {
+    /// Adds the constraints of the predicate to `constraints`.
+    fn describe(_constraints: &mut Constraints) {}
+}
+
+impl Describe for True {}
+
+impl Describe for False {}
+
+impl<A: Describe, B: Describe> Describe for And<A, B> {
+    fn describe(constraints: &mut Constraints) {
+        A::describe(constraints);
+        B::describe(constraints);
+    }
+}
+
+impl<A, B> Describe for Or<A, B> {}
+
+impl<A, B> Describe for Xor<A, B> {}
+
+impl<P> Describe for Not<P> {}
+
+impl<const MIN: usize> Describe for unsigned::GreaterThan<MIN> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_least(Limit::exclusive(Bound::Unsigned(MIN)));
+    }
+}
+
+impl<const MIN: usize> Describe for unsigned::GreaterThanEqual<MIN> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_least(Limit::inclusive(Bound::Unsigned(MIN)));
+    }
+}
+
+impl<const MAX: usize> Describe for unsigned::LessThan<MAX> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_most(Limit::exclusive(Bound::Unsigned(MAX)));
+    }
+}
+
+impl<const MAX: usize> Describe for unsigned::LessThanEqual<MAX> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_most(Limit::inclusive(Bound::Unsigned(MAX)));
+    }
+}
+
+impl<const VAL: usize> Describe for unsigned::Equals<VAL> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_least(Limit::inclusive(Bound::Unsigned(VAL)));
+        constraints.at_most(Limit::inclusive(Bound::Unsigned(VAL)));
+    }
+}
+
+impl<const DIV: usize, const MOD: usize> Describe for unsigned::Modulo<DIV, MOD> {
+    fn describe(constraints: &mut Constraints) {
+        if MOD == 0 {
+            constraints.multiple_of = Some(Bound::Unsigned(DIV));
+        }
+    }
+}
+
+impl<const MIN: isize> Describe for signed::GreaterThan<MIN> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_least(Limit::exclusive(Bound::Signed(MIN)));
+    }
+}
+
+impl<const MIN: isize> Describe for signed::GreaterThanEqual<MIN> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_least(Limit::inclusive(Bound::Signed(MIN)));
+    }
+}
+
+impl<const MAX: isize> Describe for signed::LessThan<MAX> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_most(Limit::exclusive(Bound::Signed(MAX)));
+    }
+}
+
+impl<const MAX: isize> Describe for signed::LessThanEqual<MAX> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_most(Limit::inclusive(Bound::Signed(MAX)));
+    }
+}
+
+impl<const VAL: isize> Describe for signed::Equals<VAL> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.at_least(Limit::inclusive(Bound::Signed(VAL)));
+        constraints.at_most(Limit::inclusive(Bound::Signed(VAL)));
+    }
+}
+
+impl<const DIV: isize, const MOD: isize> Describe for signed::Modulo<DIV, MOD> {
+    fn describe(constraints: &mut Constraints) {
+        if MOD == 0 {
+            constraints.multiple_of = Some(Bound::Signed(DIV));
+        }
+    }
+}
+
+impl Describe for character::IsControl {}
+
+impl Describe for character::IsDigit {}
+
+impl Describe for character::IsLowercase {}
+
+impl Describe for character::IsUppercase {}
+
+impl Describe for character::IsNumeric {}
+
+impl Describe for character::IsWhitespace {}
+
+impl Describe for character::IsHexDigit {}
+
+impl<P> Describe for collection::All<P> {}
+
+impl<P> Describe for collection::AllKeys<P> {}
+
+impl<P> Describe for collection::AllValues<P> {}
+
+impl<F, P> Describe for normalize::Normalize<F, P> {}
+
+#[cfg(feature = "alloc")]
+impl<S: TypeString> Describe for string::StartsWith<S> {}
+
+#[cfg(feature = "alloc")]
+impl<S: TypeString> Describe for string::EndsWith<S> {}
+
+#[cfg(feature = "alloc")]
+impl<S: TypeString> Describe for string::Contains<S> {}
+
+#[cfg(feature = "alloc")]
+impl Describe for string::Trimmed {}
+
+#[cfg(feature = "regex")]
+impl<S: TypeString> Describe for string::Regex<S> {
+    fn describe(constraints: &mut Constraints) {
+        constraints.matching(S::VALUE);
+    }
+}
+
+/// The name of `X` with its paths stripped and punctuation replaced, such as
+/// `Refinement_u8_LessThan_100`, for schemas that name refinements after their full type.
+#[cfg(any(feature = "async-graphql", feature = "utoipa"))]
+fn type_name<X: ?Sized>() -> std::string::String {
+    let name: std::vec::Vec<_> = core::any::type_name::<X>()
+        .split(['<', '>', ',', ' '])
+        .filter_map(|segment| segment.rsplit("::").next())
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    name.join("_")
+}
+
+#[cfg(feature = "schemars")]
+#[doc(cfg(feature = "schemars"))]
+mod json_schema {
+    use alloc::{borrow::Cow, string::String};
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator};
+    use serde_json::Value;
+
+    use super::*;
+    use crate::{Predicate, Refinement, RefinementOps, WithMessage};
+
+    fn value(bound: Bound) -> Value {
+        match bound {
+            Bound::Unsigned(value) => Value::from(value),
+            Bound::Signed(value) => Value::from(value),
+        }
+    }
+
+    /// The inclusive count implied by a lower limit, for string lengths and collection sizes.
+    fn min_count(limit: Limit) -> Value {
+        let min = limit.key().max(0) + i128::from(limit.exclusive);
+        Value::from(min as u64)
+    }
+
+    /// The inclusive count implied by an upper limit, for string lengths and collection sizes.
+    fn max_count(limit: Limit) -> Value {
+        let max = (limit.key() - i128::from(limit.exclusive)).max(0);
+        Value::from(max as u64)
+    }
+
+    /// Inserts an inclusive limit, unless the base schema already has a tighter one.
+    fn tighten(schema: &mut Schema, key: &str, bound: Bound, lower: bool) {
+        let new = value(bound);
+        let tighter = match (schema.get(key).and_then(Value::as_f64), new.as_f64()) {
+            (Some(old), Some(new)) => (lower && new > old) || (!lower && new < old),
+            _ => true,
+        };
+        if tighter {
+            schema.insert(key.into(), new);
+        }
+    }
+
+    /// Narrows `schema` by `constraints`, according to the type that it describes.
+    fn constrain(schema: &mut Schema, constraints: Constraints) {
+        let ty = schema.get("type").and_then(Value::as_str).map(String::from);
+        let (min, max) = match ty.as_deref() {
+            Some("integer") | Some("number") => {
+                match constraints.minimum {
+                    Some(limit) if limit.exclusive => {
+                        schema.insert("exclusiveMinimum".into(), value(limit.value));
+                    }
+                    Some(limit) => tighten(schema, "minimum", limit.value, true),
+                    None => {}
+                }
+                match constraints.maximum {
+                    Some(limit) if limit.exclusive => {
+                        schema.insert("exclusiveMaximum".into(), value(limit.value));
+                    }
+                    Some(limit) => tighten(schema, "maximum", limit.value, false),
+                    None => {}
+                }
+                if let Some(multiple) = constraints.multiple_of {
+                    schema.insert("multipleOf".into(), value(multiple));
+                }
+                return;
+            }
+            Some("string") => {
+                if let Some(pattern) = constraints.pattern {
+                    schema.insert("pattern".into(), pattern.into());
+                }
+                ("minLength", "maxLength")
+            }
+            Some("array") => ("minItems", "maxItems"),
+            Some("object") => ("minProperties", "maxProperties"),
+            _ => return,
+        };
+        if let Some(limit) = constraints.minimum {
+            schema.insert(min.into(), min_count(limit));
+        }
+        if let Some(limit) = constraints.maximum {
+            schema.insert(max.into(), max_count(limit));
+        }
+    }
+
+    /// The schema of the base type `T`, narrowed by the [Constraints] of `P`.
+    impl<T: JsonSchema, P: Predicate<T> + Describe> JsonSchema for Refinement<T, P> {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            T::schema_name()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            Cow::Borrowed(core::any::type_name::<Self>())
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            let mut schema = T::json_schema(generator);
+            constrain(&mut schema, Constraints::of::<P>());
+            schema
+        }
+    }
+
+    impl<M: TypeString, R: RefinementOps + JsonSchema> JsonSchema for WithMessage<M, R> {
+        fn inline_schema() -> bool {
+            R::inline_schema()
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            R::schema_name()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            R::schema_id()
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            R::json_schema(generator)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<N: TypeString, R: RefinementOps + JsonSchema> JsonSchema for crate::NamedSerde<N, R>
+    where
+        R::T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        fn inline_schema() -> bool {
+            R::inline_schema()
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            R::schema_name()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            R::schema_id()
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            R::json_schema(generator)
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+#[doc(cfg(feature = "utoipa"))]
+mod openapi {
+    use std::{borrow::Cow, string::String, vec::Vec};
+
+    use utoipa::{
+        openapi::{
+            schema::{Array, Object, Schema, SchemaType, Type},
+            RefOr,
+        },
+        Number, PartialSchema, ToSchema,
+    };
+
+    use super::*;
+    use crate::{Predicate, Refinement, RefinementOps, WithMessage};
+
+    fn number(bound: Bound) -> Number {
+        match bound {
+            Bound::Unsigned(value) => Number::UInt(value),
+            Bound::Signed(value) => Number::Int(value),
+        }
+    }
+
+    fn float(number: &Number) -> f64 {
+        match *number {
+            Number::Int(value) => value as f64,
+            Number::UInt(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+
+    /// Replaces an inclusive limit, unless the base schema already has a tighter one.
+    fn tighten(current: &mut Option<Number>, bound: Bound, lower: bool) {
+        let new = number(bound);
+        let tighter = match current {
+            Some(old) => {
+                (lower && float(&new) > float(old)) || (!lower && float(&new) < float(old))
+            }
+            None => true,
+        };
+        if tighter {
+            *current = Some(new);
+        }
+    }
+
+    /// The inclusive count implied by a lower limit, for string lengths and collection sizes.
+    fn min_count(limit: Limit) -> usize {
+        (limit.key().max(0) + i128::from(limit.exclusive)) as usize
+    }
+
+    /// The inclusive count implied by an upper limit, for string lengths and collection sizes.
+    fn max_count(limit: Limit) -> usize {
+        (limit.key() - i128::from(limit.exclusive)).max(0) as usize
+    }
+
+    fn constrain_object(object: &mut Object, constraints: Constraints) {
+        let SchemaType::Type(ty) = &object.schema_type else {
+            return;
+        };
+        match ty {
+            Type::Integer | Type::Number => {
+                match constraints.minimum {
+                    Some(limit) if limit.exclusive => {
+                        object.exclusive_minimum = Some(number(limit.value))
+                    }
+                    Some(limit) => tighten(&mut object.minimum, limit.value, true),
+                    None => {}
+                }
+                match constraints.maximum {
+                    Some(limit) if limit.exclusive => {
+                        object.exclusive_maximum = Some(number(limit.value))
+                    }
+                    Some(limit) => tighten(&mut object.maximum, limit.value, false),
+                    None => {}
+                }
+                if let Some(multiple) = constraints.multiple_of {
+                    object.multiple_of = Some(number(multiple));
+                }
+            }
+            Type::String => {
+                if let Some(pattern) = constraints.pattern {
+                    object.pattern = Some(pattern.into());
+                }
+                if let Some(limit) = constraints.minimum {
+                    object.min_length = Some(min_count(limit));
+                }
+                if let Some(limit) = constraints.maximum {
+                    object.max_length = Some(max_count(limit));
+                }
+            }
+            Type::Object => {
+                if let Some(limit) = constraints.minimum {
+                    object.min_properties = Some(min_count(limit));
+                }
+                if let Some(limit) = constraints.maximum {
+                    object.max_properties = Some(max_count(limit));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn constrain_array(array: &mut Array, constraints: Constraints) {
+        if let Some(limit) = constraints.minimum {
+            array.min_items = Some(min_count(limit));
+        }
+        if let Some(limit) = constraints.maximum {
+            array.max_items = Some(max_count(limit));
+        }
+    }
+
+    /// The schema of the base type `T`, narrowed by the [Constraints] of `P`.
+    impl<T: PartialSchema, P: Predicate<T> + Describe> PartialSchema for Refinement<T, P> {
+        fn schema() -> RefOr<Schema> {
+            let mut schema = T::schema();
+            let constraints = Constraints::of::<P>();
+            match &mut schema {
+                RefOr::T(Schema::Object(object)) => constrain_object(object, constraints),
+                RefOr::T(Schema::Array(array)) => constrain_array(array, constraints),
+                _ => {}
+            }
+            schema
+        }
+    }
+
+    /// Refinements are named after their full type, including the predicate, so that refinements
+    /// of the same base type with different predicates don't collide as components.
+    impl<T: ToSchema, P: Predicate<T> + Describe> ToSchema for Refinement<T, P> {
+        fn name() -> Cow<'static, str> {
+            Cow::Owned(type_name::<Self>())
+        }
+
+        fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+            T::schemas(schemas);
+        }
+    }
+
+    impl<M: TypeString, R: RefinementOps + PartialSchema> PartialSchema for WithMessage<M, R> {
+        fn schema() -> RefOr<Schema> {
+            R::schema()
+        }
+    }
+
+    impl<M: TypeString, R: RefinementOps + ToSchema> ToSchema for WithMessage<M, R> {
+        fn name() -> Cow<'static, str> {
+            R::name()
+        }
+
+        fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+            R::schemas(schemas);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<N: TypeString, R: RefinementOps + PartialSchema> PartialSchema for crate::NamedSerde<N, R>
+    where
+        R::T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        fn schema() -> RefOr<Schema> {
+            R::schema()
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<N: TypeString, R: RefinementOps + ToSchema> ToSchema for crate::NamedSerde<N, R>
+    where
+        R::T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        fn name() -> Cow<'static, str> {
+            R::name()
+        }
+
+        fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+            R::schemas(schemas);
+        }
+    }
+}
+
+#[cfg(feature = "async-graphql")]
+#[doc(cfg(feature = "async-graphql"))]
+mod graphql {
+    use std::{borrow::Cow, format, string::String, sync::Arc, vec::Vec};
+
+    use async_graphql::{
+        registry::{MetaType, MetaTypeId, Registry},
+        InputType, InputValueError, InputValueResult, ScalarType, Value,
+    };
+
+    use super::*;
+    use crate::{Predicate, Refinement, RefinementOps};
+
+    /// Parses a value of the base type and refines it, rejecting values that violate the predicate
+    /// with its message.
+    impl<T, P> ScalarType for Refinement<T, P>
+    where
+        T: ScalarType + InputType + 'static,
+        P: Predicate<T> + Describe + Send + Sync + 'static,
+    {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            let value = <T as ScalarType>::parse(value).map_err(InputValueError::propagate)?;
+            Self::refine(value).map_err(|err| InputValueError::custom(err.message()))
+        }
+
+        fn is_valid(value: &Value) -> bool {
+            T::is_valid(value)
+        }
+
+        fn to_value(&self) -> Value {
+            ScalarType::to_value(&self.0)
+        }
+    }
+
+    /// Refinements are custom scalars named after their full type, such as
+    /// `Refinement_u8_LessThan_100`, and described by the [Constraints] of `P`. Input values are
+    /// parsed as the base type and refined, so that values that violate the predicate are rejected
+    /// with its message.
+    impl<T, P> InputType for Refinement<T, P>
+    where
+        T: InputType + 'static,
+        P: Predicate<T> + Describe + Send + Sync + 'static,
+    {
+        type RawValueType = T::RawValueType;
+
+        fn type_name() -> Cow<'static, str> {
+            Cow::Owned(type_name::<Self>())
+        }
+
+        fn create_type_info(registry: &mut Registry) -> String {
+            registry.create_input_type::<Self, _>(MetaTypeId::Scalar, |_| MetaType::Scalar {
+                name: type_name::<Self>(),
+                description: Some(format!(
+                    "A refinement of {}: {}",
+                    T::type_name(),
+                    Constraints::of::<P>()
+                )),
+                is_valid: Some(Arc::new(|value| T::parse(Some(value.clone())).is_ok())),
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                specified_by_url: None,
+                directive_invocations: Vec::new(),
+                requires_scopes: Vec::new(),
+            })
+        }
+
+        fn parse(value: Option<Value>) -> InputValueResult<Self> {
+            let value = T::parse(value).map_err(InputValueError::propagate)?;
+            Self::refine(value).map_err(|err| InputValueError::custom(err.message()))
+        }
+
+        fn to_value(&self) -> Value {
+            InputType::to_value(&self.0)
+        }
+
+        fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+            self.0.as_raw_value()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tightest_limits() {
+        let constraints = Constraints::of::<
+            And<
+                And<unsigned::GreaterThanEqual<5>, unsigned::GreaterThan<5>>,
+                And<unsigned::LessThan<10>, unsigned::LessThanEqual<20>>,
+            >,
+        >();
+        assert_eq!(
+            constraints.minimum,
+            Some(Limit::exclusive(Bound::Unsigned(5)))
+        );
+        assert_eq!(
+            constraints.maximum,
+            Some(Limit::exclusive(Bound::Unsigned(10)))
+        );
+    }
+
+    #[test]
+    fn test_signed() {
+        let constraints = Constraints::of::<signed::ClosedInterval<-5, 5>>();
+        assert_eq!(
+            constraints.minimum,
+            Some(Limit::inclusive(Bound::Signed(-5)))
+        );
+        assert_eq!(
+            constraints.maximum,
+            Some(Limit::inclusive(Bound::Signed(5)))
+        );
+        assert_eq!(
+            Constraints::of::<signed::Even>().multiple_of,
+            Some(Bound::Signed(2))
+        );
+    }
+
+    #[test]
+    fn test_inexpressible() {
+        assert_eq!(
+            Constraints::of::<Or<unsigned::LessThan<5>, unsigned::GreaterThan<10>>>(),
+            Constraints::default()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display() {
+        use alloc::string::ToString;
+
+        assert_eq!(
+            Constraints::of::<And<unsigned::OpenClosedInterval<0, 10>, unsigned::Even>>()
+                .to_string(),
+            "greater than 0, less than or equal to 10, a multiple of 2"
+        );
+        assert_eq!(Constraints::default().to_string(), "unconstrained");
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        use alloc::{string::String, vec::Vec};
+        use schemars::{json_schema, schema_for};
+
+        use crate::{
+            boundable::unsigned::{ClosedInterval, LessThan},
+            Refinement,
+        };
+
+        assert_eq!(
+            schema_for!(Refinement<u8, And<unsigned::GreaterThan<5>, unsigned::Even>>),
+            json_schema!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "uint8",
+                "type": "integer",
+                "format": "uint8",
+                "minimum": 0,
+                "maximum": 255,
+                "exclusiveMinimum": 5,
+                "multipleOf": 2
+            })
+        );
+        assert_eq!(
+            schema_for!(Refinement<String, ClosedInterval<1, 10>>),
+            json_schema!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "string",
+                "type": "string",
+                "minLength": 1,
+                "maxLength": 10
+            })
+        );
+        assert_eq!(
+            schema_for!(Refinement<Vec<u8>, LessThan<3>>)
+                .get("maxItems")
+                .cloned(),
+            Some(2.into())
+        );
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn test_openapi_schema() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        use crate::{boundable::unsigned::ClosedOpenInterval, Refinement};
+
+        type Small = Refinement<u8, And<unsigned::LessThanEqual<200>, unsigned::Divisible<5>>>;
+        assert_eq!(
+            serde_json::to_value(Small::schema()).unwrap(),
+            serde_json::json!({
+                "type": "integer",
+                "format": "int32",
+                "minimum": 0,
+                "maximum": 200,
+                "multipleOf": 5
+            })
+        );
+
+        type Name = Refinement<String, ClosedOpenInterval<1, 33>>;
+        assert_eq!(
+            serde_json::to_value(Name::schema()).unwrap(),
+            serde_json::json!({
+                "type": "string",
+                "minLength": 1,
+                "maxLength": 32
+            })
+        );
+        assert_eq!(
+            Name::name(),
+            "Refinement_String_And_GreaterThanEqual_1_LessThan_33"
+        );
+    }
+
+    #[cfg(feature = "async-graphql")]
+    #[tokio::test]
+    async fn test_graphql_input() {
+        use async_graphql::{EmptyMutation, EmptySubscription, InputObject, Object, Schema};
+
+        use crate::Refinement;
+
+        type Size = Refinement<i32, signed::ClosedOpenInterval<1, 100>>;
+
+        #[derive(InputObject)]
+        struct Item {
+            size: Size,
+        }
+
+        struct Query;
+
+        #[Object]
+        impl Query {
+            async fn size(&self, item: Item) -> i32 {
+                *item.size
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let response = schema.execute("{ size(item: { size: 5 }) }").await;
+        assert!(response.errors.is_empty());
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "size": 5 })
+        );
+
+        let response = schema.execute("{ size(item: { size: 150 }) }").await;
+        assert!(response.errors[0].message.contains("must be less than 100"));
+
+        let sdl = schema.sdl();
+        assert!(sdl.contains(concat!(
+            "\"\"\"\nA refinement of Int: greater than or equal to 1, less than 100\n\"\"\"\n",
+            "scalar Refinement_i32_And_GreaterThanEqual_1_LessThan_100\n"
+        )));
+    }
+}
@@ -0,0 +1,717 @@
+//! Stateful predicates built from runtime data.
+//!
+//! Some predicates depend on data that is only available at startup, such as a file of permitted
+//! values or a list of patterns read from configuration. The [StatefulPredicate] implementations
+//! in this module are constructed from that data explicitly and used with
+//! [StatefulRefinementOps](crate::StatefulRefinementOps).
+//!
+//! Because every [StatefulPredicate] is also a [Predicate], these predicates can still be used
+//! statelessly (e.g. when deserializing), in which case they have no data and reject every value.
+//! [WithState] deserializes refinements using the state of a predicate instead.
+//!
+//! Stateful predicates can be combined with [And], [Or] and [Not], which hold the state of each
+//! of the predicates that they combine, and their results can be memoized with [Cached].
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, stateful::Allowlist};
+//!
+//! type Region = Refinement<String, Allowlist>;
+//!
+//! // e.g. read from a configuration file
+//! let regions = Allowlist::from_lines("# supported regions\nus-east-1\neu-west-2\n");
+//!
+//! assert!(Region::refine_with_state(&regions, "eu-west-2".to_string()).is_ok());
+//! assert_eq!(
+//!     Region::refine_with_state(&regions, "ap-south-1".to_string())
+//!         .unwrap_err()
+//!         .to_string(),
+//!     "refinement violated: must be one of eu-west-2, us-east-1"
+//! );
+//! ```
+//!
+//! Combining an allowlist with a length bound that is also provided at runtime:
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::DynLessThan, stateful::{self, Allowlist}};
+//!
+//! type Region = Refinement<String, stateful::And<Allowlist, DynLessThan>>;
+//!
+//! let rules = stateful::And::new(
+//!     Allowlist::new(["us-east-1", "eu-west-2", "ap-southeast-2"]),
+//!     DynLessThan::new(10),
+//! );
+//! assert!(Region::refine_with_state(&rules, "eu-west-2".to_string()).is_ok());
+//! assert!(Region::refine_with_state(&rules, "ap-southeast-2".to_string()).is_err());
+//! assert!(Region::refine_with_state(&rules, "ap-south-1".to_string()).is_err());
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+
+use crate::{error::ErrorKind, ErrorMessage, Predicate, RefinementError, StatefulPredicate};
+#[cfg(feature = "serde")]
+use crate::{RefinementOps, StatefulRefinementOps};
+
+/// The error of the combinators when refined without the state of their predicates.
+const STATELESS: &str = "must be refined with the state of its predicates";
+
+/// Logical conjunction of two [stateful predicates](StatefulPredicate).
+///
+/// Used statelessly, this rejects every value, since the state of its predicates is unavailable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct And<A, B>(pub A, pub B);
+
+impl<A, B> And<A, B> {
+    /// Combines two stateful predicates, both of which must hold.
+    pub const fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<T, A: StatefulPredicate<T>, B: StatefulPredicate<T>> Predicate<T> for And<A, B> {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::And
+    }
+}
+
+impl<T, A: StatefulPredicate<T>, B: StatefulPredicate<T>> StatefulPredicate<T> for And<A, B> {
+    fn test(&self, value: &T) -> bool {
+        self.0.test(value) && self.1.test(value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.0.error(), self.1.error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        let mut causes = Vec::new();
+        if !self.0.test(value) {
+            causes.push(self.0.violation(value));
+        }
+        if !self.1.test(value) {
+            causes.push(self.1.violation(value));
+        }
+        RefinementError::new(ErrorKind::And, StatefulPredicate::error(self)).with_causes(causes)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn violation(&self, value: &T) -> RefinementError {
+        if !self.0.test(value) {
+            self.0.violation(value)
+        } else {
+            self.1.violation(value)
+        }
+    }
+}
+
+/// Logical disjunction of two [stateful predicates](StatefulPredicate).
+///
+/// Used statelessly, this rejects every value, since the state of its predicates is unavailable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Or<A, B>(pub A, pub B);
+
+impl<A, B> Or<A, B> {
+    /// Combines two stateful predicates, at least one of which must hold.
+    pub const fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<T, A: StatefulPredicate<T>, B: StatefulPredicate<T>> Predicate<T> for Or<A, B> {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::Or
+    }
+}
+
+impl<T, A: StatefulPredicate<T>, B: StatefulPredicate<T>> StatefulPredicate<T> for Or<A, B> {
+    fn test(&self, value: &T) -> bool {
+        self.0.test(value) || self.1.test(value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} or {}", self.0.error(), self.1.error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self, value: &T) -> RefinementError {
+        RefinementError::new(ErrorKind::Or, StatefulPredicate::error(self)).with_causes(
+            alloc::vec![self.0.violation(value), self.1.violation(value)],
+        )
+    }
+}
+
+/// Logical negation of a [stateful predicate](StatefulPredicate).
+///
+/// Used statelessly, this rejects every value, since the state of its predicates is unavailable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Not<P>(pub P);
+
+impl<P> Not<P> {
+    /// Negates a stateful predicate.
+    pub const fn new(predicate: P) -> Self {
+        Self(predicate)
+    }
+}
+
+impl<T, P: StatefulPredicate<T>> Predicate<T> for Not<P> {
+    fn test(_value: &T) -> bool {
+        false
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from(STATELESS)
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::Not
+    }
+}
+
+impl<T, P: StatefulPredicate<T>> StatefulPredicate<T> for Not<P> {
+    fn test(&self, value: &T) -> bool {
+        !self.0.test(value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("not {}", self.0.error())
+    }
+}
+
+/// A [DeserializeSeed](serde::de::DeserializeSeed) that refines a deserialized value with a
+/// caller-supplied stateful predicate.
+///
+/// Deserializing a refinement with [Deserialize](serde::Deserialize) always uses the stateless
+/// predicate. Deserializing through this seed instead uses the state of `predicate`, e.g. a
+/// precompiled regex or runtime bounds.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::DynLessThan, stateful::WithState};
+/// use serde::de::DeserializeSeed;
+///
+/// type Size = Refinement<u8, DynLessThan>;
+///
+/// let max = DynLessThan::new(100);
+/// let mut de = serde_json::Deserializer::from_str("42");
+/// let size: Size = WithState::new(&max).deserialize(&mut de).unwrap();
+/// assert_eq!(*size, 42);
+///
+/// let mut de = serde_json::Deserializer::from_str("123");
+/// let err = WithState::<Size, _>::new(&max).deserialize(&mut de).unwrap_err();
+/// assert_eq!(err.to_string(), "refinement violated: must be less than 100");
+/// ```
+#[cfg(feature = "serde")]
+#[doc(cfg(feature = "serde"))]
+pub struct WithState<'a, R, P> {
+    predicate: &'a P,
+    refinement: PhantomData<fn() -> R>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a, R, P> WithState<'a, R, P> {
+    pub const fn new(predicate: &'a P) -> Self {
+        Self {
+            predicate,
+            refinement: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, P, R> serde::de::DeserializeSeed<'de> for WithState<'_, R, P>
+where
+    T: serde::Deserialize<'de>,
+    P: StatefulPredicate<T>,
+    R: StatefulRefinementOps<T, P> + RefinementOps<T = T>,
+{
+    type Value = R;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<R, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        R::refine_with_state(self.predicate, value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(cfg(feature = "alloc"))]
+mod lists {
+    use super::*;
+    use alloc::{collections::BTreeSet, string::String};
+
+    /// Splits configuration text into its values: one per line, ignoring surrounding whitespace,
+    /// blank lines and lines starting with `#`.
+    fn lines(text: &str) -> impl Iterator<Item = &str> {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    }
+
+    /// A stateful predicate that holds for strings contained in a set of permitted values.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+    pub struct Allowlist(BTreeSet<String>);
+
+    impl Allowlist {
+        /// Creates an allowlist of the given values.
+        pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(values: I) -> Self {
+            Self(values.into_iter().map(Into::into).collect())
+        }
+
+        /// Creates an allowlist from text containing one value per line.
+        ///
+        /// Surrounding whitespace is trimmed, and blank lines and lines starting with `#` are ignored.
+        pub fn from_lines(text: &str) -> Self {
+            Self::new(lines(text))
+        }
+
+        /// Creates an allowlist from a file containing one value per line. See
+        /// [from_lines](Allowlist::from_lines).
+        #[cfg(feature = "std")]
+        #[doc(cfg(feature = "std"))]
+        pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+            Ok(Self::from_lines(&std::fs::read_to_string(path)?))
+        }
+
+        /// Whether `value` is permitted.
+        pub fn contains(&self, value: &str) -> bool {
+            self.0.contains(value)
+        }
+
+        /// The permitted values, in order.
+        pub fn values(&self) -> impl Iterator<Item = &str> {
+            self.0.iter().map(String::as_str)
+        }
+    }
+
+    impl<T: AsRef<str>> Predicate<T> for Allowlist {
+        fn test(_value: &T) -> bool {
+            false
+        }
+
+        fn error() -> ErrorMessage {
+            String::from("must be one of the permitted values")
+        }
+    }
+
+    impl<T: AsRef<str>> StatefulPredicate<T> for Allowlist {
+        fn test(&self, value: &T) -> bool {
+            self.contains(value.as_ref())
+        }
+
+        fn error(&self) -> ErrorMessage {
+            let values: Vec<_> = self.values().collect();
+            format!("must be one of {}", values.join(", "))
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[doc(cfg(feature = "regex"))]
+    mod regex_list {
+        use super::*;
+
+        /// A stateful predicate that holds for strings matching any of a list of regular expressions.
+        ///
+        /// The expressions are compiled once, when the predicate is constructed.
+        #[derive(Clone, Debug)]
+        pub struct RegexList(regex::RegexSet);
+
+        impl RegexList {
+            /// Compiles a list of regular expressions.
+            pub fn new<I: IntoIterator<Item = S>, S: AsRef<str>>(
+                patterns: I,
+            ) -> Result<Self, regex::Error> {
+                Ok(Self(regex::RegexSet::new(patterns)?))
+            }
+
+            /// Compiles regular expressions from text containing one expression per line. See
+            /// [Allowlist::from_lines].
+            pub fn from_lines(text: &str) -> Result<Self, regex::Error> {
+                Self::new(lines(text))
+            }
+
+            /// The regular expressions, in the order that they were provided.
+            pub fn patterns(&self) -> &[String] {
+                self.0.patterns()
+            }
+        }
+
+        impl<T: AsRef<str>> Predicate<T> for RegexList {
+            fn test(_value: &T) -> bool {
+                false
+            }
+
+            fn error() -> ErrorMessage {
+                String::from("must match one of the permitted regular expressions")
+            }
+        }
+
+        impl<T: AsRef<str>> StatefulPredicate<T> for RegexList {
+            fn test(&self, value: &T) -> bool {
+                self.0.is_match(value.as_ref())
+            }
+
+            fn error(&self) -> ErrorMessage {
+                format!(
+                    "must match one of the regular expressions {}",
+                    self.patterns().join(", ")
+                )
+            }
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    pub use regex_list::*;
+}
+
+#[cfg(feature = "alloc")]
+pub use lists::*;
+
+#[cfg(feature = "std")]
+#[doc(cfg(feature = "std"))]
+mod cached {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use core::{fmt::Debug, hash::Hash};
+    use std::{
+        collections::HashMap,
+        sync::{Mutex, PoisonError},
+    };
+
+    /// The results of recent tests, evicting the least recently used result when full.
+    #[derive(Debug)]
+    struct Lru<T> {
+        results: HashMap<T, (bool, u64)>,
+        recency: BTreeMap<u64, T>,
+        tick: u64,
+    }
+
+    impl<T: Hash + Eq + Clone> Lru<T> {
+        fn get(&mut self, value: &T) -> Option<bool> {
+            let (result, used) = self.results.get_mut(value)?;
+            let key = self
+                .recency
+                .remove(used)
+                .expect("every result has a recency");
+            self.tick += 1;
+            *used = self.tick;
+            self.recency.insert(self.tick, key);
+            Some(*result)
+        }
+
+        fn insert(&mut self, value: &T, result: bool, capacity: usize) {
+            if capacity == 0 || self.results.contains_key(value) {
+                return;
+            }
+            if self.results.len() >= capacity {
+                if let Some((_, evicted)) = self.recency.pop_first() {
+                    self.results.remove(&evicted);
+                }
+            }
+            self.tick += 1;
+            self.results.insert(value.clone(), (result, self.tick));
+            self.recency.insert(self.tick, value.clone());
+        }
+    }
+
+    /// A stateful predicate that memoizes the results of testing the most recently used values
+    /// with `P`.
+    ///
+    /// This is useful when `P` is expensive to test and the same few values are refined
+    /// repeatedly, such as recurring strings in log ingestion. At most `capacity` results are
+    /// cached, evicting the least recently used result when full. The cache is shared between
+    /// threads, and errors are always produced by `P`.
+    ///
+    /// Used statelessly, this behaves like `P`.
+    ///
+    /// ```
+    /// use refined::{prelude::*, stateful::{Allowlist, Cached}};
+    ///
+    /// type Level = Refinement<String, Cached<Allowlist, String>>;
+    ///
+    /// let levels = Cached::new(Allowlist::new(["DEBUG", "INFO", "WARN", "ERROR"]), 16);
+    /// for line in ["INFO", "info", "INFO", "trace", "INFO"] {
+    ///     let _ = Level::refine_with_state(&levels, line.to_string());
+    /// }
+    /// assert_eq!(levels.len(), 3);
+    /// assert!(Level::refine_with_state(&levels, "INFO".to_string()).is_ok());
+    /// assert!(Level::refine_with_state(&levels, "trace".to_string()).is_err());
+    /// ```
+    pub struct Cached<P, T> {
+        predicate: P,
+        capacity: usize,
+        cache: Mutex<Lru<T>>,
+    }
+
+    impl<P, T> Cached<P, T> {
+        /// Wraps `predicate`, caching the results of testing at most `capacity` values.
+        pub fn new(predicate: P, capacity: usize) -> Self {
+            Self {
+                predicate,
+                capacity,
+                cache: Mutex::new(Lru {
+                    results: HashMap::new(),
+                    recency: BTreeMap::new(),
+                    tick: 0,
+                }),
+            }
+        }
+
+        /// The predicate whose results are cached.
+        pub fn predicate(&self) -> &P {
+            &self.predicate
+        }
+
+        /// The number of values whose results are cached.
+        pub fn len(&self) -> usize {
+            self.lock().results.len()
+        }
+
+        /// Whether no results are cached.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Discards every cached result.
+        pub fn clear(&self) {
+            let mut cache = self.lock();
+            cache.results.clear();
+            cache.recency.clear();
+        }
+
+        fn lock(&self) -> std::sync::MutexGuard<'_, Lru<T>> {
+            self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    impl<P: Debug, T> Debug for Cached<P, T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("Cached")
+                .field("predicate", &self.predicate)
+                .field("capacity", &self.capacity)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<T, P: StatefulPredicate<T>> Predicate<T> for Cached<P, T> {
+        fn test(value: &T) -> bool {
+            <P as Predicate<T>>::test(value)
+        }
+
+        fn normalize(value: T) -> T {
+            P::normalize(value)
+        }
+
+        fn error() -> ErrorMessage {
+            <P as Predicate<T>>::error()
+        }
+
+        fn kind() -> ErrorKind {
+            P::kind()
+        }
+
+        fn violation(value: &T) -> RefinementError {
+            <P as Predicate<T>>::violation(value)
+        }
+
+        fn explain(value: &T) -> crate::explain::Explanation {
+            <P as Predicate<T>>::explain(value)
+        }
+    }
+
+    impl<T: Hash + Eq + Clone, P: StatefulPredicate<T>> StatefulPredicate<T> for Cached<P, T> {
+        fn test(&self, value: &T) -> bool {
+            if let Some(result) = self.lock().get(value) {
+                return result;
+            }
+            let result = self.predicate.test(value);
+            self.lock().insert(value, result, self.capacity);
+            result
+        }
+
+        fn error(&self) -> ErrorMessage {
+            self.predicate.error()
+        }
+
+        fn violation(&self, value: &T) -> RefinementError {
+            self.predicate.violation(value)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use cached::*;
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::{boundable::unsigned::*, error::Bound, *};
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn test_and() {
+        type Test = Refinement<u8, And<DynGreaterThan, DynLessThan>>;
+        let predicate = And::new(DynGreaterThan::new(5), DynLessThan::new(10));
+        assert!(Test::refine_with_state(&predicate, 6).is_ok());
+        assert!(Test::refine_with_state(&predicate, 5).is_err());
+        let err = Test::refine_with_state(&predicate, 10).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::And);
+        assert_eq!(
+            err.violations()[0].kind(),
+            ErrorKind::LessThan(Bound::Unsigned(10))
+        );
+    }
+
+    #[test]
+    fn test_combinators_stateless() {
+        assert!(Refinement::<u8, And<DynGreaterThan, DynLessThan>>::refine(6).is_err());
+        assert!(Refinement::<u8, Or<DynLessThan, DynGreaterThan>>::refine(6).is_err());
+        let err = Refinement::<u8, Not<DynLessThan>>::refine(6).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: must be refined with the state of its predicates"
+        );
+    }
+
+    #[test]
+    fn test_or_not() {
+        type Test = Refinement<u8, Or<DynLessThan, Not<DynLessThan>>>;
+        let predicate = Or::new(DynLessThan::new(5), Not::new(DynLessThan::new(10)));
+        assert!(Test::refine_with_state(&predicate, 4).is_ok());
+        assert!(Test::refine_with_state(&predicate, 10).is_ok());
+        let err = Test::refine_with_state(&predicate, 7).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Or);
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: must be less than 5 or not must be less than 10"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_with_state() {
+        use serde::de::DeserializeSeed;
+
+        type_string!(Size, "size");
+        type Test = NamedSerde<Size, Refinement<u8, DynClosedInterval>>;
+        let predicate = DynClosedInterval::new(5, 10);
+        let mut de = serde_json::Deserializer::from_str("7");
+        let value: Test = WithState::new(&predicate).deserialize(&mut de).unwrap();
+        assert_eq!(*value, 7);
+        let mut de = serde_json::Deserializer::from_str("11");
+        let err = WithState::<Test, _>::new(&predicate)
+            .deserialize(&mut de)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: size must be less than or equal to 10"
+        );
+    }
+
+    #[test]
+    fn test_allowlist() {
+        type Test = Refinement<String, Allowlist>;
+        let allowlist = Allowlist::from_lines("  foo \n\n# comment\nbar\n");
+        assert_eq!(allowlist.values().collect::<Vec<_>>(), ["bar", "foo"]);
+        assert!(Test::refine_with_state(&allowlist, "foo".to_string()).is_ok());
+        assert!(Test::refine_with_state(&allowlist, "# comment".to_string()).is_err());
+        assert!(Test::refine("foo".to_string()).is_err());
+    }
+
+    /// Counts how many times it is tested statefully.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Default)]
+    struct Counted(DynLessThan, core::sync::atomic::AtomicUsize);
+
+    #[cfg(feature = "std")]
+    impl Predicate<u8> for Counted {
+        fn test(value: &u8) -> bool {
+            <DynLessThan as Predicate<u8>>::test(value)
+        }
+
+        fn error() -> ErrorMessage {
+            <DynLessThan as Predicate<u8>>::error()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl StatefulPredicate<u8> for Counted {
+        fn test(&self, value: &u8) -> bool {
+            self.1.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+            self.0.test(value)
+        }
+
+        fn error(&self) -> ErrorMessage {
+            StatefulPredicate::<u8>::error(&self.0)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cached() {
+        type Test = Refinement<u8, Cached<Counted, u8>>;
+        let predicate = Cached::new(Counted(DynLessThan::new(10), Default::default()), 2);
+        let tests = || {
+            predicate
+                .predicate()
+                .1
+                .load(core::sync::atomic::Ordering::Relaxed)
+        };
+        assert!(predicate.is_empty());
+        assert!(Test::refine_with_state(&predicate, 1).is_ok());
+        assert!(Test::refine_with_state(&predicate, 1).is_ok());
+        let err = Test::refine_with_state(&predicate, 20).unwrap_err();
+        assert_eq!(err.to_string(), "refinement violated: must be less than 10");
+        assert!(Test::refine_with_state(&predicate, 20).is_err());
+        assert_eq!((predicate.len(), tests()), (2, 2));
+
+        // 1 was used less recently than 20, so it is evicted first.
+        assert!(Test::refine_with_state(&predicate, 3).is_ok());
+        assert!(Test::refine_with_state(&predicate, 20).is_err());
+        assert_eq!((predicate.len(), tests()), (2, 3));
+        assert!(Test::refine_with_state(&predicate, 1).is_ok());
+        assert_eq!(tests(), 4);
+
+        predicate.clear();
+        assert!(predicate.is_empty());
+        assert!(Test::refine(1).is_err());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_list() {
+        type Test = Refinement<String, RegexList>;
+        let regexes = RegexList::new(["^a+$", "^b+$"]).unwrap();
+        assert!(Test::refine_with_state(&regexes, "aaa".to_string()).is_ok());
+        assert!(Test::refine_with_state(&regexes, "bb".to_string()).is_ok());
+        let err = Test::refine_with_state(&regexes, "ab".to_string()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: must match one of the regular expressions ^a+$, ^b+$"
+        );
+        assert!(RegexList::from_lines("(").is_err());
+    }
+}
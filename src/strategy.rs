@@ -0,0 +1,274 @@
+//! [proptest](https://docs.rs/proptest) strategies that generate valid refinements.
+//!
+//! [Refinement] implements [Arbitrary], so property tests over refined domain models can draw
+//! values with `Refinement::<T, P>::arbitrary()` or [any]. Values are generated from the
+//! [Constraints] that the predicate [describes](Describe): bounds become range strategies, `multiple_of`
+//! steps through multiples, and regular expressions become
+//! [string_regex](proptest::string::string_regex) strategies. [And](crate::boolean::And)
+//! intersects the constraints of both of its predicates. Everything else, including
+//! [Or](crate::boolean::Or) and [Not](crate::boolean::Not), is enforced by filtering, so
+//! predicates that reject most of the values of their constraints (such as
+//! [StartsWith](crate::string::StartsWith)) may exhaust proptest's rejection limit and need a
+//! dedicated strategy.
+//!
+//! Base types implement [ConstrainedArbitrary] to generate values within a set of [Constraints].
+//!
+//! # Example
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use refined::{prelude::*, boundable::unsigned::{ClosedInterval, Even}};
+//!
+//! type Level = Refinement<u8, ClosedInterval<1, 10>>;
+//! type Pairs = Refinement<u32, Even>;
+//!
+//! proptest! {
+//!     fn levels_are_in_range(level in Level::arbitrary(), pairs in any::<Pairs>()) {
+//!         prop_assert!((1..=10).contains(&*level));
+//!         prop_assert_eq!(*pairs % 2, 0);
+//!     }
+//! }
+//! levels_are_in_range();
+//! ```
+use core::{fmt::Debug, ops::RangeInclusive};
+use std::{string::String, vec::Vec};
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection,
+    strategy::{BoxedStrategy, Just, Strategy},
+    string::string_regex,
+};
+
+use crate::{
+    schema::{Bound, Constraints, Describe},
+    Predicate, Refinement, RefinementOps,
+};
+
+/// The number of lengths beyond the minimum that are generated when the length of a string or
+/// collection has no upper limit.
+const UNBOUNDED_LENGTHS: i128 = 32;
+
+/// Base types that can generate values within a set of [Constraints].
+///
+/// The constraints are a hint: the generated values are filtered by the predicate, so
+/// implementations may ignore constraints that they can't satisfy directly.
+pub trait ConstrainedArbitrary: Sized + Debug + 'static {
+    /// A strategy that generates values within `constraints`.
+    fn strategy(constraints: &Constraints) -> BoxedStrategy<Self>;
+}
+
+/// Generates values of the base type within the [Constraints] of `P`, keeping those that satisfy
+/// it.
+impl<T, P> Arbitrary for Refinement<T, P>
+where
+    T: ConstrainedArbitrary,
+    P: Predicate<T> + Describe + Debug + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        T::strategy(&Constraints::of::<P>())
+            .prop_filter_map("value violates the predicate", |value| {
+                Self::refine(value).ok()
+            })
+            .boxed()
+    }
+}
+
+fn value(bound: Bound) -> i128 {
+    match bound {
+        Bound::Unsigned(value) => value as i128,
+        Bound::Signed(value) => value as i128,
+    }
+}
+
+/// Integers within `min..=max` that satisfy `constraints`.
+fn integers(constraints: &Constraints, min: i128, max: i128) -> BoxedStrategy<i128> {
+    let (min, max) = constraints.range(min, max);
+    let step = match constraints.multiple_of.map(value) {
+        Some(step) if step != 0 => step.abs(),
+        _ => 1,
+    };
+    let first = min.div_euclid(step) + i128::from(min.rem_euclid(step) != 0);
+    let last = max.div_euclid(step);
+    if first > last {
+        // Nothing satisfies the constraints; the predicate will reject this.
+        return Just(min).boxed();
+    }
+    (first..=last)
+        .prop_map(move |multiple| multiple * step)
+        .boxed()
+}
+
+/// The lengths of strings or collections that satisfy `constraints`.
+fn lengths(constraints: &Constraints) -> Option<RangeInclusive<usize>> {
+    let (min, max) = constraints.range(0, usize::MAX as i128);
+    let max = match constraints.maximum {
+        Some(_) => max,
+        None => max.min(min + UNBOUNDED_LENGTHS),
+    };
+    (min <= max).then_some(min as usize..=max as usize)
+}
+
+/// `pattern` without the anchors at its start and end, which [string_regex] doesn't support. The
+/// generated strings match the whole pattern, so they satisfy the anchors regardless.
+fn unanchored(pattern: &str) -> &str {
+    let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+    match pattern.strip_suffix('$') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => pattern,
+    }
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl ConstrainedArbitrary for $ty {
+                fn strategy(constraints: &Constraints) -> BoxedStrategy<Self> {
+                    integers(constraints, <$ty>::MIN as i128, <$ty>::MAX as i128)
+                        .prop_map(|value| value as $ty)
+                        .boxed()
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! unconstrained {
+    ($($ty:ty),*) => {
+        $(
+            /// No predicates constrain this type, so values are generated by its [Arbitrary]
+            /// implementation.
+            impl ConstrainedArbitrary for $ty {
+                fn strategy(_: &Constraints) -> BoxedStrategy<Self> {
+                    any::<$ty>().boxed()
+                }
+            }
+        )*
+    };
+}
+
+unconstrained!(bool, char, f32, f64);
+
+/// Strings that match the pattern of the constraints, if any. Otherwise, strings with a length
+/// constraint are generated from printable ASCII characters, so that their length in bytes is
+/// their number of characters.
+impl ConstrainedArbitrary for String {
+    fn strategy(constraints: &Constraints) -> BoxedStrategy<Self> {
+        if let Some(strategy) = constraints
+            .pattern
+            .and_then(|pattern| string_regex(unanchored(pattern)).ok())
+        {
+            return strategy.boxed();
+        }
+        if constraints.minimum.is_none() && constraints.maximum.is_none() {
+            return any::<String>().boxed();
+        }
+        match lengths(constraints) {
+            Some(lengths) => collection::vec(0x20u8..0x7f, lengths)
+                .prop_map(|bytes| bytes.into_iter().map(char::from).collect())
+                .boxed(),
+            None => Just(String::new()).boxed(),
+        }
+    }
+}
+
+/// Vectors whose number of elements satisfies the constraints.
+impl<T: Arbitrary + 'static> ConstrainedArbitrary for Vec<T> {
+    fn strategy(constraints: &Constraints) -> BoxedStrategy<Self> {
+        match lengths(constraints) {
+            Some(lengths) => collection::vec(any::<T>(), lengths).boxed(),
+            None => Just(()).prop_map(|()| Vec::new()).boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::*, test_runner::TestRunner};
+
+    use super::*;
+    use crate::{
+        boolean::{Not, Or},
+        boundable::{signed, unsigned},
+        string::Trimmed,
+    };
+
+    fn check<R: Arbitrary + Debug>(test: impl Fn(R) -> bool) {
+        TestRunner::default()
+            .run(&any::<R>(), |value| {
+                prop_assert!(test(value));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_bounds() {
+        check(|value: Refinement<u8, unsigned::ClosedInterval<1, 10>>| (1..=10).contains(&*value));
+        check(
+            |value: Refinement<u64, unsigned::GreaterThan<{ usize::MAX - 2 }>>| {
+                *value > u64::MAX - 2
+            },
+        );
+        check(|value: Refinement<i8, signed::OpenInterval<-100, -90>>| {
+            (-99..=-91).contains(&*value)
+        });
+        check(|value: Refinement<i32, signed::Equals<-7>>| *value == -7);
+    }
+
+    #[test]
+    fn test_multiples() {
+        check(|value: Refinement<u32, unsigned::Divisible<1000>>| value.is_multiple_of(1000));
+        check(
+            |value: Refinement<
+                i16,
+                crate::boolean::And<signed::Divisible<7>, signed::Negative>,
+            >| { *value % 7 == 0 && *value < 0 },
+        );
+    }
+
+    #[test]
+    fn test_filtered() {
+        check(|value: Refinement<u8, unsigned::NonZero>| *value != 0);
+        check(
+            |value: Refinement<u16, Or<unsigned::LessThan<10>, unsigned::GreaterThan<60000>>>| {
+                *value < 10 || *value > 60000
+            },
+        );
+        check(|value: Refinement<String, Trimmed>| value.trim() == value.as_str());
+        check(
+            |value: Refinement<char, Not<crate::character::IsWhitespace>>| !value.is_whitespace(),
+        );
+    }
+
+    #[test]
+    fn test_lengths() {
+        check(
+            |value: Refinement<String, unsigned::ClosedInterval<3, 5>>| {
+                (3..=5).contains(&value.len())
+            },
+        );
+        check(
+            |value: Refinement<Vec<Refinement<u8, unsigned::Odd>>, unsigned::GreaterThan<2>>| {
+                value.len() > 2 && value.iter().all(|item| **item % 2 == 1)
+            },
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex() {
+        use crate::{type_string, TypeString};
+
+        type_string!(Code, "^[A-Z]{3}-[0-9]{4}$");
+
+        check(|value: Refinement<String, crate::string::Regex<Code>>| {
+            value.len() == 8 && value.as_bytes()[3] == b'-'
+        });
+    }
+}
@@ -17,7 +17,7 @@
 use alloc::format;
 use core::marker::PhantomData;
 
-use crate::{ErrorMessage, Predicate, TypeString};
+use crate::{error::ErrorKind, ErrorMessage, Predicate, TypeString};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct StartsWith<Prefix: TypeString>(PhantomData<Prefix>);
@@ -31,6 +31,10 @@ impl<T: AsRef<str>, Prefix: TypeString> Predicate<T> for StartsWith<Prefix> {
         format!("must start with '{}'", Prefix::VALUE)
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::StartsWith(Prefix::VALUE)
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -48,6 +52,10 @@ impl<T: AsRef<str>, Suffix: TypeString> Predicate<T> for EndsWith<Suffix> {
         format!("must end with '{}'", Suffix::VALUE)
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::EndsWith(Suffix::VALUE)
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -57,14 +65,24 @@ impl<T: AsRef<str>, Suffix: TypeString> Predicate<T> for EndsWith<Suffix> {
 pub struct Contains<Substr: TypeString>(PhantomData<Substr>);
 
 impl<T: AsRef<str>, Substr: TypeString> Predicate<T> for Contains<Substr> {
+    #[cfg(not(feature = "memchr"))]
     fn test(s: &T) -> bool {
         s.as_ref().contains(Substr::VALUE)
     }
 
+    #[cfg(feature = "memchr")]
+    fn test(s: &T) -> bool {
+        memchr::memmem::find(s.as_ref().as_bytes(), Substr::VALUE.as_bytes()).is_some()
+    }
+
     fn error() -> ErrorMessage {
         format!("must contain '{}'", Substr::VALUE)
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Contains(Substr::VALUE)
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -75,13 +93,96 @@ pub struct Trimmed;
 
 impl<T: AsRef<str>> Predicate<T> for Trimmed {
     fn test(s: &T) -> bool {
-        s.as_ref().trim() == s.as_ref()
+        // Trimming only inspects the ends of the string, so comparing lengths avoids a full scan.
+        s.as_ref().trim().len() == s.as_ref().len()
     }
 
     fn error() -> ErrorMessage {
         ErrorMessage::from("must not start or end with whitespace")
     }
 
+    fn kind() -> ErrorKind {
+        ErrorKind::Trimmed
+    }
+
+    unsafe fn optimize(value: &T) {
+        core::hint::assert_unchecked(Self::test(value));
+    }
+}
+
+/// Whether `s` contains a character for which [char::is_whitespace] holds.
+#[cfg(not(feature = "memchr"))]
+fn contains_whitespace(s: &str) -> bool {
+    s.chars().any(char::is_whitespace)
+}
+
+/// Whether `s` contains a character for which [char::is_whitespace] holds.
+///
+/// Whitespace is either an ASCII byte no greater than a space, or a non-ASCII character, which is
+/// more than [memchr::memchr3] can search for at once. Instead, `s` is scanned for those bytes a
+/// chunk at a time, in a single pass that vectorizes, and only the chunks that contain them are
+/// decoded.
+#[cfg(feature = "memchr")]
+fn contains_whitespace(s: &str) -> bool {
+    const CHUNK: usize = 32;
+
+    let mut start = 0;
+    while start < s.len() {
+        let mut end = (start + CHUNK).min(s.len());
+        while !s.is_char_boundary(end) {
+            end += 1;
+        }
+        let chunk = &s[start..end];
+        let candidate = chunk
+            .bytes()
+            .fold(false, |found, b| found | (b <= b' ' || !b.is_ascii()));
+        if candidate && chunk.chars().any(char::is_whitespace) {
+            return true;
+        }
+        start = end;
+    }
+    false
+}
+
+/// Contains no whitespace characters, as defined by [char::is_whitespace].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NoWhitespace;
+
+impl<T: AsRef<str>> Predicate<T> for NoWhitespace {
+    fn test(s: &T) -> bool {
+        !contains_whitespace(s.as_ref())
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from("must not contain whitespace")
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::NoWhitespace
+    }
+
+    unsafe fn optimize(value: &T) {
+        core::hint::assert_unchecked(Self::test(value));
+    }
+}
+
+/// Contains only ASCII characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsAscii;
+
+impl<T: AsRef<str>> Predicate<T> for IsAscii {
+    fn test(s: &T) -> bool {
+        s.as_ref().is_ascii()
+    }
+
+    fn error() -> ErrorMessage {
+        ErrorMessage::from("must be ASCII")
+    }
+
+    fn kind() -> ErrorKind {
+        ErrorKind::Ascii
+    }
+
     unsafe fn optimize(value: &T) {
         core::hint::assert_unchecked(Self::test(value));
     }
@@ -96,17 +197,53 @@ mod regex_pred {
     #[derive(Clone, Debug)]
     pub struct Regex<S: TypeString>(regex::Regex, PhantomData<S>);
 
+    /// Compiles the regular expression `S`.
+    #[cfg(not(feature = "regex-cache"))]
+    fn compile<S: TypeString>() -> regex::Regex {
+        regex::Regex::new(S::VALUE).expect("Invalid regex")
+    }
+
+    /// Compiles the regular expression `S`, or returns a cached copy if it has already been compiled.
+    ///
+    /// Generic statics are shared between monomorphizations, so the cache is keyed by pattern.
+    #[cfg(feature = "regex-cache")]
+    fn compile<S: TypeString>() -> regex::Regex {
+        use std::{
+            collections::HashMap,
+            sync::{LazyLock, RwLock},
+        };
+
+        static CACHE: LazyLock<RwLock<HashMap<&'static str, regex::Regex>>> =
+            LazyLock::new(Default::default);
+
+        if let Some(regex) = CACHE
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(S::VALUE)
+        {
+            return regex.clone();
+        }
+        CACHE
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(S::VALUE)
+            .or_insert_with(|| regex::Regex::new(S::VALUE).expect("Invalid regex"))
+            .clone()
+    }
+
     impl<S: TypeString, T: AsRef<str>> Predicate<T> for Regex<S> {
         fn test(s: &T) -> bool {
-            regex::Regex::new(S::VALUE)
-                .expect("Invalid regex")
-                .is_match(s.as_ref())
+            compile::<S>().is_match(s.as_ref())
         }
 
         fn error() -> ErrorMessage {
             format!("must match regular expression {}", S::VALUE)
         }
 
+        fn kind() -> ErrorKind {
+            ErrorKind::Regex(S::VALUE)
+        }
+
         unsafe fn optimize(value: &T) {
             core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
         }
@@ -114,10 +251,7 @@ mod regex_pred {
 
     impl<S: TypeString> Default for Regex<S> {
         fn default() -> Self {
-            Self(
-                regex::Regex::new(S::VALUE).expect("Invalid regex"),
-                PhantomData,
-            )
+            Self(compile::<S>(), PhantomData)
         }
     }
 
@@ -147,6 +281,16 @@ mod regex_pred {
             assert!(Test::refine("aab".to_string()).is_err());
         }
 
+        #[cfg(feature = "regex-cache")]
+        #[test]
+        fn test_regex_cache() {
+            type_string!(AlsoAllAs, "^a+$");
+            type Test = Refinement<String, Regex<AlsoAllAs>>;
+            assert!(Test::refine("aaa".to_string()).is_ok());
+            assert!(Test::refine("aab".to_string()).is_err());
+            assert!(Refinement::<String, Regex<AllAs>>::refine("a".to_string()).is_ok());
+        }
+
         #[test]
         fn test_stateful_regex() {
             let st = Regex::<AllAs>::default();
@@ -240,6 +384,7 @@ mod tests {
         type Test = Refinement<&'static str, Contains<Foo>>;
         assert!(Test::refine("123foo456").is_ok());
         assert!(Test::refine("bar").is_err());
+        assert!(Test::refine("fo").is_err());
     }
 
     #[test]
@@ -247,6 +392,42 @@ mod tests {
         type Test = Refinement<&'static str, Trimmed>;
         assert!(Test::refine("  foo  ").is_err());
         assert!(Test::refine("foo").is_ok());
+        assert!(Test::refine("").is_ok());
+        assert!(Test::refine("foo\u{3000}").is_err());
+    }
+
+    #[test]
+    fn test_no_whitespace() {
+        type Test = Refinement<&'static str, NoWhitespace>;
+        assert!(Test::refine("foo_bar").is_ok());
+        assert!(Test::refine("fóó").is_ok());
+        for s in [
+            "foo bar",
+            "foo\tbar",
+            "\x0Bfoo",
+            "foo\x0C",
+            "f\u{00A0}o",
+            "foo\u{2028}",
+        ] {
+            assert!(Test::refine(s).is_err(), "{:?}", s);
+        }
+        assert_eq!(
+            Test::refine("foo bar").unwrap_err().to_string(),
+            "refinement violated: must not contain whitespace"
+        );
+
+        // Whitespace that straddles, or follows, the chunks that are scanned at once.
+        let long = "x".repeat(31) + "\u{3000}" + &"y".repeat(40);
+        assert!(Refinement::<String, NoWhitespace>::refine(long.replace('\u{3000}', "é")).is_ok());
+        assert!(Refinement::<String, NoWhitespace>::refine(long).is_err());
+        assert!(Refinement::<String, NoWhitespace>::refine("y".repeat(70) + " ").is_err());
+    }
+
+    #[test]
+    fn test_is_ascii() {
+        type Test = Refinement<String, IsAscii>;
+        assert!(Test::refine("plain text".to_string()).is_ok());
+        assert!(Test::refine("naïve".to_string()).is_err());
     }
 
     #[cfg(feature = "regex")]
@@ -0,0 +1,159 @@
+//! Tracing of refinement failures.
+//!
+//! When the `tracing` feature is enabled, every failed refinement emits an `INFO` event with the
+//! target `refined`. Events carry the following fields:
+//!
+//! * `predicate`: the type name of the predicate that was violated
+//! * `type`: the type name of the value being refined
+//! * `code`: the [code](crate::error::ErrorKind::code) of the violation
+//! * `value`: the rejected value, when it was captured (see `Refinement::refine_verbose`)
+//! * `error`: the rendered error message
+//!
+//! Refinements through [Named](crate::Named) take place within a `named` span (also with the target
+//! `refined`) whose `name` field carries the name of the refinement.
+//!
+//! Tracing can be disabled for a single predicate by setting [Predicate::TRACE] to `false`, or
+//! for any refinement type by wrapping its predicate in [Untraced].
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::LessThan, trace::Untraced};
+//!
+//! // Failures of this type are never reported, e.g. because they are expected and frequent.
+//! type Guess = Refinement<u8, Untraced<LessThan<10>>>;
+//!
+//! assert!(Guess::refine(12).is_err());
+//! ```
+use core::marker::PhantomData;
+
+use crate::{error::ErrorKind, ErrorMessage, Predicate, RefinementError};
+
+/// A [Predicate] that behaves exactly like `P`, but whose failures are not traced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Untraced<P>(PhantomData<P>);
+
+impl<T, P: Predicate<T>> Predicate<T> for Untraced<P> {
+    const TRACE: bool = false;
+
+    fn test(value: &T) -> bool {
+        P::test(value)
+    }
+
+    fn error() -> ErrorMessage {
+        P::error()
+    }
+
+    fn normalize(value: T) -> T {
+        P::normalize(value)
+    }
+
+    fn kind() -> ErrorKind {
+        P::kind()
+    }
+
+    fn violation(value: &T) -> RefinementError {
+        P::violation(value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> crate::explain::Explanation {
+        P::explain(value)
+    }
+
+    unsafe fn optimize(value: &T) {
+        P::optimize(value)
+    }
+}
+
+/// Emits the tracing event for a failed refinement of `T` by `P`.
+pub(crate) fn report<T, P: Predicate<T>>(err: &RefinementError) {
+    if !P::TRACE {
+        return;
+    }
+
+    #[cfg(feature = "alloc")]
+    tracing::info!(
+        target: "refined",
+        predicate = core::any::type_name::<P>(),
+        r#type = core::any::type_name::<T>(),
+        code = err.kind().code(),
+        value = err.value(),
+        error = %err,
+        "refinement failed"
+    );
+
+    #[cfg(not(feature = "alloc"))]
+    tracing::info!(
+        target: "refined",
+        predicate = core::any::type_name::<P>(),
+        r#type = core::any::type_name::<T>(),
+        code = err.kind().code(),
+        error = %err,
+        "refinement failed"
+    );
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{boundable::unsigned::LessThan, *};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tracing::{
+        span::{Attributes, Id, Record},
+        subscriber::with_default,
+        Event, Metadata, Subscriber,
+    };
+
+    #[derive(Default)]
+    struct Counter(Arc<AtomicUsize>);
+
+    impl Subscriber for Counter {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "refined"
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    fn count_events<F: FnOnce()>(f: F) -> usize {
+        let counter = Counter::default();
+        let events = counter.0.clone();
+        with_default(counter, f);
+        events.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn test_failure_is_traced() {
+        let events = count_events(|| {
+            assert!(Refinement::<u8, LessThan<5>>::refine(4).is_ok());
+            assert!(Refinement::<u8, LessThan<5>>::refine(5).is_err());
+        });
+        assert_eq!(events, 1);
+    }
+
+    #[test]
+    fn test_untraced() {
+        let events = count_events(|| {
+            assert!(Refinement::<u8, Untraced<LessThan<5>>>::refine(5).is_err());
+        });
+        assert_eq!(events, 0);
+    }
+}